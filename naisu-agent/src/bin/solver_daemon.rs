@@ -50,6 +50,7 @@ impl Args {
             .position(|a| a == "--network" || a == "-n")
             .and_then(|i| args.get(i + 1))
             .and_then(|n| n.parse().ok())
+            .or_else(|| env::var("NETWORK").ok().and_then(|n| n.parse().ok()))
             .unwrap_or(Network::Testnet);
 
         Self { network }
@@ -289,7 +290,8 @@ async fn main() -> anyhow::Result<()> {
     let args = Args::parse();
 
     // Setup tracing
-    tracing_subscriber::fmt().with_env_filter("info").init();
+    dotenv().ok();
+    naisu_agent::logging::init().map_err(anyhow::Error::msg)?;
 
     info!("Starting Naisu Solver Daemon");
     info!("Network: {:?}", args.network);
@@ -0,0 +1,402 @@
+//! Agent subcommands
+//!
+//! `listen` runs the long-lived solver loop; the other subcommands do one
+//! unit of work and print the result as JSON, for debugging and scripted tests.
+//!
+//! `listen` only reports new hook intents unless started with `--execute`:
+//! the agent has no signing backends yet, so running an intent fails it at
+//! its first transaction.
+
+use std::time::Duration;
+
+use anyhow::{anyhow, bail, Context, Result};
+use clap::{Parser, Subcommand};
+use naisu_core::{
+    attestation_attempts, attestation_budget, EvmChain, Intent, IntentCreatedEvent,
+    IntentOrchestrator, SuiNetwork,
+};
+use naisu_sui::{CctpClient, HookLogClient};
+use tokio::sync::mpsc;
+use tracing::{info, warn};
+
+use crate::queue::{IntentScheduler, DEFAULT_AGING_STEP};
+
+/// Attestation polling cadence for `poll-attestation`; it polls for the
+/// source domain's attestation budget
+const ATTESTATION_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// How often `listen` looks for new hook intents
+const HOOK_POLL_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Intents `listen` runs at once
+const LISTEN_CONCURRENCY: usize = 4;
+
+/// Naisu solver agent
+#[derive(Debug, Parser)]
+#[command(name = "naisu-agent")]
+pub struct Cli {
+    /// Defaults to `listen`
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Subcommand)]
+pub enum Command {
+    /// Watch the hook for new intents
+    Listen {
+        /// Also run each new intent through the orchestrator. Without signing
+        /// backends this fails them at their first transaction.
+        #[arg(long)]
+        execute: bool,
+    },
+    /// Fetch one hook intent, run it through the orchestrator and print it
+    ProcessEvent {
+        #[arg(long)]
+        intent_id: String,
+        /// First block to search for the intent's event
+        #[arg(long)]
+        from_block: u64,
+    },
+    /// Wait for a CCTP attestation and print it
+    PollAttestation {
+        #[arg(long)]
+        nonce: String,
+        /// CCTP source domain; defaults to Sui's
+        #[arg(long)]
+        domain: Option<u32>,
+    },
+}
+
+impl Cli {
+    pub fn command(self) -> Command {
+        self.command.unwrap_or(Command::Listen { execute: false })
+    }
+}
+
+/// Fetch the hook's `IntentCreated` event for `intent_id`, turn it into the
+/// pending intent ingestion would create and run it with `orchestrator`
+pub async fn process_event(
+    client: &HookLogClient,
+    chain: EvmChain,
+    intent_id: &str,
+    from_block: u64,
+    orchestrator: &IntentOrchestrator,
+) -> Result<Intent> {
+    let log = client
+        .find_intent(intent_id, from_block)
+        .await?
+        .ok_or_else(|| {
+            anyhow!(
+                "no IntentCreated event for {} since block {}",
+                intent_id,
+                from_block
+            )
+        })?;
+    let event = IntentCreatedEvent::try_from(&log).context("Malformed IntentCreated log")?;
+
+    let mut intent = Intent::from((event, chain));
+    orchestrator.run(&mut intent).await;
+    Ok(intent)
+}
+
+/// Follows the hook block by block, turning every new `IntentCreated` log
+/// into a pending intent
+pub struct HookPoller {
+    client: HookLogClient,
+    chain: EvmChain,
+    /// First block not yet searched
+    next_block: u64,
+}
+
+impl HookPoller {
+    pub fn new(client: HookLogClient, chain: EvmChain, from_block: u64) -> Self {
+        Self {
+            client,
+            chain,
+            next_block: from_block,
+        }
+    }
+
+    /// The intents created since the last poll
+    pub async fn poll(&mut self) -> Result<Vec<Intent>> {
+        let head = self.client.block_number().await?;
+        if head < self.next_block {
+            return Ok(Vec::new());
+        }
+        let logs = self.client.backfill(self.next_block, head).await?;
+        let mut intents = Vec::with_capacity(logs.len());
+        for log in &logs {
+            match IntentCreatedEvent::try_from(log) {
+                Ok(event) => intents.push(Intent::from((event, self.chain))),
+                Err(e) => {
+                    warn!(tx = ?log.transaction_hash, error = %e, "Skipping malformed hook log")
+                }
+            }
+        }
+        self.next_block = head + 1;
+        Ok(intents)
+    }
+}
+
+/// Follow intents created on the hook from now on until ctrl-c. With an
+/// `orchestrator`, a [`HookPoller`] feeds an [`IntentScheduler`] running it;
+/// without one, new intents are only logged.
+pub async fn listen(network: SuiNetwork, orchestrator: Option<IntentOrchestrator>) -> Result<()> {
+    let chain = network.default_evm_chain();
+    let rpc_url =
+        std::env::var("EVM_RPC_URL").unwrap_or_else(|_| chain.default_rpc_url().to_string());
+    let hook_address =
+        std::env::var("HOOK_ADDRESS").map_err(|_| anyhow!("HOOK_ADDRESS must be set"))?;
+    let client = HookLogClient::new(rpc_url, hook_address);
+    let head = client.block_number().await?;
+    let mut poller = HookPoller::new(client, chain, head + 1);
+
+    let scheduler = IntentScheduler::new(LISTEN_CONCURRENCY, DEFAULT_AGING_STEP);
+    let dispatch = orchestrator.map(|orchestrator| {
+        let (done, mut finished) = mpsc::channel(LISTEN_CONCURRENCY);
+        tokio::spawn(async move {
+            // The scheduler logs each outcome; keep the channel drained
+            while finished.recv().await.is_some() {}
+        });
+        scheduler.spawn(orchestrator, done)
+    });
+    info!(
+        chain = chain.name(),
+        from_block = head + 1,
+        execute = dispatch.is_some(),
+        "Listening for hook intents"
+    );
+
+    let mut ticker = tokio::time::interval(HOOK_POLL_INTERVAL);
+    loop {
+        tokio::select! {
+            _ = ticker.tick() => match poller.poll().await {
+                Ok(intents) if intents.is_empty() => {}
+                Ok(intents) if dispatch.is_some() => {
+                    let found = intents.len();
+                    intents.into_iter().for_each(|intent| scheduler.submit(intent));
+                    info!(found, pending = scheduler.pending(), "Queued hook intents");
+                }
+                Ok(intents) => {
+                    for intent in &intents {
+                        info!(intent_id = %intent.id, "New hook intent (not executed; run with --execute)");
+                    }
+                }
+                Err(e) => warn!(error = %e, "Hook poll failed; retrying"),
+            },
+            result = tokio::signal::ctrl_c() => {
+                result?;
+                break;
+            }
+        }
+    }
+
+    if let Some(dispatch) = dispatch {
+        dispatch.abort();
+    }
+    Ok(())
+}
+
+/// Run a one-shot command against `network`, returning its JSON output.
+/// `Listen` is handled by the caller.
+pub async fn run_once(
+    command: &Command,
+    network: SuiNetwork,
+    orchestrator: &IntentOrchestrator,
+) -> Result<serde_json::Value> {
+    match command {
+        Command::Listen { .. } => bail!("listen is not a one-shot command"),
+        Command::ProcessEvent {
+            intent_id,
+            from_block,
+        } => {
+            let chain = network.default_evm_chain();
+            let rpc_url = std::env::var("EVM_RPC_URL")
+                .unwrap_or_else(|_| chain.default_rpc_url().to_string());
+            let hook_address =
+                std::env::var("HOOK_ADDRESS").map_err(|_| anyhow!("HOOK_ADDRESS must be set"))?;
+
+            let client = HookLogClient::new(rpc_url, hook_address);
+            let intent =
+                process_event(&client, chain, intent_id, *from_block, orchestrator).await?;
+            Ok(serde_json::to_value(intent)?)
+        }
+        Command::PollAttestation { nonce, domain } => {
+            let client = match std::env::var("CCTP_API_URL") {
+                Ok(url) => CctpClient::new(url),
+                Err(_) => CctpClient::for_network(network),
+            };
+            let domain = domain.unwrap_or(network.cctp_domain());
+            let max_attempts =
+                attestation_attempts(attestation_budget(domain), ATTESTATION_POLL_INTERVAL);
+            let message = client
+                .poll_attestation(domain, nonce, ATTESTATION_POLL_INTERVAL, max_attempts)
+                .await?;
+            Ok(serde_json::to_value(message)?)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use naisu_core::testing::{intent_created_log, orchestrator, Script, Step};
+    use naisu_core::{IntentStatus, YieldStrategy};
+    use serde_json::json;
+    use wiremock::matchers::{body_partial_json, method};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn parse(args: &[&str]) -> Result<Command, clap::Error> {
+        Cli::try_parse_from(std::iter::once("naisu-agent").chain(args.iter().copied()))
+            .map(Cli::command)
+    }
+
+    #[test]
+    fn test_parse_subcommands() {
+        assert_eq!(parse(&[]).unwrap(), Command::Listen { execute: false });
+        assert_eq!(
+            parse(&["listen", "--execute"]).unwrap(),
+            Command::Listen { execute: true }
+        );
+        assert_eq!(
+            parse(&["process-event", "--from-block", "42", "--intent-id", "0x01"]).unwrap(),
+            Command::ProcessEvent {
+                intent_id: "0x01".to_string(),
+                from_block: 42,
+            }
+        );
+        assert_eq!(
+            parse(&["poll-attestation", "--nonce", "7"]).unwrap(),
+            Command::PollAttestation {
+                nonce: "7".to_string(),
+                domain: None,
+            }
+        );
+        assert!(parse(&["process-event", "--intent-id", "0x01"]).is_err());
+        // A flag is never taken as another flag's value
+        assert!(parse(&["process-event", "--intent-id", "--from-block", "42"]).is_err());
+        assert!(parse(&["poll-attestation", "--nonce", "7", "--domian", "5"]).is_err());
+        assert!(parse(&["bogus"]).is_err());
+        assert_eq!(
+            parse(&["--help"]).unwrap_err().kind(),
+            clap::error::ErrorKind::DisplayHelp
+        );
+    }
+
+    #[tokio::test]
+    async fn test_process_event_against_mocked_provider() {
+        let intent_id = format!("0x{:064x}", 9);
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(body_partial_json(json!({
+                "method": "eth_getLogs",
+                "params": [{ "fromBlock": "0x2a", "topics": [IntentCreatedEvent::topic0(), intent_id] }],
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": [intent_created_log(&intent_id)],
+            })))
+            .mount(&server)
+            .await;
+
+        let client = HookLogClient::new(server.uri(), "0xf0093fcf07aa10de35b78d1f33c60439d11bc0c0");
+        let script = std::sync::Arc::new(Script::default());
+        let intent = process_event(
+            &client,
+            EvmChain::BaseSepolia,
+            "0x09",
+            42,
+            &orchestrator(&script),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(intent.id, intent_id);
+        assert_eq!(intent.strategy, Some(YieldStrategy::ScallopUsdc));
+        assert_eq!(
+            intent.source_address,
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"
+        );
+        // The orchestrator took it from pending all the way through
+        assert_eq!(intent.status, IntentStatus::Completed);
+        assert_eq!(
+            script.calls(),
+            [
+                Step::Swap,
+                Step::Burn,
+                Step::ReceivedCheck,
+                Step::Attestation,
+                Step::Mint,
+                Step::Deposit
+            ]
+        );
+        assert_eq!(intent.deposit_tx_hash.as_deref(), Some("0xdeposit"));
+    }
+
+    #[tokio::test]
+    async fn test_process_event_missing_intent() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": [],
+            })))
+            .mount(&server)
+            .await;
+
+        let client = HookLogClient::new(server.uri(), "0xf0093fcf07aa10de35b78d1f33c60439d11bc0c0");
+        let script = std::sync::Arc::new(Script::default());
+        let err = process_event(
+            &client,
+            EvmChain::BaseSepolia,
+            "0x09",
+            0,
+            &orchestrator(&script),
+        )
+        .await
+        .unwrap_err();
+
+        assert!(err.to_string().contains("no IntentCreated event"));
+        assert!(script.calls().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_poller_returns_new_intents_once() {
+        let intent_id = format!("0x{:064x}", 9);
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(body_partial_json(json!({ "method": "eth_blockNumber" })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": "0x2a",
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(body_partial_json(json!({
+                "method": "eth_getLogs",
+                "params": [{ "fromBlock": "0x28", "toBlock": "0x2a" }],
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": [intent_created_log(&intent_id)],
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = HookLogClient::new(server.uri(), "0xf0093fcf07aa10de35b78d1f33c60439d11bc0c0");
+        let mut poller = HookPoller::new(client, EvmChain::BaseSepolia, 40);
+
+        let intents = poller.poll().await.unwrap();
+        assert_eq!(intents.len(), 1);
+        assert_eq!(intents[0].id, intent_id);
+        assert_eq!(intents[0].status, IntentStatus::Pending);
+        // Same head: nothing new to search
+        assert!(poller.poll().await.unwrap().is_empty());
+    }
+}
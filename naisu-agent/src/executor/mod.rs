@@ -6,6 +6,7 @@ pub mod navi_executor;
 pub mod real_executor;
 
 use anyhow::Result;
+use naisu_core::SUI_COIN_TYPE;
 use serde_json::Value;
 
 /// Transaction executor for Sui
@@ -39,7 +40,7 @@ impl SuiExecutor {
             "jsonrpc": "2.0",
             "id": 1,
             "method": "suix_getBalance",
-            "params": [self.wallet_address, "0x2::sui::SUI"]
+            "params": [self.wallet_address, SUI_COIN_TYPE]
         });
 
         let response = self.client.post(&self.rpc_url).json(&query).send().await?;
@@ -58,7 +59,7 @@ impl SuiExecutor {
             "jsonrpc": "2.0",
             "id": 1,
             "method": "suix_getCoins",
-            "params": [self.wallet_address, "0x2::sui::SUI"]
+            "params": [self.wallet_address, SUI_COIN_TYPE]
         });
 
         let response = self.client.post(&self.rpc_url).json(&query).send().await?;
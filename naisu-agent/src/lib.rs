@@ -18,12 +18,17 @@
 //! - Mainnet: Cetus, Scallop, Navi, Native Staking, DeepBook
 
 pub mod bots;
+pub mod cli;
 pub mod config;
 pub mod executor;
+pub mod logging;
+pub mod ops;
+pub mod queue;
 pub mod solver;
 pub mod solver_factory;
 
 pub use config::{Network, Protocol, ProtocolConfig};
 pub use executor::{SuiCoin, SuiExecutor, TransactionResult};
+pub use queue::{IntentQueue, IntentScheduler};
 pub use solver::{Bid, Solver, SolverConfig};
 pub use solver_factory::{MultiNetworkSolver, SolverFactory};
@@ -0,0 +1,179 @@
+//! Tracing subscriber setup for the agent binaries and the API
+//!
+//! - `LOG_FORMAT`: `pretty` (default) or `json`; anything else fails startup
+//! - `LOG_LEVEL`: max level / filter directive (default `info`); `RUST_LOG` wins if set
+
+use std::str::FromStr;
+
+use tracing::Subscriber;
+use tracing_subscriber::{
+    fmt::{self, format::FmtSpan, MakeWriter},
+    layer::SubscriberExt,
+    EnvFilter,
+};
+
+/// Log output format
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LogFormat {
+    /// Human-readable, for local development
+    #[default]
+    Pretty,
+    /// One JSON object per line, for log ingestion
+    Json,
+}
+
+impl FromStr for LogFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "pretty" | "text" => Ok(LogFormat::Pretty),
+            "json" => Ok(LogFormat::Json),
+            _ => Err(format!("Unknown log format: {}", s)),
+        }
+    }
+}
+
+/// Logging configuration
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogConfig {
+    pub format: LogFormat,
+    pub level: String,
+}
+
+impl Default for LogConfig {
+    fn default() -> Self {
+        Self {
+            format: LogFormat::Pretty,
+            level: "info".to_string(),
+        }
+    }
+}
+
+impl LogConfig {
+    /// Read `LOG_FORMAT` and `LOG_LEVEL`; unset means the defaults
+    pub fn from_env() -> Result<Self, String> {
+        Self::from_values(
+            std::env::var("LOG_FORMAT").ok(),
+            std::env::var("LOG_LEVEL").ok(),
+        )
+    }
+
+    fn from_values(format: Option<String>, level: Option<String>) -> Result<Self, String> {
+        let format = match format.as_deref().map(str::trim) {
+            None | Some("") => LogFormat::default(),
+            Some(format) => format
+                .parse()
+                .map_err(|reason| format!("LOG_FORMAT: {}", reason))?,
+        };
+        let level = level.unwrap_or_else(|| "info".to_string());
+
+        Ok(Self { format, level })
+    }
+
+    fn env_filter(&self) -> EnvFilter {
+        EnvFilter::try_from_default_env()
+            .or_else(|_| EnvFilter::try_new(&self.level))
+            .unwrap_or_else(|_| EnvFilter::new("info"))
+    }
+}
+
+/// Build a subscriber for `config` writing to `writer`
+pub fn build_subscriber<W>(config: &LogConfig, writer: W) -> Box<dyn Subscriber + Send + Sync>
+where
+    W: for<'a> MakeWriter<'a> + Send + Sync + 'static,
+{
+    let registry = tracing_subscriber::registry().with(config.env_filter());
+
+    match config.format {
+        LogFormat::Pretty => Box::new(
+            registry.with(
+                fmt::layer()
+                    .with_target(true)
+                    .with_span_events(FmtSpan::CLOSE)
+                    .with_writer(writer),
+            ),
+        ),
+        LogFormat::Json => Box::new(
+            registry.with(
+                fmt::layer()
+                    .json()
+                    .with_target(true)
+                    .with_span_events(FmtSpan::CLOSE)
+                    .with_writer(writer),
+            ),
+        ),
+    }
+}
+
+/// Install the global subscriber configured from the environment; fails on
+/// a bad `LOG_FORMAT` or when a global subscriber is already set
+pub fn init() -> Result<(), String> {
+    let config = LogConfig::from_env()?;
+    tracing::subscriber::set_global_default(build_subscriber(&config, std::io::stdout))
+        .map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+
+    #[derive(Clone, Default)]
+    struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+    impl std::io::Write for Buffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl<'a> MakeWriter<'a> for Buffer {
+        type Writer = Buffer;
+
+        fn make_writer(&'a self) -> Self::Writer {
+            self.clone()
+        }
+    }
+
+    #[test]
+    fn test_log_format_from_str() {
+        assert_eq!("JSON".parse::<LogFormat>().unwrap(), LogFormat::Json);
+        assert_eq!("pretty".parse::<LogFormat>().unwrap(), LogFormat::Pretty);
+        assert!("xml".parse::<LogFormat>().is_err());
+    }
+
+    #[test]
+    fn test_unknown_log_format_is_refused() {
+        let config = |format: Option<&str>| LogConfig::from_values(format.map(String::from), None);
+        assert_eq!(config(None).unwrap(), LogConfig::default());
+        assert_eq!(config(Some("")).unwrap(), LogConfig::default());
+        assert_eq!(config(Some("json")).unwrap().format, LogFormat::Json);
+        assert!(config(Some("jsno")).unwrap_err().starts_with("LOG_FORMAT"));
+    }
+
+    #[test]
+    fn test_json_subscriber_emits_json_lines() {
+        let buffer = Buffer::default();
+        let config = LogConfig {
+            format: LogFormat::Json,
+            level: "debug".to_string(),
+        };
+
+        tracing::subscriber::with_default(build_subscriber(&config, buffer.clone()), || {
+            tracing::info!(intent_id = "0xabc", "intent created");
+        });
+
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        let line: serde_json::Value = serde_json::from_str(output.lines().next().unwrap()).unwrap();
+        assert_eq!(line["level"], "INFO");
+        assert_eq!(line["fields"]["message"], "intent created");
+        assert_eq!(line["fields"]["intent_id"], "0xabc");
+    }
+}
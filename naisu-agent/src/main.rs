@@ -1,14 +1,43 @@
 //! Naisu Solver Bot Runner
 //!
 //! Run multiple solver bots that compete to fulfill yield intents.
+//! See `naisu_agent::cli` for one-shot subcommands.
 
+use clap::Parser;
 use naisu_agent::bots::{NaviSolver, ScallopSolver};
+use naisu_agent::cli::{self, Cli, Command};
+use naisu_agent::ops::{self, AgentOps, DEFAULT_NONCE_STORE_PATH};
 use naisu_agent::solver::Solver;
+use naisu_core::SuiNetwork;
+use naisu_sui::CctpClient;
 use tracing::info;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    tracing_subscriber::fmt::init();
+    dotenvy::dotenv().ok();
+    naisu_agent::logging::init()?;
+
+    let command = Cli::parse().command();
+    let network = match std::env::var("NETWORK") {
+        Ok(network) if !network.trim().is_empty() => network
+            .trim()
+            .parse::<SuiNetwork>()
+            .map_err(|e| format!("NETWORK: {}", e))?,
+        _ => SuiNetwork::Testnet,
+    };
+    let nonce_store =
+        std::env::var("NONCE_STORE_PATH").unwrap_or_else(|_| DEFAULT_NONCE_STORE_PATH.to_string());
+    let ops = match std::env::var("CCTP_API_URL") {
+        Ok(url) => AgentOps::new(CctpClient::new(url)),
+        Err(_) => AgentOps::for_network(network),
+    };
+    let orchestrator = ops::orchestrator(ops, &nonce_store)?;
+
+    let Command::Listen { execute } = command else {
+        let output = cli::run_once(&command, network, &orchestrator).await?;
+        println!("{}", serde_json::to_string_pretty(&output)?);
+        return Ok(());
+    };
 
     info!("🤖 Starting Naisu Solver Bots...");
 
@@ -20,15 +49,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     info!("   - {}", scallop.name());
     info!("   - {}", navi.name());
 
-    // TODO: Start solver event loops
-    // 1. Poll for new YieldIntent shared objects
-    // 2. Evaluate and bid
-    // 3. Race to fulfill
-
-    info!("⏳ Solver bots ready (implementation in progress)");
-
-    // Keep alive
-    tokio::signal::ctrl_c().await?;
+    if execute {
+        info!("   Burn nonces recorded in {}", nonce_store);
+    } else {
+        info!("   Watching only; pass --execute to run new intents");
+    }
+    cli::listen(network, execute.then_some(orchestrator)).await?;
     info!("👋 Shutting down solver bots");
 
     Ok(())
@@ -0,0 +1,235 @@
+//! Orchestrator backends for the agent
+//!
+//! [`AgentOps`] implements the orchestrator's chain traits with what the agent
+//! can do without a signing key: polling Circle for attestations, checking
+//! that an EVM burn is still on chain and whether an EVM mint already happened. Steps that submit transactions fail
+//! with a config error instead of pretending to succeed, so an intent that
+//! reaches one is failed with a readable reason.
+//!
+//! [`orchestrator`] wires these backends to a [`FileNonceStore`], so a
+//! restarted agent resumes bridged intents at the attestation.
+
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use naisu_core::{
+    attestation_attempts, attestation_budget, Attestation, BridgeOps, BurnReceipt, Direction,
+    EvmOps, FileNonceStore, Intent, IntentOrchestrator, NaisuError, OutputReceipt, SuiNetwork,
+    SuiOps, UsdcReceipt,
+};
+use naisu_sui::circle::CctpClient;
+
+/// Where burn nonces are kept when `NONCE_STORE_PATH` is unset
+pub const DEFAULT_NONCE_STORE_PATH: &str = "naisu-nonces.json";
+
+/// Delay between attestation polls
+const ATTESTATION_POLL_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Read-only chain backends; see the module docs
+pub struct AgentOps {
+    cctp: CctpClient,
+    poll_interval: Duration,
+}
+
+impl AgentOps {
+    pub fn new(cctp: CctpClient) -> Self {
+        Self {
+            cctp,
+            poll_interval: ATTESTATION_POLL_INTERVAL,
+        }
+    }
+
+    /// Backends against Circle's attestation API for `network`
+    pub fn for_network(network: SuiNetwork) -> Self {
+        Self::new(CctpClient::for_network(network))
+    }
+
+    pub fn with_poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+}
+
+fn needs_signer(step: &str) -> NaisuError {
+    NaisuError::Config(format!("{} needs a signer, and none is configured", step))
+}
+
+#[async_trait]
+impl EvmOps for AgentOps {
+    async fn swap_to_usdc(&self, _: &Intent) -> Result<UsdcReceipt, NaisuError> {
+        Err(needs_signer("the EVM swap"))
+    }
+
+    async fn quote_from_usdc(&self, _: &Intent, _: &str, _: &str) -> Result<String, NaisuError> {
+        Err(needs_signer("the output swap quote"))
+    }
+
+    async fn swap_from_usdc(
+        &self,
+        _: &Intent,
+        _: &str,
+        _: &str,
+    ) -> Result<OutputReceipt, NaisuError> {
+        Err(needs_signer("the output swap"))
+    }
+
+    async fn unwrap_weth(&self, _: &Intent, _: &str) -> Result<String, NaisuError> {
+        Err(needs_signer("the WETH unwrap"))
+    }
+
+    async fn tx_included(&self, intent: &Intent, tx_hash: &str) -> Result<bool, NaisuError> {
+        let chain = intent.evm_chain.ok_or_else(|| NaisuError::InvalidState {
+            expected: "an EVM chain".to_string(),
+            actual: "none".to_string(),
+        })?;
+        Ok(self
+            .cctp
+            .burn_tx_included(chain.default_rpc_url(), tx_hash)
+            .await?)
+    }
+}
+
+#[async_trait]
+impl SuiOps for AgentOps {
+    async fn withdraw_usdc(&self, _: &Intent) -> Result<UsdcReceipt, NaisuError> {
+        Err(needs_signer("the Sui withdraw"))
+    }
+
+    async fn deposit(&self, _: &Intent) -> Result<String, NaisuError> {
+        Err(needs_signer("the Sui deposit"))
+    }
+}
+
+#[async_trait]
+impl BridgeOps for AgentOps {
+    async fn burn(&self, _: &Intent) -> Result<BurnReceipt, NaisuError> {
+        Err(needs_signer("the CCTP burn"))
+    }
+
+    async fn attestation(&self, intent: &Intent, nonce: &str) -> Result<Attestation, NaisuError> {
+        let domain = intent
+            .source_cctp_domain()
+            .ok_or_else(|| NaisuError::InvalidState {
+                expected: "a CCTP source domain".to_string(),
+                actual: "none".to_string(),
+            })?;
+        let attempts = attestation_attempts(attestation_budget(domain), self.poll_interval);
+        let message = self
+            .cctp
+            .poll_attestation(domain, nonce, self.poll_interval, attempts)
+            .await?;
+        Ok(Attestation {
+            message: message.message,
+            attestation: message.attestation,
+        })
+    }
+
+    async fn already_received(&self, intent: &Intent, nonce: &str) -> Result<bool, NaisuError> {
+        // Only an EVM destination's MessageTransmitter can be asked
+        let (Direction::SuiToEvm, Some(chain)) = (intent.direction, intent.evm_chain) else {
+            return Ok(false);
+        };
+        let domain = intent
+            .source_cctp_domain()
+            .ok_or_else(|| NaisuError::InvalidState {
+                expected: "a CCTP source domain".to_string(),
+                actual: "none".to_string(),
+            })?;
+        let nonce = nonce
+            .parse()
+            .map_err(|_| NaisuError::Bridge(format!("burn nonce {} is not numeric", nonce)))?;
+        Ok(self
+            .cctp
+            .clone()
+            .with_evm_destination(chain, chain.default_rpc_url())
+            .is_message_already_received(domain, nonce)
+            .await?)
+    }
+
+    async fn mint(&self, _: &Intent, _: &Attestation) -> Result<String, NaisuError> {
+        Err(needs_signer("the CCTP mint"))
+    }
+}
+
+/// Orchestrator over `ops` that records burn nonces in `nonce_store`
+pub fn orchestrator(
+    ops: AgentOps,
+    nonce_store: impl AsRef<Path>,
+) -> Result<IntentOrchestrator, NaisuError> {
+    let nonces = FileNonceStore::open(nonce_store.as_ref())?;
+    let ops = Arc::new(ops);
+    Ok(IntentOrchestrator::new(ops.clone(), ops.clone(), ops).with_nonce_store(Arc::new(nonces)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use naisu_core::{BridgeNonce, EvmChain, IntentStatus, NonceStore, YieldStrategy};
+    use serde_json::json;
+    use wiremock::matchers::{method, path_regex};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn bridging_intent() -> Intent {
+        let mut intent = Intent::new_evm_to_sui(
+            "0x01".to_string(),
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed".to_string(),
+            "0x2".to_string(),
+            EvmChain::BaseSepolia,
+            "0xweth".to_string(),
+            "1000000000000000".to_string(),
+            YieldStrategy::ScallopUsdc,
+        );
+        intent.usdc_amount = Some("990000".to_string());
+        intent.set_status(IntentStatus::Bridging);
+        intent
+    }
+
+    #[tokio::test]
+    async fn test_resumes_from_recorded_nonce_and_fails_without_signer() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path_regex("^/v2/messages/6$"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "messages": [{
+                    "message": "0xmessage",
+                    "attestation": "0xattestation",
+                    "status": "complete",
+                    "eventNonce": "42"
+                }]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nonces.json");
+        FileNonceStore::open(&path)
+            .unwrap()
+            .put(
+                "0x01",
+                BridgeNonce {
+                    burn_tx_hash: "0xburn".to_string(),
+                    nonce: "42".to_string(),
+                    message_hash: None,
+                },
+            )
+            .await
+            .unwrap();
+
+        let ops = AgentOps::new(CctpClient::new(server.uri()))
+            .with_poll_interval(Duration::from_millis(1));
+        let orchestrator = orchestrator(ops, &path).unwrap();
+        let mut intent = bridging_intent();
+
+        // The nonce comes from the store; the mint needs a key the agent lacks
+        assert_eq!(orchestrator.step(&mut intent).await, IntentStatus::Failed);
+        assert_eq!(intent.bridge_nonce.as_deref(), Some("42"));
+        assert!(intent
+            .error_message
+            .as_deref()
+            .unwrap()
+            .contains("needs a signer"));
+    }
+}
@@ -0,0 +1,283 @@
+//! Priority scheduling of intents for the orchestrator
+//!
+//! Intents are ordered by a virtual start time: when they were queued, moved
+//! earlier by `priority × aging_step`. A higher priority is a head start, not
+//! a permanent lane, so an old low-priority intent eventually outranks newer
+//! urgent ones and nothing starves.
+//!
+//! ```text
+//! submit → IntentQueue → worker (≤ max_concurrency) → IntentOrchestrator::run
+//! ```
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use naisu_core::{Direction, Intent, IntentOrchestrator};
+use tokio::sync::{mpsc, Notify, Semaphore};
+use tokio::task::JoinHandle;
+use tokio::time::Instant;
+
+/// Priority of an intent that didn't ask for one
+pub const DEFAULT_PRIORITY: u8 = 0;
+
+/// Highest priority an intent can get; higher requests are capped here, so a
+/// client can jump the queue by at most `MAX_PRIORITY × aging_step`
+pub const MAX_PRIORITY: u8 = 3;
+
+/// Default head start per priority level
+pub const DEFAULT_AGING_STEP: Duration = Duration::from_secs(30);
+
+/// Scheduling priority of `intent`, higher first. An explicit `priority` wins;
+/// otherwise intents with a short attestation wait go first: a burn on Sui is
+/// final within seconds, while EVM burns wait for Ethereum finality. Withdraws
+/// never bridge and rank with the Sui burns. Capped at [`MAX_PRIORITY`].
+pub fn priority_of(intent: &Intent) -> u8 {
+    let priority = intent.priority.unwrap_or(match intent.direction {
+        Direction::SuiToEvm | Direction::SuiWithdraw => DEFAULT_PRIORITY + 1,
+        Direction::EvmToSui => DEFAULT_PRIORITY,
+    });
+    priority.min(MAX_PRIORITY)
+}
+
+#[derive(Debug)]
+struct Queued {
+    /// `(virtual start, submission order)`, smallest first
+    key: Reverse<(Instant, u64)>,
+    intent: Intent,
+}
+
+impl PartialEq for Queued {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl Eq for Queued {}
+
+impl PartialOrd for Queued {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Queued {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.key.cmp(&other.key)
+    }
+}
+
+/// Intents waiting for a worker, highest effective priority first
+#[derive(Debug)]
+pub struct IntentQueue {
+    heap: BinaryHeap<Queued>,
+    aging_step: Duration,
+    next_seq: u64,
+}
+
+impl IntentQueue {
+    pub fn new(aging_step: Duration) -> Self {
+        Self {
+            heap: BinaryHeap::new(),
+            aging_step,
+            next_seq: 0,
+        }
+    }
+
+    /// Queue `intent` as submitted at `now`
+    pub fn push(&mut self, intent: Intent, now: Instant) {
+        let head_start = self.aging_step * u32::from(priority_of(&intent));
+        let max_head_start = self.aging_step * u32::from(MAX_PRIORITY);
+        // Shift everything later by the largest head start instead of
+        // subtracting, which could underflow `Instant`
+        let start = now + (max_head_start - head_start);
+        self.heap.push(Queued {
+            key: Reverse((start, self.next_seq)),
+            intent,
+        });
+        self.next_seq += 1;
+    }
+
+    pub fn pop(&mut self) -> Option<Intent> {
+        self.heap.pop().map(|queued| queued.intent)
+    }
+
+    /// Put back an entry taken with [`IntentQueue::pop_entry`], keeping its
+    /// place in line
+    fn requeue(&mut self, queued: Queued) {
+        self.heap.push(queued);
+    }
+
+    fn pop_entry(&mut self) -> Option<Queued> {
+        self.heap.pop()
+    }
+
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+}
+
+/// Bounded pool of workers running queued intents to completion
+pub struct IntentScheduler {
+    queue: Mutex<IntentQueue>,
+    queued: Notify,
+    workers: Arc<Semaphore>,
+}
+
+impl IntentScheduler {
+    pub fn new(max_concurrency: usize, aging_step: Duration) -> Arc<Self> {
+        Arc::new(Self {
+            queue: Mutex::new(IntentQueue::new(aging_step)),
+            queued: Notify::new(),
+            workers: Arc::new(Semaphore::new(max_concurrency.max(1))),
+        })
+    }
+
+    /// Queue an intent for processing
+    pub fn submit(&self, intent: Intent) {
+        self.queue.lock().unwrap().push(intent, Instant::now());
+        self.queued.notify_one();
+    }
+
+    pub fn pending(&self) -> usize {
+        self.queue.lock().unwrap().len()
+    }
+
+    /// Dispatch loop: whenever a worker is free, run the highest-priority
+    /// intent and send it to `done` once terminal. Stops when `done` closes,
+    /// leaving the intent it had picked in the queue.
+    pub fn spawn(
+        self: &Arc<Self>,
+        orchestrator: IntentOrchestrator,
+        done: mpsc::Sender<Intent>,
+    ) -> JoinHandle<()> {
+        let scheduler = self.clone();
+        tokio::spawn(async move {
+            loop {
+                // Pick the next intent only once a worker is free, so anything
+                // submitted meanwhile competes on priority
+                let Ok(permit) = scheduler.workers.clone().acquire_owned().await else {
+                    return;
+                };
+                let queued = scheduler.next().await;
+                if done.is_closed() {
+                    scheduler.queue.lock().unwrap().requeue(queued);
+                    return;
+                }
+                let mut intent = queued.intent;
+
+                let orchestrator = orchestrator.clone();
+                let done = done.clone();
+                tokio::spawn(async move {
+                    let status = orchestrator.run(&mut intent).await;
+                    tracing::info!(intent_id = %intent.id, status = ?status, "Intent finished");
+                    let _ = done.send(intent).await;
+                    drop(permit);
+                });
+            }
+        })
+    }
+
+    async fn next(&self) -> Queued {
+        loop {
+            let notified = self.queued.notified();
+            if let Some(queued) = self.queue.lock().unwrap().pop_entry() {
+                return queued;
+            }
+            notified.await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use naisu_core::testing::{self, Script};
+    use naisu_core::EvmChain;
+
+    fn intent(id: &str, priority: Option<u8>) -> Intent {
+        let mut intent = Intent::new_evm_to_sui(
+            id.to_string(),
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed".to_string(),
+            "0x2".to_string(),
+            EvmChain::BaseSepolia,
+            "0xusdc".to_string(),
+            "1000000".to_string(),
+            naisu_core::YieldStrategy::ScallopUsdc,
+        );
+        intent.priority = priority;
+        intent
+    }
+
+    #[tokio::test]
+    async fn test_high_priority_intent_runs_first() {
+        let scheduler = IntentScheduler::new(1, DEFAULT_AGING_STEP);
+        for id in ["low-1", "low-2", "low-3"] {
+            scheduler.submit(intent(id, None));
+        }
+        scheduler.submit(intent("urgent", Some(2)));
+
+        let orchestrator = testing::orchestrator(&Arc::new(Script::default()));
+        let (tx, mut rx) = mpsc::channel(4);
+        let handle = scheduler.spawn(orchestrator, tx);
+
+        let mut order = Vec::new();
+        for _ in 0..4 {
+            order.push(rx.recv().await.unwrap().id);
+        }
+        handle.abort();
+
+        assert_eq!(order, ["urgent", "low-1", "low-2", "low-3"]);
+    }
+
+    #[test]
+    fn test_waiting_intent_ages_past_newer_priority() {
+        let step = Duration::from_secs(30);
+        let mut queue = IntentQueue::new(step);
+        let t0 = Instant::now();
+
+        queue.push(intent("old", None), t0);
+        // Priority 2 is a 60 s head start; this one arrives 61 s later
+        queue.push(intent("new", Some(2)), t0 + Duration::from_secs(61));
+        queue.push(intent("fresh", Some(2)), t0 + Duration::from_secs(1));
+
+        let order: Vec<_> = std::iter::from_fn(|| queue.pop()).map(|i| i.id).collect();
+        assert_eq!(order, ["fresh", "old", "new"]);
+    }
+
+    #[test]
+    fn test_priority_is_capped() {
+        let step = Duration::from_secs(30);
+        let mut queue = IntentQueue::new(step);
+        let t0 = Instant::now();
+
+        assert_eq!(priority_of(&intent("greedy", Some(u8::MAX))), MAX_PRIORITY);
+        queue.push(intent("old", None), t0);
+        // u8::MAX would be a two hour head start; capped it is 90 s
+        queue.push(
+            intent("greedy", Some(u8::MAX)),
+            t0 + Duration::from_secs(91),
+        );
+
+        let order: Vec<_> = std::iter::from_fn(|| queue.pop()).map(|i| i.id).collect();
+        assert_eq!(order, ["old", "greedy"]);
+    }
+
+    #[tokio::test]
+    async fn test_closed_receiver_leaves_intent_queued() {
+        let scheduler = IntentScheduler::new(1, DEFAULT_AGING_STEP);
+        let (tx, rx) = mpsc::channel(1);
+        drop(rx);
+        let handle = scheduler.spawn(testing::orchestrator(&Arc::new(Script::default())), tx);
+
+        scheduler.submit(intent("orphan", None));
+        handle.await.unwrap();
+
+        assert_eq!(scheduler.pending(), 1);
+    }
+}
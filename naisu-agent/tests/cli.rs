@@ -0,0 +1,120 @@
+//! Runs the agent binary's one-shot subcommands against mocked providers
+
+use naisu_core::testing::intent_created_log;
+use naisu_core::IntentCreatedEvent;
+use serde_json::json;
+use tokio::process::Command;
+use wiremock::matchers::{body_partial_json, method};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const HOOK_ADDRESS: &str = "0xf0093fcf07aa10de35b78d1f33c60439d11bc0c0";
+
+/// The agent binary with only `vars` set, run from an empty directory so no
+/// `.env` file is picked up
+fn agent(dir: &tempfile::TempDir, vars: &[(&str, &str)]) -> Command {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_naisu-agent"));
+    command
+        .current_dir(dir.path())
+        .env_clear()
+        .env("RUST_LOG", "off")
+        .envs(vars.iter().copied());
+    command
+}
+
+#[tokio::test]
+async fn test_process_event_prints_the_orchestrated_intent() {
+    let intent_id = format!("0x{:064x}", 9);
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(body_partial_json(json!({
+            "method": "eth_getLogs",
+            "params": [{ "fromBlock": "0x2a", "topics": [IntentCreatedEvent::topic0(), intent_id] }],
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "result": [intent_created_log(&intent_id)],
+        })))
+        .mount(&server)
+        .await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let output = agent(
+        &dir,
+        &[
+            ("NETWORK", "testnet"),
+            ("EVM_RPC_URL", &server.uri()),
+            ("HOOK_ADDRESS", HOOK_ADDRESS),
+        ],
+    )
+    .args(["process-event", "--intent-id", "0x09", "--from-block", "42"])
+    .output()
+    .await
+    .unwrap();
+
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let intent: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(intent["id"], intent_id);
+    assert_eq!(intent["evm_chain"], "basesepolia");
+    assert_eq!(intent["usdc_amount"], "4990000");
+    // The agent has no signer, so the orchestrator fails the swap step
+    assert_eq!(intent["status"], "failed");
+    assert_eq!(
+        intent["error_message"],
+        "swap failed: Configuration error: the EVM swap needs a signer, and none is configured"
+    );
+    let history: Vec<_> = intent["status_history"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|change| change["status"].as_str().unwrap())
+        .collect();
+    assert_eq!(history, ["failed"]);
+}
+
+#[tokio::test]
+async fn test_process_event_fails_without_the_event() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "result": [],
+        })))
+        .mount(&server)
+        .await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let output = agent(
+        &dir,
+        &[
+            ("EVM_RPC_URL", &server.uri()),
+            ("HOOK_ADDRESS", HOOK_ADDRESS),
+        ],
+    )
+    .args(["process-event", "--intent-id", "0x09", "--from-block", "0"])
+    .output()
+    .await
+    .unwrap();
+
+    assert!(!output.status.success());
+    assert!(output.stdout.is_empty());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("no IntentCreated event"));
+}
+
+#[tokio::test]
+async fn test_unknown_flag_is_refused() {
+    let dir = tempfile::tempdir().unwrap();
+    let output = agent(&dir, &[])
+        .args(["poll-attestation", "--nonce", "7", "--domian", "5"])
+        .output()
+        .await
+        .unwrap();
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--domian"));
+}
@@ -0,0 +1,36 @@
+//! Native ETH balance lookups, for gas-reservation checks on native inputs
+
+use serde_json::json;
+
+/// `address`'s native balance in wei, from `eth_getBalance` at the latest block
+pub async fn native_balance(
+    client: &reqwest::Client,
+    rpc_url: &str,
+    address: &str,
+) -> Result<u128, String> {
+    let body = json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "eth_getBalance",
+        "params": [address, "latest"],
+    });
+
+    let response: serde_json::Value = client
+        .post(rpc_url)
+        .json(&body)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?
+        .json()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    if let Some(error) = response.get("error") {
+        return Err(format!("RPC error: {}", error));
+    }
+    let balance = response["result"]
+        .as_str()
+        .ok_or_else(|| "missing result".to_string())?;
+    u128::from_str_radix(balance.trim_start_matches("0x"), 16)
+        .map_err(|e| format!("balance {}: {}", balance, e))
+}
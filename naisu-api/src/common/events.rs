@@ -0,0 +1,103 @@
+//! Bounded event channel with backpressure warnings
+//!
+//! Used between chain event listeners and their consumers. The capacity comes
+//! from config; once the buffer is more than 80% full each send logs a warning
+//! so operators can see a slow consumer before the producer starts blocking.
+
+use tokio::sync::mpsc;
+use tracing::warn;
+
+/// Fill ratio above which sends log a backpressure warning
+const BACKPRESSURE_THRESHOLD: f64 = 0.8;
+
+/// Sending half of a bounded event channel
+#[derive(Debug)]
+pub struct EventSender<T> {
+    name: &'static str,
+    tx: mpsc::Sender<T>,
+}
+
+impl<T> Clone for EventSender<T> {
+    fn clone(&self) -> Self {
+        Self {
+            name: self.name,
+            tx: self.tx.clone(),
+        }
+    }
+}
+
+/// Create a bounded event channel named `name` (used in logs)
+pub fn event_channel<T>(
+    name: &'static str,
+    capacity: usize,
+) -> (EventSender<T>, mpsc::Receiver<T>) {
+    let (tx, rx) = mpsc::channel(capacity.max(1));
+    (EventSender { name, tx }, rx)
+}
+
+impl<T> EventSender<T> {
+    /// Send an event, waiting for space if the buffer is full.
+    /// Fails immediately if the consumer has been dropped.
+    pub async fn send(&self, event: T) -> Result<(), mpsc::error::SendError<T>> {
+        if self.tx.is_closed() {
+            return Err(mpsc::error::SendError(event));
+        }
+
+        let fill = self.fill_ratio();
+        if fill > BACKPRESSURE_THRESHOLD {
+            warn!(
+                channel = self.name,
+                queued = self.len(),
+                capacity = self.capacity(),
+                "Event channel backpressure"
+            );
+        }
+
+        self.tx.send(event).await
+    }
+
+    /// Configured buffer size
+    pub fn capacity(&self) -> usize {
+        self.tx.max_capacity()
+    }
+
+    /// Events currently buffered
+    pub fn len(&self) -> usize {
+        self.tx.max_capacity() - self.tx.capacity()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn fill_ratio(&self) -> f64 {
+        self.len() as f64 / self.capacity() as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_configured_capacity_is_honored() {
+        let (tx, mut rx) = event_channel::<u32>("test", 5);
+        assert_eq!(tx.capacity(), 5);
+
+        for i in 0..5 {
+            tx.send(i).await.unwrap();
+        }
+        assert_eq!(tx.len(), 5);
+        assert!(tx.tx.try_send(5).is_err());
+
+        assert_eq!(rx.recv().await, Some(0));
+        assert_eq!(tx.len(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_send_fails_fast_when_consumer_dropped() {
+        let (tx, rx) = event_channel::<u32>("test", 5);
+        drop(rx);
+        assert!(tx.send(1).await.is_err());
+    }
+}
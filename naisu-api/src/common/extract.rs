@@ -0,0 +1,113 @@
+//! Request extractors that reject with the `ApiErrorResponse` envelope
+
+use axum::{
+    extract::{rejection::JsonRejection, FromRequest, Request},
+    http::StatusCode,
+    Json,
+};
+use serde::de::DeserializeOwned;
+
+use crate::common::response::ApiErrorResponse;
+
+/// Drop-in replacement for `axum::Json` whose rejections are JSON errors:
+/// 400 for malformed bodies or missing/invalid fields (with the field path),
+/// 413 for bodies over the configured limit, 415 for a missing content type.
+pub struct ApiJson<T>(pub T);
+
+impl<T, S> FromRequest<S> for ApiJson<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = ApiErrorResponse;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        Json::<T>::from_request(req, state)
+            .await
+            .map(|Json(value)| ApiJson(value))
+            .map_err(json_rejection)
+    }
+}
+
+fn json_rejection(rejection: JsonRejection) -> ApiErrorResponse {
+    let (code, message) = match &rejection {
+        JsonRejection::JsonDataError(_) => (StatusCode::BAD_REQUEST, "Invalid request body"),
+        JsonRejection::JsonSyntaxError(_) => (StatusCode::BAD_REQUEST, "Malformed JSON body"),
+        JsonRejection::MissingJsonContentType(_) => (
+            StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            "Expected Content-Type: application/json",
+        ),
+        _ if rejection.status() == StatusCode::PAYLOAD_TOO_LARGE => {
+            (StatusCode::PAYLOAD_TOO_LARGE, "Request body too large")
+        }
+        _ => (rejection.status(), "Failed to read request body"),
+    };
+
+    // body_text includes the serde path, e.g. "...: missing field `direction` at line 1 column 2"
+    ApiErrorResponse::new(message)
+        .with_code(code)
+        .with_error(rejection.body_text())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use axum::{body::Body, http::Request};
+    use naisu_core::SuiNetwork;
+    use tower::ServiceExt;
+
+    use crate::config::Config;
+    use crate::route::app_routes;
+    use crate::state::AppState;
+
+    async fn post_intent(body: String, max_body_bytes: usize) -> (u16, serde_json::Value) {
+        let mut config = Config::load(SuiNetwork::Testnet, &HashMap::new()).unwrap();
+        config.server.max_body_bytes = max_body_bytes;
+        let app = app_routes(AppState::with_config(config));
+
+        let response = app
+            .oneshot(
+                Request::post("/api/v1/intents")
+                    .header("content-type", "application/json")
+                    .body(Body::from(body))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let status = response.status().as_u16();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_oversized_body_is_json_413() {
+        let body = format!(r#"{{"padding":"{}"}}"#, "x".repeat(2048));
+        let (status, json) = post_intent(body, 1024).await;
+
+        assert_eq!(status, 413);
+        assert_eq!(json["success"], false);
+        assert_eq!(json["code"], 413);
+    }
+
+    #[tokio::test]
+    async fn test_missing_field_is_structured_400() {
+        let body = serde_json::json!({
+            "direction": "evm_to_sui",
+            "source_address": "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            "evm_chain": "basesepolia",
+            "input_token": "0x036CbD53842c5426634e7929541eC2318f3dCF7e",
+            "input_amount": "1000000"
+        })
+        .to_string();
+        let (status, json) = post_intent(body, 64 * 1024).await;
+
+        assert_eq!(status, 400);
+        assert_eq!(json["success"], false);
+        assert_eq!(json["message"], "Invalid request body");
+        assert!(json["error"].as_str().unwrap().contains("dest_address"));
+    }
+}
@@ -0,0 +1,105 @@
+//! USD-denominated fee reporting and route comparison
+
+use serde::Serialize;
+
+use crate::common::price::{AssetChain, PriceError, PriceOracle, NATIVE_TOKEN};
+
+/// USD value of `amount` whole units of `token`
+pub async fn usd_value(
+    oracle: &dyn PriceOracle,
+    chain: AssetChain,
+    token: &str,
+    amount: f64,
+) -> Result<f64, PriceError> {
+    Ok(oracle.price_usd(chain, token).await? * amount)
+}
+
+/// A candidate way to deliver funds, before pricing
+#[derive(Debug, Clone)]
+pub struct RouteCandidate {
+    pub name: String,
+    /// Chain the output is received on
+    pub output_chain: AssetChain,
+    pub output_token: String,
+    /// Output in whole token units
+    pub output_amount: f64,
+    /// Chain gas is paid on
+    pub gas_chain: AssetChain,
+    /// Gas cost in whole native units
+    pub gas_native: f64,
+}
+
+/// A route with its USD breakdown
+#[derive(Debug, Clone, Serialize)]
+pub struct RankedRoute {
+    pub name: String,
+    pub output_usd: f64,
+    pub gas_usd: f64,
+    /// `output_usd - gas_usd`
+    pub net_usd: f64,
+}
+
+/// Price every route and sort by net USD received, best first
+pub async fn rank_routes(
+    oracle: &dyn PriceOracle,
+    routes: &[RouteCandidate],
+) -> Result<Vec<RankedRoute>, PriceError> {
+    let mut ranked = Vec::with_capacity(routes.len());
+    for route in routes {
+        let output_usd = usd_value(
+            oracle,
+            route.output_chain,
+            &route.output_token,
+            route.output_amount,
+        )
+        .await?;
+        let gas_usd = usd_value(oracle, route.gas_chain, NATIVE_TOKEN, route.gas_native).await?;
+
+        ranked.push(RankedRoute {
+            name: route.name.clone(),
+            output_usd,
+            gas_usd,
+            net_usd: output_usd - gas_usd,
+        });
+    }
+
+    ranked.sort_by(|a, b| b.net_usd.total_cmp(&a.net_usd));
+    Ok(ranked)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::price::StaticPriceOracle;
+    use naisu_core::EvmChain;
+
+    const USDC: &str = "0xusdc";
+
+    #[tokio::test]
+    async fn test_low_gas_route_ranks_first() {
+        let base = AssetChain::Evm(EvmChain::Base);
+        let oracle = StaticPriceOracle::new()
+            .with_price(base, USDC, 1.0)
+            .with_price(base, NATIVE_TOKEN, 3000.0)
+            .with_price(AssetChain::Sui, NATIVE_TOKEN, 3.0);
+
+        let route = |name: &str, gas_chain, gas_native| RouteCandidate {
+            name: name.to_string(),
+            output_chain: base,
+            output_token: USDC.to_string(),
+            output_amount: 100.0,
+            gas_chain,
+            gas_native,
+        };
+        // Slightly more output but gas paid in ETH costs $3 vs $0.03 in SUI
+        let mut expensive = route("evm_gas", base, 0.001);
+        expensive.output_amount = 100.5;
+        let cheap = route("sui_gas", AssetChain::Sui, 0.01);
+
+        let ranked = rank_routes(&oracle, &[expensive, cheap]).await.unwrap();
+
+        assert_eq!(ranked[0].name, "sui_gas");
+        assert!((ranked[0].gas_usd - 0.03).abs() < 1e-9);
+        assert!((ranked[1].net_usd - 97.5).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,253 @@
+//! Li.Fi API client (token lists, transfer status)
+//!
+//! Chains are always sent as numeric ids (see `EvmChain::lifi_chain_id`) so
+//! mainnets and testnets can't be confused.
+
+use std::collections::HashMap;
+
+use naisu_core::{EvmChain, TokenInfo};
+use naisu_sui::HttpTimeouts;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Public Li.Fi API
+pub const LIFI_API_URL: &str = "https://li.quest/v1";
+
+#[derive(Debug, thiserror::Error)]
+pub enum LiFiError {
+    #[error("HTTP request failed: {0}")]
+    Request(String),
+
+    #[error("Li.Fi API error {status}: {message}")]
+    Api { status: u16, message: String },
+
+    #[error("Failed to parse response: {0}")]
+    Parse(String),
+}
+
+#[derive(Debug, Deserialize)]
+struct TokensResponse {
+    /// Keyed by numeric chain id (as a string)
+    tokens: HashMap<String, Vec<LiFiToken>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LiFiToken {
+    address: String,
+    symbol: String,
+    decimals: u8,
+}
+
+/// Where Li.Fi says a transfer is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum LiFiStatus {
+    /// Li.Fi hasn't indexed the source transaction (yet)
+    NotFound,
+    /// The transaction is not a Li.Fi transfer
+    Invalid,
+    Pending,
+    Done,
+    Failed,
+}
+
+/// A transfer's status, as reported by `GET /status`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+pub struct LiFiTransfer {
+    pub status: LiFiStatus,
+    /// Finer-grained state, e.g. `COMPLETED` or `PARTIAL` for `DONE`
+    pub substatus: Option<String>,
+    /// Destination-chain transaction, once the funds arrived
+    pub receiving_tx_hash: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct StatusResponse {
+    status: LiFiStatus,
+    substatus: Option<String>,
+    receiving: Option<ReceivingLeg>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ReceivingLeg {
+    #[serde(rename = "txHash")]
+    tx_hash: Option<String>,
+}
+
+/// Li.Fi API client
+#[derive(Debug, Clone)]
+pub struct LiFiClient {
+    client: reqwest::Client,
+    base_url: String,
+}
+
+impl LiFiClient {
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self {
+            client: HttpTimeouts::default().build_client(),
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+        }
+    }
+
+    pub fn with_timeouts(mut self, timeouts: HttpTimeouts) -> Self {
+        self.client = timeouts.build_client();
+        self
+    }
+
+    /// Status of the transfer started by source transaction `tx_hash`
+    pub async fn get_status(&self, tx_hash: &str) -> Result<LiFiTransfer, LiFiError> {
+        let response = self
+            .client
+            .get(format!("{}/status", self.base_url))
+            .query(&[("txHash", tx_hash)])
+            .send()
+            .await
+            .map_err(|e| LiFiError::Request(e.to_string()))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(LiFiError::Api {
+                status: status.as_u16(),
+                message: body,
+            });
+        }
+
+        let body: StatusResponse = response
+            .json()
+            .await
+            .map_err(|e| LiFiError::Parse(e.to_string()))?;
+
+        Ok(LiFiTransfer {
+            status: body.status,
+            substatus: body.substatus,
+            receiving_tx_hash: body.receiving.and_then(|r| r.tx_hash),
+        })
+    }
+
+    /// Tokens Li.Fi knows on each of `chains`, keyed by Li.Fi chain id
+    pub async fn get_tokens(
+        &self,
+        chains: &[EvmChain],
+    ) -> Result<HashMap<u64, Vec<TokenInfo>>, LiFiError> {
+        let chain_ids = chains
+            .iter()
+            .map(|c| c.lifi_chain_id().to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+
+        let response = self
+            .client
+            .get(format!("{}/tokens", self.base_url))
+            .query(&[("chains", chain_ids)])
+            .send()
+            .await
+            .map_err(|e| LiFiError::Request(e.to_string()))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(LiFiError::Api {
+                status: status.as_u16(),
+                message: body,
+            });
+        }
+
+        let body: TokensResponse = response
+            .json()
+            .await
+            .map_err(|e| LiFiError::Parse(e.to_string()))?;
+
+        Ok(body
+            .tokens
+            .into_iter()
+            .filter_map(|(id, tokens)| {
+                let id: u64 = id.parse().ok()?;
+                let chain = EvmChain::from_lifi_chain_id(id)?;
+                let tokens = tokens
+                    .into_iter()
+                    .map(|t| TokenInfo {
+                        symbol: t.symbol,
+                        address: t.address,
+                        decimals: t.decimals,
+                        chain,
+                    })
+                    .collect();
+                Some((id, tokens))
+            })
+            .collect())
+    }
+
+    /// Resolve a token on one chain by symbol or address (case-insensitive)
+    pub async fn find_token(
+        &self,
+        chain: EvmChain,
+        symbol_or_address: &str,
+    ) -> Result<Option<TokenInfo>, LiFiError> {
+        let mut tokens = self.get_tokens(&[chain]).await?;
+        Ok(tokens
+            .remove(&chain.lifi_chain_id())
+            .and_then(|tokens| find_in(tokens, symbol_or_address)))
+    }
+}
+
+fn find_in(tokens: Vec<TokenInfo>, symbol_or_address: &str) -> Option<TokenInfo> {
+    let needle = symbol_or_address.trim();
+    tokens
+        .into_iter()
+        .find(|t| t.address.eq_ignore_ascii_case(needle) || t.symbol.eq_ignore_ascii_case(needle))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_tokens_keep_per_chain_structure() {
+        let server = MockServer::start().await;
+        let body = serde_json::json!({
+            "tokens": {
+                "8453": [
+                    { "address": "0x833589fCD6eDb6E08f4c7C32D4f71b54bdA02913", "symbol": "USDC", "decimals": 6, "chainId": 8453 },
+                    { "address": "0x4200000000000000000000000000000000000006", "symbol": "WETH", "decimals": 18, "chainId": 8453 }
+                ],
+                "84532": [
+                    { "address": "0x036CbD53842c5426634e7929541eC2318f3dCF7e", "symbol": "USDC", "decimals": 6, "chainId": 84532 }
+                ]
+            }
+        });
+        Mock::given(method("GET"))
+            .and(path("/tokens"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(body))
+            .mount(&server)
+            .await;
+
+        let client = LiFiClient::new(server.uri());
+        let tokens = client
+            .get_tokens(&[EvmChain::Base, EvmChain::BaseSepolia])
+            .await
+            .unwrap();
+
+        assert_eq!(tokens[&8453].len(), 2);
+        assert_eq!(tokens[&84532].len(), 1);
+
+        // USDC exists on both chains; the Base Sepolia entry must be returned
+        let usdc = client
+            .find_token(EvmChain::BaseSepolia, "usdc")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(usdc.address, "0x036CbD53842c5426634e7929541eC2318f3dCF7e");
+        assert_eq!(usdc.chain, EvmChain::BaseSepolia);
+
+        let weth = find_in(
+            tokens[&8453].clone(),
+            "0x4200000000000000000000000000000000000006",
+        )
+        .unwrap();
+        assert_eq!(weth.symbol, "WETH");
+        assert!(find_in(tokens[&84532].clone(), "WETH").is_none());
+    }
+}
@@ -0,0 +1,550 @@
+//! Hook event listener
+//!
+//! Streams the hook's `IntentCreated` logs either from an `eth_subscribe`
+//! WebSocket subscription or by polling `eth_getLogs`. Both paths advance one
+//! [`BlockCursor`], so switching from WebSocket to polling mid-stream neither
+//! skips nor repeats a log. A [`Checkpoint`] on disk lets a restarted
+//! listener resume at the last ingested block.
+
+use std::collections::{HashSet, VecDeque};
+use std::path::PathBuf;
+use std::time::Duration;
+
+use futures_util::{SinkExt, StreamExt};
+use naisu_core::{EvmLog, IntentCreatedEvent};
+use naisu_sui::{HookLogClient, HookLogError};
+use serde::Deserialize;
+use serde_json::json;
+use tokio_tungstenite::{connect_async, tungstenite::Message};
+use tracing::{info, warn};
+
+use crate::common::events::EventSender;
+use crate::config::EvmConfig;
+
+/// Largest block range requested by one `eth_getLogs` poll
+const MAX_POLL_RANGE: u64 = 1_000;
+
+/// `Auto` falls back to polling after `ws_max_failures` WebSocket failures
+/// within this long
+const WS_FAILURE_WINDOW: Duration = Duration::from_secs(5 * 60);
+
+/// Longest wait between attempts to read the chain head at startup
+const MAX_HEAD_BACKOFF: Duration = Duration::from_secs(60);
+
+/// How hook logs are received
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ListenerMode {
+    /// WebSocket when an endpoint is configured, polling once it keeps failing
+    #[default]
+    Auto,
+    WebSocket,
+    Polling,
+}
+
+impl std::str::FromStr for ListenerMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "auto" => Ok(ListenerMode::Auto),
+            "ws" | "websocket" => Ok(ListenerMode::WebSocket),
+            "poll" | "polling" => Ok(ListenerMode::Polling),
+            other => Err(format!("unknown listener mode: {}", other)),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ListenerError {
+    /// The subscription was never established
+    #[error("WebSocket connection failed: {0}")]
+    Connect(String),
+
+    /// An established subscription broke
+    #[error("WebSocket subscription dropped: {0}")]
+    Dropped(String),
+
+    #[error("No WebSocket endpoint configured")]
+    NoWebSocketUrl,
+
+    #[error(transparent)]
+    Rpc(#[from] HookLogError),
+
+    #[error("Event consumer dropped")]
+    ConsumerClosed,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct LogKey {
+    transaction_hash: Option<String>,
+    topics: Vec<String>,
+    data: String,
+}
+
+/// Next block to read, plus the logs of that block already delivered (a
+/// WebSocket may stop halfway through a block)
+#[derive(Debug, Default)]
+pub struct BlockCursor {
+    next_block: u64,
+    delivered: HashSet<LogKey>,
+}
+
+impl BlockCursor {
+    pub fn new(start_block: u64) -> Self {
+        Self {
+            next_block: start_block,
+            delivered: HashSet::new(),
+        }
+    }
+
+    pub fn next_block(&self) -> u64 {
+        self.next_block
+    }
+
+    /// Record `log` and return whether it is new. Logs before the cursor, or
+    /// without a block number (pending), are rejected.
+    pub fn accept(&mut self, log: &EvmLog) -> bool {
+        let Some(block) = log.block_number.as_deref().and_then(parse_hex) else {
+            return false;
+        };
+        if block < self.next_block {
+            return false;
+        }
+        if block > self.next_block {
+            self.next_block = block;
+            self.delivered.clear();
+        }
+        self.delivered.insert(LogKey {
+            transaction_hash: log.transaction_hash.clone(),
+            topics: log.topics.clone(),
+            data: log.data.clone(),
+        })
+    }
+
+    /// Every log up to and including `block` has been delivered
+    pub fn complete_through(&mut self, block: u64) {
+        if block >= self.next_block {
+            self.next_block = block + 1;
+            self.delivered.clear();
+        }
+    }
+}
+
+fn parse_hex(value: &str) -> Option<u64> {
+    u64::from_str_radix(value.trim_start_matches("0x"), 16).ok()
+}
+
+/// WebSocket failures seen within a sliding window. A subscription that
+/// connects and then drops counts too, so a flapping endpoint is given up on
+/// just like one that never connects.
+#[derive(Debug)]
+struct FailureWindow {
+    window: Duration,
+    max_failures: u32,
+    failures: VecDeque<tokio::time::Instant>,
+}
+
+impl FailureWindow {
+    fn new(window: Duration, max_failures: u32) -> Self {
+        Self {
+            window,
+            max_failures,
+            failures: VecDeque::new(),
+        }
+    }
+
+    /// Record a failure at `now`; true once `max_failures` fall in the window
+    fn record(&mut self, now: tokio::time::Instant) -> bool {
+        while self
+            .failures
+            .front()
+            .is_some_and(|&at| now.duration_since(at) >= self.window)
+        {
+            self.failures.pop_front();
+        }
+        self.failures.push_back(now);
+        self.failures.len() >= self.max_failures as usize
+    }
+
+    fn len(&self) -> usize {
+        self.failures.len()
+    }
+}
+
+/// Last hook block whose logs were ingested, kept in a file so a restarted
+/// listener resumes there instead of at the chain head. The block is read
+/// again on resume; ingestion is idempotent.
+#[derive(Debug, Clone)]
+pub struct Checkpoint {
+    path: PathBuf,
+}
+
+impl Checkpoint {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// The saved block, `None` if nothing was saved yet
+    pub async fn load(&self) -> std::io::Result<Option<u64>> {
+        match tokio::fs::read_to_string(&self.path).await {
+            Ok(text) => text.trim().parse().map(Some).map_err(|e| {
+                std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    format!("corrupt checkpoint {}: {}", self.path.display(), e),
+                )
+            }),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Replace the saved block (through a temporary file and a rename)
+    pub async fn save(&self, block: u64) -> std::io::Result<()> {
+        let tmp = self.path.with_extension("tmp");
+        tokio::fs::write(&tmp, block.to_string()).await?;
+        tokio::fs::rename(&tmp, &self.path).await
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct SubscriptionReply {
+    #[serde(default)]
+    result: Option<serde_json::Value>,
+    #[serde(default)]
+    error: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Notification {
+    params: NotificationParams,
+}
+
+#[derive(Debug, Deserialize)]
+struct NotificationParams {
+    result: SubscribedLog,
+}
+
+#[derive(Debug, Deserialize)]
+struct SubscribedLog {
+    #[serde(flatten)]
+    log: EvmLog,
+    /// Set when a reorg drops a previously sent log
+    #[serde(default)]
+    removed: bool,
+}
+
+/// Receives hook logs according to the configured [`ListenerMode`]
+#[derive(Debug, Clone)]
+pub struct HookListener {
+    mode: ListenerMode,
+    http: HookLogClient,
+    ws_url: Option<String>,
+    hook_address: String,
+    poll_interval: Duration,
+    ws_max_failures: u32,
+}
+
+impl HookListener {
+    /// A `ws(s)://` RPC URL doubles as the WebSocket endpoint; polling then
+    /// uses the same host over `http(s)://`
+    pub fn new(evm: &EvmConfig) -> Self {
+        let rpc_is_ws = evm.rpc_url.starts_with("ws://") || evm.rpc_url.starts_with("wss://");
+        let http_url = if rpc_is_ws {
+            evm.rpc_url.replacen("ws", "http", 1)
+        } else {
+            evm.rpc_url.clone()
+        };
+        let ws_url = evm
+            .ws_url
+            .clone()
+            .or_else(|| rpc_is_ws.then(|| evm.rpc_url.clone()));
+
+        Self {
+            mode: evm.listener_mode,
+            http: HookLogClient::new(http_url, &evm.hook_address),
+            ws_url,
+            hook_address: evm.hook_address.clone(),
+            poll_interval: evm.poll_interval,
+            ws_max_failures: evm.ws_max_failures.max(1),
+        }
+    }
+
+    /// Current chain head, used as the start block when none is configured
+    pub async fn head(&self) -> Result<u64, HookLogError> {
+        self.http.block_number().await
+    }
+
+    /// [`HookListener::head`], retried with exponential backoff (from
+    /// `poll_interval` up to a minute) until the RPC answers
+    pub async fn wait_for_head(&self) -> u64 {
+        let mut delay = self.poll_interval;
+        loop {
+            match self.head().await {
+                Ok(head) => return head,
+                Err(e) => {
+                    warn!(error = %e, retry_in = ?delay, "Hook listener could not read the chain head");
+                    tokio::time::sleep(delay).await;
+                    delay = (delay * 2).min(MAX_HEAD_BACKOFF);
+                }
+            }
+        }
+    }
+
+    /// Deliver logs from `cursor` on until the consumer goes away
+    pub async fn run(&self, cursor: &mut BlockCursor, tx: &EventSender<EvmLog>) -> ListenerError {
+        match (self.mode, self.ws_url.as_deref()) {
+            (ListenerMode::Polling, _) | (ListenerMode::Auto, None) => self.poll(cursor, tx).await,
+            (ListenerMode::WebSocket, None) => ListenerError::NoWebSocketUrl,
+            (ListenerMode::WebSocket, Some(url)) => loop {
+                match self.subscribe(url, cursor, tx).await {
+                    ListenerError::ConsumerClosed => return ListenerError::ConsumerClosed,
+                    e => warn!(error = %e, "Hook WebSocket failed, reconnecting"),
+                }
+                tokio::time::sleep(self.poll_interval).await;
+            },
+            (ListenerMode::Auto, Some(url)) => {
+                let mut failures = FailureWindow::new(WS_FAILURE_WINDOW, self.ws_max_failures);
+                loop {
+                    let e = self.subscribe(url, cursor, tx).await;
+                    if let ListenerError::ConsumerClosed = e {
+                        return e;
+                    }
+                    if failures.record(tokio::time::Instant::now()) {
+                        break;
+                    }
+                    warn!(error = %e, failures = failures.len(), "Hook WebSocket failed, reconnecting");
+                    tokio::time::sleep(self.poll_interval).await;
+                }
+                warn!(
+                    failures = failures.len(),
+                    next_block = cursor.next_block(),
+                    "Hook WebSocket unhealthy, falling back to polling"
+                );
+                self.poll(cursor, tx).await
+            }
+        }
+    }
+
+    async fn poll(&self, cursor: &mut BlockCursor, tx: &EventSender<EvmLog>) -> ListenerError {
+        loop {
+            match self.poll_once(cursor, tx).await {
+                Err(ListenerError::ConsumerClosed) => return ListenerError::ConsumerClosed,
+                Err(e) => warn!(error = %e, "Hook log poll failed"),
+                Ok(()) => {}
+            }
+            tokio::time::sleep(self.poll_interval).await;
+        }
+    }
+
+    /// Deliver every log from the cursor up to the current head
+    async fn poll_once(
+        &self,
+        cursor: &mut BlockCursor,
+        tx: &EventSender<EvmLog>,
+    ) -> Result<(), ListenerError> {
+        let head = self.http.block_number().await?;
+        while cursor.next_block() <= head {
+            let from = cursor.next_block();
+            let to = head.min(from + MAX_POLL_RANGE - 1);
+            for log in self.http.backfill(from, to).await? {
+                deliver(cursor, tx, log).await?;
+            }
+            cursor.complete_through(to);
+        }
+        Ok(())
+    }
+
+    /// Subscribe over WebSocket, catch up on the blocks before the
+    /// subscription, then stream. Only returns on failure.
+    async fn subscribe(
+        &self,
+        url: &str,
+        cursor: &mut BlockCursor,
+        tx: &EventSender<EvmLog>,
+    ) -> ListenerError {
+        let (mut ws, _) = match connect_async(url).await {
+            Ok(connection) => connection,
+            Err(e) => return ListenerError::Connect(e.to_string()),
+        };
+
+        let request = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "eth_subscribe",
+            "params": ["logs", {
+                "address": self.hook_address,
+                "topics": [IntentCreatedEvent::topic0()],
+            }],
+        });
+        if let Err(e) = ws.send(Message::text(request.to_string())).await {
+            return ListenerError::Connect(e.to_string());
+        }
+        match ws.next().await {
+            Some(Ok(Message::Text(text))) => {
+                match serde_json::from_str::<SubscriptionReply>(&text) {
+                    Ok(SubscriptionReply {
+                        result: Some(_),
+                        error: None,
+                    }) => {}
+                    _ => return ListenerError::Connect(format!("subscription refused: {}", text)),
+                }
+            }
+            other => return ListenerError::Connect(format!("no subscription reply: {:?}", other)),
+        }
+        info!(
+            url,
+            next_block = cursor.next_block(),
+            "Subscribed to hook logs"
+        );
+
+        // Logs emitted before the subscription started are only reachable by polling
+        if let Err(e) = self.poll_once(cursor, tx).await {
+            return e;
+        }
+
+        while let Some(message) = ws.next().await {
+            let text = match message {
+                Ok(Message::Text(text)) => text,
+                Ok(Message::Close(frame)) => return ListenerError::Dropped(format!("{:?}", frame)),
+                Ok(_) => continue,
+                Err(e) => return ListenerError::Dropped(e.to_string()),
+            };
+            let Ok(notification) = serde_json::from_str::<Notification>(&text) else {
+                continue;
+            };
+            let SubscribedLog { log, removed } = notification.params.result;
+            if removed {
+                warn!(tx = ?log.transaction_hash, "Hook log removed by reorg");
+                continue;
+            }
+            if let Err(e) = deliver(cursor, tx, log).await {
+                return e;
+            }
+        }
+        ListenerError::Dropped("stream ended".to_string())
+    }
+}
+
+async fn deliver(
+    cursor: &mut BlockCursor,
+    tx: &EventSender<EvmLog>,
+    log: EvmLog,
+) -> Result<(), ListenerError> {
+    if cursor.accept(&log) {
+        tx.send(log)
+            .await
+            .map_err(|_| ListenerError::ConsumerClosed)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::events::event_channel;
+    use crate::config::Config;
+    use naisu_core::SuiNetwork;
+    use std::collections::HashMap;
+    use wiremock::matchers::{body_partial_json, method};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn log_at(block: u64, tx: &str) -> EvmLog {
+        EvmLog {
+            address: "0xhook".to_string(),
+            topics: vec![IntentCreatedEvent::topic0()],
+            data: "0x".to_string(),
+            transaction_hash: Some(tx.to_string()),
+            block_number: Some(format!("0x{:x}", block)),
+        }
+    }
+
+    #[test]
+    fn test_cursor_skips_logs_already_delivered() {
+        let mut cursor = BlockCursor::new(10);
+        assert!(!cursor.accept(&log_at(9, "0xold")));
+
+        // A WebSocket delivered half of block 12, then died
+        assert!(cursor.accept(&log_at(12, "0xa")));
+        assert_eq!(cursor.next_block(), 12);
+
+        // Polling re-reads block 12 from the start
+        assert!(!cursor.accept(&log_at(12, "0xa")));
+        assert!(cursor.accept(&log_at(12, "0xb")));
+        cursor.complete_through(12);
+        assert_eq!(cursor.next_block(), 13);
+        assert!(!cursor.accept(&log_at(12, "0xb")));
+    }
+
+    #[test]
+    fn test_drops_within_the_window_add_up() {
+        let t0 = tokio::time::Instant::now();
+        let minute = Duration::from_secs(60);
+        let mut failures = FailureWindow::new(Duration::from_secs(5 * 60), 3);
+
+        assert!(!failures.record(t0));
+        assert!(!failures.record(t0 + minute));
+        // The first failure has aged out by now
+        assert!(!failures.record(t0 + 5 * minute));
+        assert!(failures.record(t0 + 5 * minute + minute / 2));
+    }
+
+    #[tokio::test]
+    async fn test_checkpoint_round_trips() {
+        let dir = tempfile::tempdir().unwrap();
+        let checkpoint = Checkpoint::new(dir.path().join("cursor"));
+
+        assert_eq!(checkpoint.load().await.unwrap(), None);
+        checkpoint.save(0x2a).await.unwrap();
+        checkpoint.save(0x2b).await.unwrap();
+        assert_eq!(checkpoint.load().await.unwrap(), Some(0x2b));
+    }
+
+    #[tokio::test]
+    async fn test_auto_falls_back_to_polling_when_websocket_fails() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(body_partial_json(json!({ "method": "eth_blockNumber" })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "jsonrpc": "2.0", "id": 1, "result": "0x14",
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(body_partial_json(json!({
+                "method": "eth_getLogs",
+                "params": [{ "fromBlock": "0x10", "toBlock": "0x14" }],
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "jsonrpc": "2.0", "id": 1, "result": [log_at(0x12, "0xpolled")],
+            })))
+            .mount(&server)
+            .await;
+
+        let mut evm = Config::load(SuiNetwork::Testnet, &HashMap::new())
+            .unwrap()
+            .evm;
+        evm.listener_mode = ListenerMode::Auto;
+        evm.rpc_url = server.uri();
+        // Nothing listens on port 1
+        evm.ws_url = Some("ws://127.0.0.1:1".to_string());
+        evm.ws_max_failures = 2;
+        evm.poll_interval = Duration::from_millis(10);
+
+        let listener = HookListener::new(&evm);
+        let (tx, mut rx) = event_channel("test", 4);
+        let task = tokio::spawn(async move {
+            let mut cursor = BlockCursor::new(0x10);
+            listener.run(&mut cursor, &tx).await
+        });
+
+        let log = tokio::time::timeout(Duration::from_secs(5), rx.recv())
+            .await
+            .expect("polled log within timeout")
+            .unwrap();
+        assert_eq!(log.transaction_hash.as_deref(), Some("0xpolled"));
+
+        drop(rx);
+        task.abort();
+    }
+}
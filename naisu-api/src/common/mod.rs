@@ -1,2 +1,13 @@
+pub mod balance;
+pub mod events;
+pub mod extract;
+pub mod fees;
+pub mod lifi;
+pub mod listener;
+pub mod outbox;
+pub mod price;
+pub mod quote;
+pub mod receipt;
 pub mod response;
 pub mod server;
+pub mod transitions;
@@ -0,0 +1,590 @@
+//! Transactional outbox for side effects (webhooks, relay submissions)
+//!
+//! Side effects are staged together with the state change that caused them
+//! (see `AppState::update_intent_with_effects`). The outbox file is written
+//! once the intents lock is released, and a failed write rolls the change
+//! back, so a state change is never kept without its effects or vice versa.
+//! A worker drains the outbox with at-least-once delivery; every entry
+//! carries a dedup key the receiver uses to ignore redeliveries.
+//!
+//! An outbox opened on a file rewrites it after every change, so pending
+//! effects survive a restart. Failed deliveries back off exponentially; an
+//! entry that keeps failing, or that no deliverer can perform, moves to the
+//! dead letters for an operator to inspect.
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use tokio::task::JoinHandle;
+
+use crate::state::AppState;
+
+/// Delay between outbox drains
+pub const DRAIN_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Delay before the first retry; doubled on every further failure
+const BASE_BACKOFF_SECS: u64 = 5;
+
+/// Longest delay between retries
+const MAX_BACKOFF_SECS: u64 = 60 * 60;
+
+/// A side effect to perform after an intent state change
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum SideEffect {
+    /// POST `payload` to `url`
+    Webhook {
+        url: String,
+        payload: serde_json::Value,
+    },
+    /// Submit `receiveMessage` for a completed CCTP attestation
+    RelayReceiveMessage {
+        intent_id: String,
+        message: String,
+        attestation: String,
+    },
+}
+
+/// Queued side effect
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OutboxEntry {
+    /// Stable key for receiver-side deduplication (also the enqueue dedup key)
+    pub dedup_key: String,
+    pub effect: SideEffect,
+    pub attempts: u32,
+    pub last_error: Option<String>,
+    /// Unix secs before which the entry is not retried
+    #[serde(default)]
+    pub next_attempt_at: u64,
+}
+
+/// Pending side effects, in enqueue order, and the ones given up on
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Outbox {
+    entries: BTreeMap<u64, OutboxEntry>,
+    dead_letters: Vec<OutboxEntry>,
+    next_seq: u64,
+    /// Backing file; `None` keeps the outbox in memory
+    #[serde(skip)]
+    path: Option<PathBuf>,
+    /// Failed deliveries before an entry is dead-lettered
+    #[serde(skip)]
+    max_attempts: u32,
+}
+
+impl Outbox {
+    /// In-memory outbox; pending effects are lost on restart
+    pub fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts,
+            ..Self::default()
+        }
+    }
+
+    /// Load the outbox saved at `path`, or start empty if there is none yet.
+    /// Every change is written back to `path`.
+    pub async fn open(path: impl Into<PathBuf>, max_attempts: u32) -> std::io::Result<Self> {
+        let path = path.into();
+        let mut outbox: Self = match tokio::fs::read(&path).await {
+            Ok(bytes) => serde_json::from_slice(&bytes).map_err(|e| {
+                std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    format!("corrupt outbox {}: {}", path.display(), e),
+                )
+            })?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Self::default(),
+            Err(e) => return Err(e),
+        };
+        outbox.path = Some(path);
+        outbox.max_attempts = max_attempts;
+        Ok(outbox)
+    }
+
+    /// Queue an effect; a no-op if `dedup_key` is already pending
+    pub async fn enqueue(
+        &mut self,
+        dedup_key: impl Into<String>,
+        effect: SideEffect,
+    ) -> std::io::Result<()> {
+        if self.stage(vec![(dedup_key.into(), effect)]).is_empty() {
+            return Ok(());
+        }
+        self.save().await
+    }
+
+    /// Add `effects` in memory only, skipping dedup keys already pending.
+    /// Returns the sequence numbers added, for [`Outbox::unstage`] should
+    /// the following [`Outbox::save`] fail.
+    pub(crate) fn stage(&mut self, effects: Vec<(String, SideEffect)>) -> Vec<u64> {
+        let mut staged = Vec::new();
+        for (dedup_key, effect) in effects {
+            if self.entries.values().any(|e| e.dedup_key == dedup_key) {
+                continue;
+            }
+            self.entries.insert(
+                self.next_seq,
+                OutboxEntry {
+                    dedup_key,
+                    effect,
+                    attempts: 0,
+                    last_error: None,
+                    next_attempt_at: 0,
+                },
+            );
+            staged.push(self.next_seq);
+            self.next_seq += 1;
+        }
+        staged
+    }
+
+    /// Drop entries added by [`Outbox::stage`]
+    pub(crate) fn unstage(&mut self, staged: &[u64]) {
+        for seq in staged {
+            self.entries.remove(seq);
+        }
+    }
+
+    /// Pending entries with their sequence numbers
+    pub fn pending(&self) -> Vec<(u64, OutboxEntry)> {
+        self.entries
+            .iter()
+            .map(|(seq, entry)| (*seq, entry.clone()))
+            .collect()
+    }
+
+    /// Entries that failed for good, oldest first
+    pub fn dead_letters(&self) -> &[OutboxEntry] {
+        &self.dead_letters
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Pending entries whose backoff has elapsed at `now` (unix secs)
+    fn due(&self, now: u64) -> Vec<(u64, OutboxEntry)> {
+        self.entries
+            .iter()
+            .filter(|(_, entry)| entry.next_attempt_at <= now)
+            .map(|(seq, entry)| (*seq, entry.clone()))
+            .collect()
+    }
+
+    async fn complete(&mut self, seq: u64) -> std::io::Result<()> {
+        self.entries.remove(&seq);
+        self.save().await
+    }
+
+    /// Count a failed delivery at `now`: schedule the retry, or dead-letter
+    /// the entry once it is out of attempts. Returns whether it was
+    /// dead-lettered.
+    async fn record_failure(&mut self, seq: u64, error: String, now: u64) -> std::io::Result<bool> {
+        let Some(entry) = self.entries.get_mut(&seq) else {
+            return Ok(false);
+        };
+        entry.attempts += 1;
+        entry.last_error = Some(error);
+        entry.next_attempt_at = now + backoff_secs(entry.attempts);
+        if entry.attempts < self.max_attempts {
+            self.save().await?;
+            return Ok(false);
+        }
+        self.dead_letter(seq, None).await?;
+        Ok(true)
+    }
+
+    /// Give up on `seq`, recording `error` if given
+    async fn dead_letter(&mut self, seq: u64, error: Option<String>) -> std::io::Result<()> {
+        if let Some(mut entry) = self.entries.remove(&seq) {
+            if let Some(error) = error {
+                entry.attempts += 1;
+                entry.last_error = Some(error);
+            }
+            self.dead_letters.push(entry);
+        }
+        self.save().await
+    }
+
+    /// Rewrite the backing file (through a temporary file and a rename)
+    pub(crate) async fn save(&self) -> std::io::Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        let json = serde_json::to_vec(self).map_err(std::io::Error::other)?;
+        let tmp = path.with_extension("tmp");
+        tokio::fs::write(&tmp, json).await?;
+        tokio::fs::rename(&tmp, path).await
+    }
+}
+
+/// Seconds to wait after the `attempts`-th failure
+fn backoff_secs(attempts: u32) -> u64 {
+    BASE_BACKOFF_SECS
+        .saturating_mul(1 << attempts.saturating_sub(1).min(20))
+        .min(MAX_BACKOFF_SECS)
+}
+
+/// Why a delivery failed
+#[derive(Debug, thiserror::Error)]
+pub enum DeliveryError {
+    /// Worth retrying later (timeouts, 5xx, rate limits)
+    #[error("{0}")]
+    Retry(String),
+    /// Will never succeed (unsupported effect, rejected request); the entry
+    /// is dead-lettered straight away
+    #[error("{0}")]
+    Reject(String),
+}
+
+/// Performs side effects. Must tolerate redelivery of the same `dedup_key`.
+#[async_trait::async_trait]
+pub trait EffectDeliverer: Send + Sync {
+    async fn deliver(&self, entry: &OutboxEntry) -> Result<(), DeliveryError>;
+}
+
+/// Delivers `Webhook` effects over HTTP with an `Idempotency-Key` header.
+/// Nothing delivers `RelayReceiveMessage` yet, so those are rejected.
+pub struct WebhookDeliverer {
+    client: reqwest::Client,
+}
+
+impl WebhookDeliverer {
+    pub fn new() -> Self {
+        Self {
+            client: reqwest::Client::builder()
+                .timeout(Duration::from_secs(10))
+                .build()
+                .expect("Failed to build HTTP client"),
+        }
+    }
+}
+
+impl Default for WebhookDeliverer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait::async_trait]
+impl EffectDeliverer for WebhookDeliverer {
+    async fn deliver(&self, entry: &OutboxEntry) -> Result<(), DeliveryError> {
+        let SideEffect::Webhook { url, payload } = &entry.effect else {
+            return Err(DeliveryError::Reject(
+                "no deliverer for this effect".to_string(),
+            ));
+        };
+
+        let response = self
+            .client
+            .post(url)
+            .header("Idempotency-Key", &entry.dedup_key)
+            .json(payload)
+            .send()
+            .await
+            .map_err(|e| DeliveryError::Retry(e.to_string()))?;
+
+        let status = response.status();
+        if status.is_success() {
+            Ok(())
+        } else if status.is_server_error()
+            || status == reqwest::StatusCode::TOO_MANY_REQUESTS
+            || status == reqwest::StatusCode::REQUEST_TIMEOUT
+        {
+            Err(DeliveryError::Retry(format!("{} answered {}", url, status)))
+        } else {
+            Err(DeliveryError::Reject(format!(
+                "{} answered {}",
+                url, status
+            )))
+        }
+    }
+}
+
+/// Attempt every entry due at `now` (unix secs) once. Entries are removed
+/// only after a successful delivery, so a crash mid-drain redelivers on the
+/// next run. Returns the number delivered.
+pub async fn drain_outbox(state: &AppState, deliverer: &dyn EffectDeliverer, now: u64) -> usize {
+    let due = state.outbox.read().await.due(now);
+
+    let mut delivered = 0;
+    for (seq, entry) in due {
+        let result = deliverer.deliver(&entry).await;
+        let mut outbox = state.outbox.write().await;
+        let saved = match result {
+            Ok(()) => {
+                delivered += 1;
+                outbox.complete(seq).await
+            }
+            Err(DeliveryError::Retry(error)) => {
+                tracing::warn!(key = %entry.dedup_key, attempts = entry.attempts + 1, %error, "Outbox delivery failed");
+                match outbox.record_failure(seq, error, now).await {
+                    Ok(true) => {
+                        tracing::error!(key = %entry.dedup_key, "Outbox entry out of attempts, dead-lettered");
+                        Ok(())
+                    }
+                    Ok(false) => Ok(()),
+                    Err(e) => Err(e),
+                }
+            }
+            Err(DeliveryError::Reject(error)) => {
+                tracing::error!(key = %entry.dedup_key, %error, "Outbox entry rejected, dead-lettered");
+                outbox.dead_letter(seq, Some(error)).await
+            }
+        };
+        if let Err(error) = saved {
+            tracing::error!(%error, "Failed to save the outbox");
+        }
+    }
+    delivered
+}
+
+/// Drain the outbox every `interval`
+pub fn spawn_outbox_worker(
+    state: AppState,
+    deliverer: Box<dyn EffectDeliverer>,
+    interval: Duration,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            let now = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or_default();
+            drain_outbox(&state, deliverer.as_ref(), now).await;
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, HashSet};
+    use std::sync::Mutex;
+
+    use naisu_core::{EvmChain, Intent, IntentStatus, SuiNetwork};
+
+    use super::*;
+    use crate::config::Config;
+
+    const NOW: u64 = 1_770_000_000;
+
+    /// Receiver that applies each dedup key at most once
+    #[derive(Default)]
+    struct RecordingReceiver {
+        seen: Mutex<HashSet<String>>,
+        applied: Mutex<u32>,
+    }
+
+    #[async_trait::async_trait]
+    impl EffectDeliverer for RecordingReceiver {
+        async fn deliver(&self, entry: &OutboxEntry) -> Result<(), DeliveryError> {
+            if self.seen.lock().unwrap().insert(entry.dedup_key.clone()) {
+                *self.applied.lock().unwrap() += 1;
+            }
+            Ok(())
+        }
+    }
+
+    /// Receiver that is always down
+    struct Unreachable;
+
+    #[async_trait::async_trait]
+    impl EffectDeliverer for Unreachable {
+        async fn deliver(&self, _: &OutboxEntry) -> Result<(), DeliveryError> {
+            Err(DeliveryError::Retry("connection refused".to_string()))
+        }
+    }
+
+    fn webhook(intent: &Intent) -> SideEffect {
+        SideEffect::Webhook {
+            url: "http://hooks.invalid/intents".to_string(),
+            payload: serde_json::json!({ "id": intent.id, "status": intent.status }),
+        }
+    }
+
+    fn relay() -> SideEffect {
+        SideEffect::RelayReceiveMessage {
+            intent_id: "intent-1".to_string(),
+            message: "0x01".to_string(),
+            attestation: "0x02".to_string(),
+        }
+    }
+
+    const WEBHOOK_URL: &str = "http://hooks.invalid/intents";
+
+    /// State notifying `WEBHOOK_URL`, holding one pending intent
+    async fn state_with_intent(outbox: Outbox) -> AppState {
+        let mut config = Config::load(SuiNetwork::Testnet, &HashMap::new()).unwrap();
+        config.intents.webhook_url = Some(WEBHOOK_URL.to_string());
+        let state = AppState::with_config(config).with_outbox(outbox);
+        state
+            .upsert_intent(Intent::new_sui_to_evm(
+                "intent-1".to_string(),
+                "0x2".to_string(),
+                "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed".to_string(),
+                EvmChain::BaseSepolia,
+                "usdc".to_string(),
+                "1000000".to_string(),
+            ))
+            .await
+            .unwrap();
+        state
+    }
+
+    #[tokio::test]
+    async fn test_crash_before_delivery_delivers_exactly_once() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("outbox.json");
+        let state = state_with_intent(Outbox::open(&path, 5).await.unwrap()).await;
+
+        // Only a terminal status is announced
+        state
+            .update_intent_status("intent-1", IntentStatus::Bridging)
+            .await
+            .unwrap();
+        assert!(state.outbox.read().await.is_empty());
+
+        // State change and its webhook are recorded together
+        state
+            .update_intent_status("intent-1", IntentStatus::Completed)
+            .await
+            .unwrap();
+        assert_eq!(
+            state.get_intent("intent-1").await.unwrap().status,
+            IntentStatus::Completed
+        );
+        let (_, entry) = state.outbox.read().await.pending().remove(0);
+        assert_eq!(entry.dedup_key, "intent-1:completed");
+        let SideEffect::Webhook { url, payload } = entry.effect else {
+            panic!("expected a webhook");
+        };
+        assert_eq!(url, WEBHOOK_URL);
+        assert_eq!(payload["status"], "completed");
+
+        // "Crash": the process goes away before delivering; a new one
+        // rebuilds the outbox from the file
+        drop(state);
+        let restarted = state_with_intent(Outbox::open(&path, 5).await.unwrap()).await;
+        assert_eq!(restarted.outbox.read().await.len(), 1);
+
+        // A delivery lands but the worker dies before recording it
+        let receiver = RecordingReceiver::default();
+        let (_, entry) = restarted.outbox.read().await.pending().remove(0);
+        receiver.deliver(&entry).await.unwrap();
+
+        // Next drain redelivers; the dedup key keeps the effect applied once
+        assert_eq!(drain_outbox(&restarted, &receiver, NOW).await, 1);
+        assert_eq!(drain_outbox(&restarted, &receiver, NOW).await, 0);
+        assert_eq!(*receiver.applied.lock().unwrap(), 1);
+        assert!(Outbox::open(&path, 5).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_unsaved_effects_roll_back_the_change() {
+        let dir = tempfile::tempdir().unwrap();
+        // The directory is missing, so the outbox file can't be written
+        let path = dir.path().join("missing").join("outbox.json");
+        let state = state_with_intent(Outbox::open(&path, 5).await.unwrap()).await;
+
+        let err = state
+            .update_intent_status("intent-1", IntentStatus::Failed)
+            .await
+            .unwrap_err();
+        assert_eq!(err.intent_id, "intent-1");
+
+        let intent = state.get_intent("intent-1").await.unwrap();
+        assert_eq!(intent.status, IntentStatus::Pending);
+        assert!(state.outbox.read().await.is_empty());
+        assert!(state
+            .transitions
+            .read()
+            .await
+            .since("intent-1", None)
+            .is_empty());
+
+        // Changes without effects don't touch the file
+        assert!(state
+            .update_intent_status("intent-1", IntentStatus::Bridging)
+            .await
+            .unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_failures_back_off_then_dead_letter() {
+        let state = state_with_intent(Outbox::new(3)).await;
+        let intent = state.get_intent("intent-1").await.unwrap();
+        state
+            .outbox
+            .write()
+            .await
+            .enqueue("webhook:intent-1", webhook(&intent))
+            .await
+            .unwrap();
+
+        assert_eq!(drain_outbox(&state, &Unreachable, NOW).await, 0);
+        let (_, entry) = state.outbox.read().await.pending().remove(0);
+        assert_eq!(entry.attempts, 1);
+        assert_eq!(entry.next_attempt_at, NOW + BASE_BACKOFF_SECS);
+
+        // Not retried before the backoff elapses
+        drain_outbox(&state, &Unreachable, NOW + 1).await;
+        assert_eq!(state.outbox.read().await.pending()[0].1.attempts, 1);
+
+        drain_outbox(&state, &Unreachable, NOW + BASE_BACKOFF_SECS).await;
+        let (_, entry) = state.outbox.read().await.pending().remove(0);
+        assert_eq!(entry.attempts, 2);
+        assert_eq!(
+            entry.next_attempt_at,
+            NOW + 3 * BASE_BACKOFF_SECS,
+            "backoff doubles"
+        );
+
+        drain_outbox(&state, &Unreachable, NOW + 3 * BASE_BACKOFF_SECS).await;
+        let outbox = state.outbox.read().await;
+        assert!(outbox.is_empty());
+        assert_eq!(outbox.dead_letters().len(), 1);
+        assert_eq!(outbox.dead_letters()[0].attempts, 3);
+    }
+
+    #[tokio::test]
+    async fn test_undeliverable_effect_is_dead_lettered() {
+        let state = state_with_intent(Outbox::new(5)).await;
+        state
+            .outbox
+            .write()
+            .await
+            .enqueue("relay:intent-1", relay())
+            .await
+            .unwrap();
+
+        assert_eq!(drain_outbox(&state, &WebhookDeliverer::new(), NOW).await, 0);
+        let outbox = state.outbox.read().await;
+        assert!(outbox.is_empty());
+        assert_eq!(
+            outbox.dead_letters()[0].last_error.as_deref(),
+            Some("no deliverer for this effect")
+        );
+    }
+
+    #[test]
+    fn test_backoff_is_capped() {
+        assert_eq!(backoff_secs(1), BASE_BACKOFF_SECS);
+        assert_eq!(backoff_secs(2), 2 * BASE_BACKOFF_SECS);
+        assert_eq!(backoff_secs(u32::MAX), MAX_BACKOFF_SECS);
+    }
+
+    #[tokio::test]
+    async fn test_enqueue_ignores_duplicate_keys() {
+        let mut outbox = Outbox::new(5);
+        outbox.enqueue("relay:intent-1", relay()).await.unwrap();
+        outbox.enqueue("relay:intent-1", relay()).await.unwrap();
+        assert_eq!(outbox.len(), 1);
+    }
+}
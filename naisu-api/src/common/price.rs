@@ -0,0 +1,284 @@
+//! USD price sources for fee reporting and route comparison
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use naisu_core::{is_native_token, EvmChain};
+use serde::Deserialize;
+
+/// Token identifier used for a chain's native gas token
+pub const NATIVE_TOKEN: &str = "native";
+
+/// Public CoinGecko API
+pub const COINGECKO_API_URL: &str = "https://api.coingecko.com/api/v3";
+
+/// Chain a priced asset lives on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AssetChain {
+    Evm(EvmChain),
+    Sui,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum PriceError {
+    #[error("No price for {token} on {chain:?}")]
+    NotFound { chain: AssetChain, token: String },
+
+    #[error("Price request failed: {0}")]
+    Request(String),
+
+    #[error("Failed to parse price response: {0}")]
+    Parse(String),
+}
+
+/// Source of USD prices
+#[async_trait::async_trait]
+pub trait PriceOracle: Send + Sync {
+    /// USD price of one whole unit of `token` (an address or [`NATIVE_TOKEN`])
+    async fn price_usd(&self, chain: AssetChain, token: &str) -> Result<f64, PriceError>;
+}
+
+/// Fixed prices, for tests and offline runs
+#[derive(Debug, Default, Clone)]
+pub struct StaticPriceOracle {
+    prices: HashMap<(AssetChain, String), f64>,
+}
+
+impl StaticPriceOracle {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_price(mut self, chain: AssetChain, token: &str, usd: f64) -> Self {
+        self.prices.insert((chain, token.to_lowercase()), usd);
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl PriceOracle for StaticPriceOracle {
+    async fn price_usd(&self, chain: AssetChain, token: &str) -> Result<f64, PriceError> {
+        self.prices
+            .get(&(chain, token.to_lowercase()))
+            .copied()
+            .ok_or_else(|| PriceError::NotFound {
+                chain,
+                token: token.to_string(),
+            })
+    }
+}
+
+/// CoinGecko-backed oracle with a short-lived in-memory cache
+pub struct CoinGeckoOracle {
+    client: reqwest::Client,
+    base_url: String,
+    ttl: Duration,
+    cache: Mutex<HashMap<(AssetChain, String), (f64, Instant)>>,
+}
+
+impl CoinGeckoOracle {
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self {
+            client: reqwest::Client::builder()
+                .timeout(Duration::from_secs(10))
+                .build()
+                .expect("Failed to build HTTP client"),
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            ttl: Duration::from_secs(60),
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// How long a fetched price is reused
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    fn cached(&self, key: &(AssetChain, String)) -> Option<f64> {
+        let cache = self.cache.lock().expect("price cache poisoned");
+        cache
+            .get(key)
+            .filter(|(_, fetched_at)| fetched_at.elapsed() < self.ttl)
+            .map(|(price, _)| *price)
+    }
+
+    async fn fetch(&self, chain: AssetChain, token: &str) -> Result<f64, PriceError> {
+        let not_found = || PriceError::NotFound {
+            chain,
+            token: token.to_string(),
+        };
+
+        // Response shape for both endpoints: { "<id or address>": { "usd": 1.0 } }
+        let (url, query, key) = if token == NATIVE_TOKEN {
+            let id = native_coin_id(chain);
+            (
+                format!("{}/simple/price", self.base_url),
+                [
+                    ("ids", id.to_string()),
+                    ("vs_currencies", "usd".to_string()),
+                ],
+                id.to_string(),
+            )
+        } else {
+            let platform = platform_id(chain).ok_or_else(not_found)?;
+            (
+                format!("{}/simple/token_price/{}", self.base_url, platform),
+                [
+                    ("contract_addresses", token.to_string()),
+                    ("vs_currencies", "usd".to_string()),
+                ],
+                token.to_lowercase(),
+            )
+        };
+
+        let response = self
+            .client
+            .get(&url)
+            .query(&query)
+            .send()
+            .await
+            .map_err(|e| PriceError::Request(e.to_string()))?
+            .error_for_status()
+            .map_err(|e| PriceError::Request(e.to_string()))?;
+
+        let body: HashMap<String, UsdPrice> = response
+            .json()
+            .await
+            .map_err(|e| PriceError::Parse(e.to_string()))?;
+
+        body.into_iter()
+            .find(|(k, _)| k.to_lowercase() == key)
+            .map(|(_, price)| price.usd)
+            .ok_or_else(not_found)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct UsdPrice {
+    usd: f64,
+}
+
+#[async_trait::async_trait]
+impl PriceOracle for CoinGeckoOracle {
+    async fn price_usd(&self, chain: AssetChain, token: &str) -> Result<f64, PriceError> {
+        let key = (chain, token.to_lowercase());
+        if let Some(price) = self.cached(&key) {
+            return Ok(price);
+        }
+
+        let price = self.fetch(chain, token).await?;
+        self.cache
+            .lock()
+            .expect("price cache poisoned")
+            .insert(key, (price, Instant::now()));
+        Ok(price)
+    }
+}
+
+/// CoinGecko asset platform; testnet tokens have no market price
+fn platform_id(chain: AssetChain) -> Option<&'static str> {
+    match chain {
+        AssetChain::Evm(EvmChain::Ethereum) => Some("ethereum"),
+        AssetChain::Evm(EvmChain::Base) => Some("base"),
+        AssetChain::Evm(EvmChain::Arbitrum) => Some("arbitrum-one"),
+        AssetChain::Evm(EvmChain::Optimism) => Some("optimistic-ethereum"),
+        AssetChain::Evm(EvmChain::BaseSepolia | EvmChain::Sepolia) => None,
+        AssetChain::Sui => Some("sui"),
+    }
+}
+
+/// CoinGecko coin id of the native gas token (testnets priced as mainnet)
+fn native_coin_id(chain: AssetChain) -> &'static str {
+    match chain {
+        AssetChain::Evm(_) => "ethereum",
+        AssetChain::Sui => "sui",
+    }
+}
+
+/// Value of `amount` base units of EVM `token` in USDC base units. USDC is
+/// taken at face value; WETH and native ETH are priced as the chain's native
+/// token so testnets still get a mainnet price.
+pub async fn usdc_value(
+    oracle: &dyn PriceOracle,
+    chain: EvmChain,
+    token: &str,
+    amount: u128,
+) -> Result<u128, PriceError> {
+    if token.eq_ignore_ascii_case(chain.usdc_address()) {
+        return Ok(amount);
+    }
+    let (priced_as, decimals) = if is_native_token(token) {
+        (NATIVE_TOKEN, chain.native_decimals())
+    } else {
+        let known = chain
+            .known_token(token)
+            .ok_or_else(|| PriceError::NotFound {
+                chain: AssetChain::Evm(chain),
+                token: token.to_string(),
+            })?;
+        let priced_as = if known.address == chain.weth_address() {
+            NATIVE_TOKEN
+        } else {
+            known.address
+        };
+        (priced_as, known.decimals)
+    };
+    let price = oracle.price_usd(AssetChain::Evm(chain), priced_as).await?;
+    let whole_units = amount as f64 / 10f64.powi(decimals as i32);
+    Ok((whole_units * price * 1e6) as u128)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_coingecko_price_is_cached() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/simple/price"))
+            .and(query_param("ids", "sui"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({ "sui": { "usd": 3.5 } })),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let oracle = CoinGeckoOracle::new(server.uri());
+        for _ in 0..2 {
+            let price = oracle
+                .price_usd(AssetChain::Sui, NATIVE_TOKEN)
+                .await
+                .unwrap();
+            assert_eq!(price, 3.5);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_static_oracle_unknown_token() {
+        let oracle = StaticPriceOracle::new().with_price(AssetChain::Sui, NATIVE_TOKEN, 3.5);
+        assert!(oracle.price_usd(AssetChain::Sui, "0xabc").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_usdc_value_prices_weth_and_native_eth() {
+        let chain = EvmChain::BaseSepolia;
+        let oracle =
+            StaticPriceOracle::new().with_price(AssetChain::Evm(chain), NATIVE_TOKEN, 2000.0);
+        let half_eth = 500_000_000_000_000_000;
+
+        for token in [chain.weth_address(), naisu_core::NATIVE_ETH_SENTINEL] {
+            let value = usdc_value(&oracle, chain, token, half_eth).await.unwrap();
+            assert_eq!(value, 1_000_000_000);
+        }
+        let usdc = usdc_value(&oracle, chain, chain.usdc_address(), 5_000_000).await;
+        assert_eq!(usdc.unwrap(), 5_000_000);
+        assert!(usdc_value(&oracle, chain, "0xabc", 1).await.is_err());
+    }
+}
@@ -0,0 +1,118 @@
+//! Short-lived bridge quotes
+//!
+//! A quote pins what the user saw (amount in, amount out, destination) under
+//! an id. Bridge initiation looks the id up again, so a transaction is only
+//! built against terms the user agreed to, and only while they are fresh.
+
+use std::collections::HashMap;
+
+use naisu_core::EvmChain;
+use naisu_sui::circle::{CctpClient, CctpError};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// Terms of a Sui → EVM CCTP transfer, valid until `expires_at`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+pub struct BridgeQuote {
+    pub quote_id: String,
+    pub evm_chain: EvmChain,
+    /// USDC burned on Sui (raw, 6 decimals)
+    pub amount: String,
+    /// Circle's transfer fee (raw), deducted on mint
+    pub fee: String,
+    /// USDC minted on `evm_chain` (raw)
+    pub amount_out: String,
+    /// Unix seconds after which the quote can no longer be used
+    pub expires_at: u64,
+}
+
+/// Current `(fee, amount_out)` for burning `amount` on `source_domain` and
+/// minting on `dest_domain`, at Circle's standard transfer fee
+pub async fn price(
+    cctp: &CctpClient,
+    source_domain: u32,
+    dest_domain: u32,
+    amount: u64,
+) -> Result<(u64, u64), CctpError> {
+    let bps = cctp.transfer_fee_bps(source_domain, dest_domain).await?;
+    Ok(apply_fee(amount, bps))
+}
+
+/// Split `amount` into `(fee, amount_out)` at `bps` basis points, rounding
+/// the fee up so the quote never promises more than Circle mints
+fn apply_fee(amount: u64, bps: f64) -> (u64, u64) {
+    let fee = ((amount as f64) * bps / 10_000.0)
+        .ceil()
+        .clamp(0.0, amount as f64) as u64;
+    (fee, amount - fee)
+}
+
+/// Issued quotes by id, dropped once expired
+#[derive(Debug)]
+pub struct QuoteStore {
+    ttl_secs: u64,
+    quotes: HashMap<String, BridgeQuote>,
+}
+
+impl QuoteStore {
+    /// Quotes stay valid for `ttl_secs` after issue
+    pub fn new(ttl_secs: u64) -> Self {
+        Self {
+            ttl_secs,
+            quotes: HashMap::new(),
+        }
+    }
+
+    /// Store a quote priced at `(fee, amount_out)` (see [`price`]), issued at
+    /// `now` (unix secs)
+    pub fn issue(
+        &mut self,
+        amount: u64,
+        evm_chain: EvmChain,
+        (fee, amount_out): (u64, u64),
+        now: u64,
+    ) -> BridgeQuote {
+        self.quotes.retain(|_, q| q.expires_at > now);
+
+        let quote = BridgeQuote {
+            quote_id: uuid::Uuid::new_v4().to_string(),
+            evm_chain,
+            amount: amount.to_string(),
+            fee: fee.to_string(),
+            amount_out: amount_out.to_string(),
+            expires_at: now + self.ttl_secs,
+        };
+        self.quotes.insert(quote.quote_id.clone(), quote.clone());
+        quote
+    }
+
+    /// The quote `id`, unless it is unknown or expired at `now`
+    pub fn get(&self, id: &str, now: u64) -> Option<&BridgeQuote> {
+        self.quotes.get(id).filter(|q| q.expires_at > now)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quote_expires_after_ttl() {
+        let mut store = QuoteStore::new(30);
+        let quote = store.issue(1_000_000, EvmChain::BaseSepolia, (100, 999_900), 100);
+
+        assert_eq!(quote.expires_at, 130);
+        assert_eq!(quote.amount_out, "999900");
+        assert_eq!(store.get(&quote.quote_id, 129), Some(&quote));
+        assert_eq!(store.get(&quote.quote_id, 130), None);
+        assert_eq!(store.get("unknown", 100), None);
+    }
+
+    #[test]
+    fn test_fee_rounds_up() {
+        assert_eq!(apply_fee(1_000_000, 0.0), (0, 1_000_000));
+        assert_eq!(apply_fee(1_000_000, 1.0), (100, 999_900));
+        assert_eq!(apply_fee(1_234_567, 0.5), (62, 1_234_505));
+        assert_eq!(apply_fee(10, 20_000.0), (10, 0));
+    }
+}
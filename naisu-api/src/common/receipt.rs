@@ -0,0 +1,286 @@
+//! Signed completion receipts
+//!
+//! A receipt is the canonical JSON of an intent's outcome (object keys sorted,
+//! no whitespace) signed with a server key, so users and partners can prove
+//! what the service reported. Both HMAC-SHA256 (shared secret) and ed25519
+//! (publishable verifying key) are supported.
+
+use std::fmt;
+
+use ed25519_dalek::{Signer, SigningKey};
+use hmac::{Hmac, Mac};
+use naisu_core::{Direction, EvmChain, Intent, IntentStatus, YieldStrategy};
+use serde::Serialize;
+use serde_json::Value;
+use sha2::Sha256;
+use utoipa::ToSchema;
+
+use crate::config::REDACTED;
+
+/// Signature scheme of a receipt
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum ReceiptScheme {
+    HmacSha256,
+    Ed25519,
+}
+
+impl std::str::FromStr for ReceiptScheme {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "hmac" | "hmac-sha256" => Ok(ReceiptScheme::HmacSha256),
+            "ed25519" => Ok(ReceiptScheme::Ed25519),
+            other => Err(format!("unknown receipt signing scheme: {}", other)),
+        }
+    }
+}
+
+/// Server key used to sign receipts
+#[derive(Clone)]
+pub enum ReceiptSigner {
+    Hmac(Vec<u8>),
+    Ed25519(SigningKey),
+}
+
+impl fmt::Debug for ReceiptSigner {
+    // Never print key material
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ReceiptSigner({:?})", self.scheme())
+    }
+}
+
+impl Serialize for ReceiptSigner {
+    // Scheme and public key only; key material is never serialized
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("ReceiptSigner", 3)?;
+        state.serialize_field("scheme", &self.scheme())?;
+        state.serialize_field("key", REDACTED)?;
+        state.serialize_field("public_key", &self.public_key())?;
+        state.end()
+    }
+}
+
+impl ReceiptSigner {
+    /// Build a signer from config. HMAC takes the key as given; ed25519 takes a
+    /// hex-encoded 32-byte seed.
+    pub fn from_key(scheme: ReceiptScheme, key: &str) -> Result<Self, String> {
+        match scheme {
+            ReceiptScheme::HmacSha256 if key.is_empty() => Err("empty HMAC key".to_string()),
+            ReceiptScheme::HmacSha256 => Ok(ReceiptSigner::Hmac(key.as_bytes().to_vec())),
+            ReceiptScheme::Ed25519 => {
+                let seed: [u8; 32] = hex::decode(key.trim_start_matches("0x"))
+                    .map_err(|e| format!("ed25519 seed is not hex: {}", e))?
+                    .try_into()
+                    .map_err(|_| "ed25519 seed must be 32 bytes".to_string())?;
+                Ok(ReceiptSigner::Ed25519(SigningKey::from_bytes(&seed)))
+            }
+        }
+    }
+
+    pub fn scheme(&self) -> ReceiptScheme {
+        match self {
+            ReceiptSigner::Hmac(_) => ReceiptScheme::HmacSha256,
+            ReceiptSigner::Ed25519(_) => ReceiptScheme::Ed25519,
+        }
+    }
+
+    /// Hex verifying key for ed25519; `None` for HMAC (the key is secret)
+    pub fn public_key(&self) -> Option<String> {
+        match self {
+            ReceiptSigner::Hmac(_) => None,
+            ReceiptSigner::Ed25519(key) => Some(hex::encode(key.verifying_key().as_bytes())),
+        }
+    }
+
+    /// Hex signature over `payload`
+    pub fn sign(&self, payload: &[u8]) -> String {
+        match self {
+            ReceiptSigner::Hmac(key) => {
+                hex::encode(hmac_sha256(key, payload).finalize().into_bytes())
+            }
+            ReceiptSigner::Ed25519(key) => hex::encode(key.sign(payload).to_bytes()),
+        }
+    }
+
+    /// Check a hex signature produced by [`ReceiptSigner::sign`]
+    pub fn verify(&self, payload: &[u8], signature: &str) -> bool {
+        let Ok(signature) = hex::decode(signature) else {
+            return false;
+        };
+        match self {
+            ReceiptSigner::Hmac(key) => hmac_sha256(key, payload).verify_slice(&signature).is_ok(),
+            ReceiptSigner::Ed25519(key) => {
+                let Ok(signature) = ed25519_dalek::Signature::from_slice(&signature) else {
+                    return false;
+                };
+                key.verify(payload, &signature).is_ok()
+            }
+        }
+    }
+}
+
+fn hmac_sha256(key: &[u8], payload: &[u8]) -> Hmac<Sha256> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts any key length");
+    mac.update(payload);
+    mac
+}
+
+/// Transaction hashes of every executed step
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+pub struct ReceiptTxHashes {
+    pub swap: Option<String>,
+    pub bridge: Option<String>,
+    pub mint: Option<String>,
+    pub deposit: Option<String>,
+    pub output_swap: Option<String>,
+    pub unwrap: Option<String>,
+    pub dest: Option<String>,
+}
+
+/// When the intent entered a status
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+pub struct ReceiptTimestamp {
+    pub status: IntentStatus,
+    /// Unix seconds
+    pub at: i64,
+}
+
+/// Outcome of a finished intent, as signed
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+pub struct IntentReceipt {
+    pub intent_id: String,
+    pub direction: Direction,
+    pub status: IntentStatus,
+    pub source_address: String,
+    pub dest_address: String,
+    /// None for withdraw-only intents
+    pub evm_chain: Option<EvmChain>,
+    pub strategy: Option<YieldStrategy>,
+    pub input_token: String,
+    /// Amount the user put in, smallest unit of `input_token`
+    pub gross_amount: String,
+    /// USDC that was bridged
+    pub net_amount: Option<String>,
+    /// `gross_amount - net_amount`, only when the input was already USDC
+    pub fees: Option<String>,
+    pub tx_hashes: ReceiptTxHashes,
+    /// Creation followed by the intent's status history, oldest first
+    pub timestamps: Vec<ReceiptTimestamp>,
+    pub error_message: Option<String>,
+}
+
+impl IntentReceipt {
+    pub fn new(intent: &Intent) -> Self {
+        let usdc_input = intent.direction != Direction::EvmToSui
+            || intent
+                .evm_chain
+                .is_some_and(|c| intent.input_token.eq_ignore_ascii_case(c.usdc_address()));
+        let fees = intent
+            .usdc_amount
+            .as_deref()
+            .filter(|_| usdc_input)
+            .and_then(|net| {
+                let gross: u128 = intent.input_amount.parse().ok()?;
+                gross.checked_sub(net.parse().ok()?)
+            })
+            .map(|fee| fee.to_string());
+
+        let timestamps = std::iter::once(ReceiptTimestamp {
+            status: IntentStatus::Pending,
+            at: intent.created_at,
+        })
+        .chain(intent.status_history.iter().map(|change| ReceiptTimestamp {
+            status: change.status,
+            at: change.at,
+        }))
+        .collect();
+
+        Self {
+            intent_id: intent.id.clone(),
+            direction: intent.direction,
+            status: intent.status,
+            source_address: intent.source_address.clone(),
+            dest_address: intent.dest_address.clone(),
+            evm_chain: intent.evm_chain,
+            strategy: intent.strategy,
+            input_token: intent.input_token.clone(),
+            gross_amount: intent.input_amount.clone(),
+            net_amount: intent.usdc_amount.clone(),
+            fees,
+            tx_hashes: ReceiptTxHashes {
+                swap: intent.swap_tx_hash.clone(),
+                bridge: intent.bridge_tx_hash.clone(),
+                mint: intent.mint_tx_hash.clone(),
+                deposit: intent.deposit_tx_hash.clone(),
+                output_swap: intent.output_swap_tx_hash.clone(),
+                unwrap: intent.unwrap_tx_hash.clone(),
+                dest: intent.dest_tx_hash.clone(),
+            },
+            timestamps,
+            error_message: intent.error_message.clone(),
+        }
+    }
+
+    /// The exact bytes that are signed
+    pub fn canonical_bytes(&self) -> Vec<u8> {
+        let value = serde_json::to_value(self).expect("receipt serializes");
+        canonical_json(&value).into_bytes()
+    }
+}
+
+/// Compact JSON with object keys sorted at every level
+pub fn canonical_json(value: &Value) -> String {
+    match value {
+        Value::Object(map) => {
+            let mut entries: Vec<_> = map.iter().collect();
+            entries.sort_by(|a, b| a.0.cmp(b.0));
+            let fields: Vec<_> = entries
+                .into_iter()
+                .map(|(k, v)| format!("{}:{}", Value::String(k.clone()), canonical_json(v)))
+                .collect();
+            format!("{{{}}}", fields.join(","))
+        }
+        Value::Array(items) => {
+            let items: Vec<_> = items.iter().map(canonical_json).collect();
+            format!("[{}]", items.join(","))
+        }
+        scalar => scalar.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_canonical_json_sorts_keys() {
+        let value = json!({ "b": 1, "a": { "d": [true, null], "c": "x" } });
+        assert_eq!(
+            canonical_json(&value),
+            r#"{"a":{"c":"x","d":[true,null]},"b":1}"#
+        );
+    }
+
+    #[test]
+    fn test_signers_reject_other_payloads() {
+        let hmac = ReceiptSigner::from_key(ReceiptScheme::HmacSha256, "secret").unwrap();
+        let ed25519 = ReceiptSigner::from_key(ReceiptScheme::Ed25519, &"07".repeat(32)).unwrap();
+
+        for signer in [hmac, ed25519] {
+            let signature = signer.sign(b"payload");
+            assert!(signer.verify(b"payload", &signature));
+            assert!(!signer.verify(b"payload!", &signature));
+            assert!(!signer.verify(b"payload", "zz"));
+        }
+    }
+
+    #[test]
+    fn test_ed25519_seed_must_be_32_bytes() {
+        assert!(ReceiptSigner::from_key(ReceiptScheme::Ed25519, "abcd").is_err());
+        assert!(ReceiptSigner::from_key(ReceiptScheme::HmacSha256, "").is_err());
+    }
+}
@@ -1,19 +1,29 @@
 use axum::{
     http::StatusCode,
     response::{IntoResponse, Response},
-    Json,
 };
-use serde::Serialize;
+use naisu_core::FieldError;
+use naisu_sui::SuiClientError;
+use serde::{Deserialize, Serialize};
 use std::fmt;
+use utoipa::ToSchema;
+
+use crate::state::EffectsNotSaved;
 
 /// Standard error response
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
 pub struct ApiErrorResponse {
     pub success: bool,
     pub code: u16,
     pub message: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<String>,
+    /// Whether retrying the same request may succeed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retryable: Option<bool>,
+    /// Every invalid request field, for validation failures
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fields: Option<Vec<FieldError>>,
 }
 
 impl ApiErrorResponse {
@@ -23,6 +33,8 @@ impl ApiErrorResponse {
             code: 500,
             message: message.into(),
             error: None,
+            retryable: None,
+            fields: None,
         }
     }
 
@@ -40,6 +52,16 @@ impl ApiErrorResponse {
         self.error = Some(error.into());
         self
     }
+
+    pub fn with_retryable(mut self, retryable: bool) -> Self {
+        self.retryable = Some(retryable);
+        self
+    }
+
+    pub fn with_fields(mut self, fields: Vec<FieldError>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
 }
 
 impl Default for ApiErrorResponse {
@@ -49,6 +71,8 @@ impl Default for ApiErrorResponse {
             code: 500,
             message: "Internal server error".to_string(),
             error: None,
+            retryable: None,
+            fields: None,
         }
     }
 }
@@ -64,7 +88,7 @@ impl std::error::Error for ApiErrorResponse {}
 impl IntoResponse for ApiErrorResponse {
     fn into_response(self) -> Response {
         let status = StatusCode::from_u16(self.code).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
-        (status, Json(self)).into_response()
+        super::format::negotiated(status, &self)
     }
 }
 
@@ -84,3 +108,62 @@ impl IntoApiError for &str {
         ApiErrorResponse::new(self)
     }
 }
+
+impl From<EffectsNotSaved> for ApiErrorResponse {
+    fn from(err: EffectsNotSaved) -> Self {
+        ApiErrorResponse::new("Failed to save the intent change")
+            .with_code(StatusCode::INTERNAL_SERVER_ERROR)
+            .with_retryable(true)
+            .with_error(err.to_string())
+    }
+}
+
+impl From<SuiClientError> for ApiErrorResponse {
+    fn from(err: SuiClientError) -> Self {
+        let (code, message) = match &err {
+            SuiClientError::InsufficientBalance { .. } => {
+                (StatusCode::UNPROCESSABLE_ENTITY, "Insufficient balance")
+            }
+            SuiClientError::ObjectNotFound(_) => (StatusCode::NOT_FOUND, "Sui object not found"),
+            SuiClientError::TransactionFailed(_) => {
+                (StatusCode::UNPROCESSABLE_ENTITY, "Sui transaction failed")
+            }
+            SuiClientError::PtbBuild(_) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Failed to build Sui transaction",
+            ),
+            SuiClientError::Timeout => (StatusCode::GATEWAY_TIMEOUT, "Sui RPC timed out"),
+            SuiClientError::Request(_) | SuiClientError::Rpc { .. } | SuiClientError::Parse(_) => {
+                (StatusCode::BAD_GATEWAY, "Sui RPC request failed")
+            }
+        };
+
+        ApiErrorResponse::new(message)
+            .with_code(code)
+            .with_retryable(err.is_retryable())
+            .with_error(err.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sui_errors_map_to_status_and_retryability() {
+        let err = ApiErrorResponse::from(SuiClientError::InsufficientBalance {
+            required: 10,
+            available: 4,
+        });
+        assert_eq!(err.code, 422);
+        assert_eq!(err.retryable, Some(false));
+
+        let err = ApiErrorResponse::from(SuiClientError::Timeout);
+        assert_eq!(err.code, 504);
+        assert_eq!(err.retryable, Some(true));
+
+        let err = ApiErrorResponse::from(SuiClientError::ObjectNotFound("0x5".to_string()));
+        assert_eq!(err.code, 404);
+        assert_eq!(err.retryable, Some(false));
+    }
+}
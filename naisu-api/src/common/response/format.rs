@@ -0,0 +1,116 @@
+//! Response body encoding negotiated from the `Accept` header
+//!
+//! `middleware::negotiate_format` picks a [`ResponseFormat`] per request and
+//! scopes it over the handler; the response types read it back when they are
+//! turned into a response. Outside that scope (e.g. handlers called directly
+//! in tests) bodies are JSON.
+
+use axum::{
+    http::{header, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::Serialize;
+
+pub const MSGPACK_CONTENT_TYPE: &str = "application/msgpack";
+
+/// Encoding of API response bodies
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ResponseFormat {
+    #[default]
+    Json,
+    MsgPack,
+}
+
+tokio::task_local! {
+    static RESPONSE_FORMAT: ResponseFormat;
+}
+
+impl ResponseFormat {
+    /// Most preferred supported type in an `Accept` header (by q-value, then
+    /// order). Anything unsupported, malformed or missing means JSON.
+    pub fn from_accept(accept: Option<&HeaderValue>) -> Self {
+        let Some(accept) = accept.and_then(|v| v.to_str().ok()) else {
+            return Self::Json;
+        };
+
+        let mut best: Option<(f32, Self)> = None;
+        for range in accept.split(',') {
+            let mut parts = range.split(';').map(str::trim);
+            let format = match parts.next().unwrap_or_default() {
+                "application/json" => Self::Json,
+                "application/msgpack" | "application/x-msgpack" => Self::MsgPack,
+                _ => continue,
+            };
+            let q = parts
+                .find_map(|p| p.strip_prefix("q="))
+                .and_then(|q| q.parse::<f32>().ok())
+                .unwrap_or(1.0);
+            if q > 0.0 && best.is_none_or(|(best_q, _)| q > best_q) {
+                best = Some((q, format));
+            }
+        }
+        best.map_or(Self::Json, |(_, format)| format)
+    }
+
+    /// Format negotiated for the request being handled
+    pub fn current() -> Self {
+        RESPONSE_FORMAT.try_with(|f| *f).unwrap_or_default()
+    }
+
+    /// Run `f` with `self` as the negotiated format
+    pub async fn scope<F: std::future::Future>(self, f: F) -> F::Output {
+        RESPONSE_FORMAT.scope(self, f).await
+    }
+}
+
+/// `body` encoded in the negotiated format
+pub(crate) fn negotiated<T: Serialize>(status: StatusCode, body: &T) -> Response {
+    let mut response = match ResponseFormat::current() {
+        ResponseFormat::Json => (status, Json(body)).into_response(),
+        ResponseFormat::MsgPack => match rmp_serde::to_vec_named(body) {
+            Ok(bytes) => (
+                status,
+                [(
+                    header::CONTENT_TYPE,
+                    HeaderValue::from_static(MSGPACK_CONTENT_TYPE),
+                )],
+                bytes,
+            )
+                .into_response(),
+            Err(e) => {
+                tracing::error!(error = %e, "Failed to encode response as MessagePack");
+                StatusCode::INTERNAL_SERVER_ERROR.into_response()
+            }
+        },
+    };
+    response
+        .headers_mut()
+        .insert(header::VARY, HeaderValue::from_static("accept"));
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(accept: &str) -> ResponseFormat {
+        ResponseFormat::from_accept(Some(&HeaderValue::from_str(accept).unwrap()))
+    }
+
+    #[test]
+    fn test_accept_picks_preferred_supported_type() {
+        assert_eq!(parse("application/msgpack"), ResponseFormat::MsgPack);
+        assert_eq!(
+            parse("application/json;q=0.5, application/msgpack"),
+            ResponseFormat::MsgPack
+        );
+        assert_eq!(
+            parse("application/json, application/msgpack"),
+            ResponseFormat::Json
+        );
+        assert_eq!(parse("application/msgpack;q=0"), ResponseFormat::Json);
+        assert_eq!(parse("text/html, */*"), ResponseFormat::Json);
+        assert_eq!(ResponseFormat::from_accept(None), ResponseFormat::Json);
+    }
+}
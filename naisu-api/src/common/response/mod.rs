@@ -1,14 +1,15 @@
 use axum::{
     http::StatusCode,
     response::{IntoResponse, Response},
-    Json,
 };
 use serde::{Deserialize, Serialize};
 
 pub mod error;
+pub mod format;
 pub mod success;
 
 pub use error::ApiErrorResponse;
+pub use format::ResponseFormat;
 pub use success::ApiSuccessResponse;
 
 /// Generic API response type
@@ -59,6 +60,6 @@ impl<T: Serialize> IntoResponse for ApiResponseWrapper<T> {
                 _ => StatusCode::OK,
             }
         };
-        (status, Json(self)).into_response()
+        format::negotiated(status, &self)
     }
 }
@@ -1,12 +1,12 @@
 use axum::{
     http::StatusCode,
     response::{IntoResponse, Response},
-    Json,
 };
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 /// Standard success response wrapper
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
 pub struct ApiSuccessResponse<T> {
     pub success: bool,
     pub code: u16,
@@ -58,6 +58,6 @@ where
 impl<T: Serialize> IntoResponse for ApiSuccessResponse<T> {
     fn into_response(self) -> Response {
         let status = StatusCode::from_u16(self.code).unwrap_or(StatusCode::OK);
-        (status, Json(self)).into_response()
+        super::format::negotiated(status, &self)
     }
 }
@@ -0,0 +1,267 @@
+//! Intent status transitions with a per-intent replay buffer
+//!
+//! Every status change is numbered per intent and kept in a bounded ring
+//! buffer, then broadcast to that intent's live subscribers. A client that
+//! reconnects with the last sequence number it saw gets the missed transitions
+//! from the buffer before switching to the live feed.
+//!
+//! Sequence numbers carry the log's start time in their high bits, so numbers
+//! handed out after a restart are always above the ones a client saw before
+//! it. Histories of finished intents are dropped once they are older than
+//! the retention period.
+
+use std::collections::{HashMap, VecDeque};
+
+use naisu_core::IntentStatus;
+use serde::Serialize;
+use tokio::sync::broadcast;
+use utoipa::ToSchema;
+
+/// Live subscribers of one intent lagging further than this miss events (and
+/// must reconnect)
+const BROADCAST_CAPACITY: usize = 64;
+
+/// Bits of a sequence number left for the per-intent counter; the rest hold
+/// the epoch
+const COUNTER_BITS: u32 = 20;
+
+/// One status change of an intent
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+pub struct StatusTransition {
+    /// Per-intent sequence number, increasing across restarts
+    pub seq: u64,
+    pub intent_id: String,
+    pub from: IntentStatus,
+    pub to: IntentStatus,
+    /// Unix seconds
+    pub at: i64,
+}
+
+#[derive(Debug, Default)]
+struct IntentHistory {
+    last_seq: u64,
+    recent: VecDeque<StatusTransition>,
+    /// When the intent reached a terminal status, if it has
+    finished_at: Option<i64>,
+}
+
+/// Recent transitions per intent plus a live broadcast of new ones
+#[derive(Debug)]
+pub struct TransitionLog {
+    capacity: usize,
+    retention_secs: i64,
+    /// Added to every sequence number, see the module docs
+    epoch: u64,
+    histories: HashMap<String, IntentHistory>,
+    /// Finished intents in the order they finished, for eviction
+    finished: VecDeque<(i64, String)>,
+    live: HashMap<String, broadcast::Sender<StatusTransition>>,
+}
+
+impl TransitionLog {
+    /// Keep up to `capacity` transitions per intent, and the history of a
+    /// finished intent for `retention_secs`. Sequence numbers start from the
+    /// current time.
+    pub fn new(capacity: usize, retention_secs: u64) -> Self {
+        let now_ms = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or_default();
+        Self::with_epoch(capacity, retention_secs, now_ms)
+    }
+
+    /// Like [`TransitionLog::new`], with sequence numbers starting from
+    /// `epoch` (milliseconds) instead of the clock
+    pub fn with_epoch(capacity: usize, retention_secs: u64, epoch: u64) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            retention_secs: retention_secs.try_into().unwrap_or(i64::MAX),
+            epoch: epoch << COUNTER_BITS,
+            histories: HashMap::new(),
+            finished: VecDeque::new(),
+            live: HashMap::new(),
+        }
+    }
+
+    /// Number and store a transition, then publish it to live subscribers
+    pub fn record(
+        &mut self,
+        intent_id: &str,
+        from: IntentStatus,
+        to: IntentStatus,
+        at: i64,
+    ) -> StatusTransition {
+        self.evict_finished_before(at.saturating_sub(self.retention_secs));
+
+        let history = self.histories.entry(intent_id.to_string()).or_default();
+        history.last_seq += 1;
+
+        let transition = StatusTransition {
+            seq: self.epoch + history.last_seq,
+            intent_id: intent_id.to_string(),
+            from,
+            to,
+            at,
+        };
+        if history.recent.len() == self.capacity {
+            history.recent.pop_front();
+        }
+        history.recent.push_back(transition.clone());
+
+        history.finished_at = to.is_terminal().then_some(at);
+        if to.is_terminal() {
+            self.finished.push_back((at, intent_id.to_string()));
+        }
+
+        if let Some(live) = self.live.get(intent_id) {
+            // No receivers left: everyone disconnected
+            if live.send(transition.clone()).is_err() {
+                self.live.remove(intent_id);
+            }
+        }
+        transition
+    }
+
+    /// Buffered transitions of `intent_id` after `last_seq` (all when `None`)
+    pub fn since(&self, intent_id: &str, last_seq: Option<u64>) -> Vec<StatusTransition> {
+        let after = last_seq.unwrap_or(0);
+        self.histories
+            .get(intent_id)
+            .map(|h| h.recent.iter().filter(|t| t.seq > after).cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Receive every transition of `intent_id` recorded from now on
+    pub fn subscribe(&mut self, intent_id: &str) -> broadcast::Receiver<StatusTransition> {
+        self.live
+            .entry(intent_id.to_string())
+            .or_insert_with(|| broadcast::channel(BROADCAST_CAPACITY).0)
+            .subscribe()
+    }
+
+    /// Number of intents with a buffered history
+    pub fn len(&self) -> usize {
+        self.histories.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.histories.is_empty()
+    }
+
+    /// Drop the histories of intents that finished before `cutoff` (unix secs)
+    /// and haven't moved since
+    fn evict_finished_before(&mut self, cutoff: i64) {
+        while let Some((at, _)) = self.finished.front() {
+            if *at >= cutoff {
+                break;
+            }
+            let (at, intent_id) = self.finished.pop_front().unwrap();
+            if self
+                .histories
+                .get(&intent_id)
+                .is_some_and(|h| h.finished_at == Some(at))
+            {
+                self.histories.remove(&intent_id);
+                self.live.remove(&intent_id);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPOCH: u64 = 1_770_000_000_000;
+
+    fn seq(n: u64) -> u64 {
+        (EPOCH << COUNTER_BITS) + n
+    }
+
+    #[test]
+    fn test_buffer_is_bounded_and_numbered_per_intent() {
+        let mut log = TransitionLog::with_epoch(2, 3600, EPOCH);
+        log.record("a", IntentStatus::Pending, IntentStatus::SwapCompleted, 1);
+        log.record("a", IntentStatus::SwapCompleted, IntentStatus::Bridging, 2);
+        log.record(
+            "a",
+            IntentStatus::Bridging,
+            IntentStatus::BridgeCompleted,
+            3,
+        );
+        let other = log.record("b", IntentStatus::Pending, IntentStatus::Failed, 4);
+
+        let seqs: Vec<_> = log.since("a", None).iter().map(|t| t.seq).collect();
+        assert_eq!(seqs, [seq(2), seq(3)]);
+        assert_eq!(log.since("a", Some(seq(2))).len(), 1);
+        assert_eq!(other.seq, seq(1));
+    }
+
+    #[test]
+    fn test_numbers_keep_increasing_after_a_restart() {
+        let mut before = TransitionLog::with_epoch(8, 3600, EPOCH);
+        let mut last = 0;
+        for _ in 0..100 {
+            last = before
+                .record("a", IntentStatus::Pending, IntentStatus::Bridging, 1)
+                .seq;
+        }
+
+        // A restart a millisecond later starts the intent over at 1
+        let mut after = TransitionLog::with_epoch(8, 3600, EPOCH + 1);
+        let first = after.record(
+            "a",
+            IntentStatus::Bridging,
+            IntentStatus::BridgeCompleted,
+            2,
+        );
+        assert!(first.seq > last);
+        // A client resuming from before the restart still gets it
+        assert_eq!(after.since("a", Some(last)), [first]);
+    }
+
+    #[test]
+    fn test_finished_histories_are_evicted_after_retention() {
+        let mut log = TransitionLog::with_epoch(8, 60, EPOCH);
+        log.record("done", IntentStatus::Bridging, IntentStatus::Failed, 100);
+        log.record("busy", IntentStatus::Pending, IntentStatus::Bridging, 100);
+        // Finished, then moved again (e.g. a retry): not evicted
+        log.record("retried", IntentStatus::Bridging, IntentStatus::Failed, 100);
+        log.record("retried", IntentStatus::Failed, IntentStatus::Pending, 120);
+
+        log.record(
+            "busy",
+            IntentStatus::Bridging,
+            IntentStatus::BridgeCompleted,
+            160,
+        );
+        assert_eq!(log.since("done", None).len(), 1);
+
+        log.record(
+            "busy",
+            IntentStatus::BridgeCompleted,
+            IntentStatus::Deposited,
+            161,
+        );
+        assert!(log.since("done", None).is_empty());
+        assert_eq!(log.since("busy", None).len(), 3);
+        assert_eq!(log.since("retried", None).len(), 2);
+        assert_eq!(log.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_subscribers_receive_their_intents_transitions() {
+        let mut log = TransitionLog::with_epoch(8, 3600, EPOCH);
+        let mut rx = log.subscribe("a");
+
+        // Traffic on other intents doesn't fill a's channel
+        for at in 0..(2 * BROADCAST_CAPACITY as i64) {
+            log.record("b", IntentStatus::Pending, IntentStatus::Bridging, at);
+        }
+        log.record("a", IntentStatus::Pending, IntentStatus::Bridging, 1);
+
+        let received = rx.recv().await.unwrap();
+        assert_eq!(received.intent_id, "a");
+        assert_eq!(received.to, IntentStatus::Bridging);
+    }
+}
@@ -0,0 +1,266 @@
+//! Admin Handlers
+//!
+//! Operator endpoints for repairing drifted state
+
+use axum::{extract::State, http::StatusCode};
+use naisu_core::EvmChain;
+use naisu_sui::HookLogClient;
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+use crate::common::extract::ApiJson;
+use crate::common::response::{ApiErrorResponse, ApiResponse, ApiSuccessResponse};
+use crate::feature::intent::{replay_logs, ReplaySummary};
+use crate::state::AppState;
+
+/// Block range to re-ingest
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ReplayRequest {
+    pub chain: EvmChain,
+    pub from_block: u64,
+    pub to_block: u64,
+}
+
+/// POST /admin/replay — re-ingest hook events for a block range
+#[utoipa::path(
+    post,
+    path = "/admin/replay",
+    tag = "admin",
+    request_body = ReplayRequest,
+    responses(
+        (status = 200, description = "Range replayed", body = ApiSuccessResponse<ReplaySummary>),
+        (status = 400, description = "Invalid range or unconfigured chain", body = ApiErrorResponse),
+        (status = 401, description = "Invalid or missing admin key", body = ApiErrorResponse),
+        (status = 502, description = "RPC backfill failed", body = ApiErrorResponse)
+    )
+)]
+pub async fn replay_events(
+    State(state): State<AppState>,
+    ApiJson(request): ApiJson<ReplayRequest>,
+) -> ApiResponse<ReplaySummary> {
+    let evm = &state.config.evm;
+    if request.chain != evm.default_chain {
+        return Err(ApiErrorResponse::new(format!(
+            "No hook listener configured for {}",
+            request.chain.name()
+        ))
+        .with_code(StatusCode::BAD_REQUEST));
+    }
+    if request.to_block < request.from_block {
+        return Err(
+            ApiErrorResponse::new("to_block must not be before from_block")
+                .with_code(StatusCode::BAD_REQUEST),
+        );
+    }
+    let max_blocks = state.config.admin.max_replay_blocks;
+    if request.to_block - request.from_block >= max_blocks {
+        return Err(
+            ApiErrorResponse::new(format!("Range exceeds {} blocks", max_blocks))
+                .with_code(StatusCode::BAD_REQUEST),
+        );
+    }
+
+    let logs = HookLogClient::new(&evm.rpc_url, &evm.hook_address)
+        .backfill(request.from_block, request.to_block)
+        .await
+        .map_err(|e| {
+            ApiErrorResponse::new("Backfill failed")
+                .with_code(StatusCode::BAD_GATEWAY)
+                .with_error(e.to_string())
+        })?;
+
+    let summary = replay_logs(&state, request.chain, &logs).await;
+
+    tracing::info!(
+        chain = request.chain.name(),
+        from_block = request.from_block,
+        to_block = request.to_block,
+        events = summary.events_replayed,
+        changed = summary.intents_changed,
+        malformed = summary.malformed,
+        "Replayed hook events"
+    );
+
+    Ok(ApiSuccessResponse::new(summary))
+}
+
+/// GET /admin/config — the config the running service resolved, with
+/// secrets and RPC URL paths redacted
+#[utoipa::path(
+    get,
+    path = "/admin/config",
+    tag = "admin",
+    responses(
+        (status = 200, description = "Effective config", body = ApiSuccessResponse<Object>),
+        (status = 401, description = "Invalid or missing admin key", body = ApiErrorResponse)
+    )
+)]
+pub async fn get_config(State(state): State<AppState>) -> ApiResponse<serde_json::Value> {
+    serde_json::to_value(state.config.as_ref())
+        .map(ApiSuccessResponse::new)
+        .map_err(|e| {
+            ApiErrorResponse::new("Failed to serialize config")
+                .with_code(StatusCode::INTERNAL_SERVER_ERROR)
+                .with_error(e.to_string())
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use axum::{body::Body, http::Request};
+    use naisu_core::testing::intent_created_log;
+    use naisu_core::{IntentStatus, SuiNetwork};
+    use serde_json::json;
+    use tower::ServiceExt;
+    use wiremock::matchers::{body_partial_json, method};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use crate::config::Config;
+    use crate::feature::intent::replay_logs;
+    use crate::route::app_routes;
+    use crate::state::AppState;
+
+    const ADMIN_KEY: &str = "test-admin-key";
+
+    fn test_state(rpc_url: String) -> AppState {
+        let mut config = Config::load(SuiNetwork::Testnet, &HashMap::new()).unwrap();
+        config.evm.rpc_url = rpc_url;
+        config.admin.api_key = Some(ADMIN_KEY.to_string());
+        AppState::with_config(config)
+    }
+
+    async fn replay(state: AppState, auth: Option<&str>) -> (u16, serde_json::Value) {
+        let body = json!({
+            "chain": state.config.evm.default_chain,
+            "from_block": 100,
+            "to_block": 110,
+        });
+        let mut request =
+            Request::post("/api/v1/admin/replay").header("content-type", "application/json");
+        if let Some(key) = auth {
+            request = request.header("authorization", format!("Bearer {}", key));
+        }
+
+        let response = app_routes(state)
+            .oneshot(request.body(Body::from(body.to_string())).unwrap())
+            .await
+            .unwrap();
+        let status = response.status().as_u16();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_replay_reemits_range_events_idempotently() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(body_partial_json(json!({
+                "method": "eth_getLogs",
+                "params": [{ "fromBlock": "0x64", "toBlock": "0x6e" }],
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": [intent_created_log("0x1"), intent_created_log("0x2")],
+            })))
+            .mount(&server)
+            .await;
+
+        let state = test_state(server.uri());
+
+        let (status, json) = replay(state.clone(), Some(ADMIN_KEY)).await;
+        assert_eq!(status, 200);
+        assert_eq!(json["data"]["events_replayed"], 2);
+        assert_eq!(json["data"]["intents_changed"], 2);
+
+        let intent = state
+            .get_intent(&format!("0x{:064x}", 2))
+            .await
+            .expect("replayed intent is stored");
+        assert_eq!(intent.status, IntentStatus::Pending);
+        assert_eq!(intent.usdc_amount.as_deref(), Some("4990000"));
+
+        let (status, json) = replay(state.clone(), Some(ADMIN_KEY)).await;
+        assert_eq!(status, 200);
+        assert_eq!(json["data"]["events_replayed"], 2);
+        assert_eq!(json["data"]["intents_changed"], 0);
+        assert_eq!(state.list_intents().await.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_event_intent_is_found_by_onchain_id() {
+        let state = test_state("http://127.0.0.1:1".to_string());
+        let chain = state.config.evm.default_chain;
+        replay_logs(&state, chain, &[intent_created_log("0xabcdef")]).await;
+
+        // As a frontend might copy it from the event: no prefix, any case
+        let onchain_id = format!("{:064X}", 0xabcdef);
+        let response = app_routes(state)
+            .oneshot(
+                Request::get(format!("/api/v1/intents/{}", onchain_id))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["data"]["id"], format!("0x{:064x}", 0xabcdef));
+    }
+
+    #[tokio::test]
+    async fn test_replay_requires_admin_key() {
+        let state = test_state("http://127.0.0.1:1".to_string());
+
+        let (status, json) = replay(state.clone(), None).await;
+        assert_eq!(status, 401);
+        assert_eq!(json["success"], false);
+
+        let (status, _) = replay(state, Some("wrong")).await;
+        assert_eq!(status, 401);
+    }
+
+    #[tokio::test]
+    async fn test_config_dump_redacts_secrets() {
+        let mut config = Config::load(SuiNetwork::Testnet, &HashMap::new()).unwrap();
+        config.admin.api_key = Some(ADMIN_KEY.to_string());
+        config.evm.rpc_url = "https://base-mainnet.example.com/v2/provider-key".to_string();
+        config.evm.hook_address = "0xf0093fcf07aa10de35b78d1f33c60439d11bc0c0".to_string();
+        let state = AppState::with_config(config);
+
+        let response = app_routes(state)
+            .oneshot(
+                Request::get("/api/v1/admin/config")
+                    .header("authorization", format!("Bearer {}", ADMIN_KEY))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let text = String::from_utf8(body.to_vec()).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&text).unwrap();
+
+        assert!(!text.contains(ADMIN_KEY));
+        assert!(!text.contains("provider-key"));
+        assert_eq!(json["data"]["admin"]["api_key"], "<redacted>");
+        assert_eq!(
+            json["data"]["evm"]["rpc_url"],
+            "https://base-mainnet.example.com/<redacted>"
+        );
+        assert_eq!(
+            json["data"]["evm"]["hook_address"],
+            "0xf0093fcf07aa10de35b78d1f33c60439d11bc0c0"
+        );
+    }
+}
@@ -0,0 +1,9 @@
+//! Admin Feature Module
+//!
+//! Operator endpoints, guarded by `require_admin_key`
+
+pub mod handler;
+pub mod route;
+
+pub use handler::*;
+pub use route::admin_routes;
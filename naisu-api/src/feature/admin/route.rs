@@ -0,0 +1,19 @@
+//! Admin Routes
+
+use axum::{
+    middleware,
+    routing::{get, post},
+    Router,
+};
+
+use super::handler;
+use crate::middleware::require_admin_key;
+use crate::state::AppState;
+
+/// Create admin routes; every route requires the admin key
+pub fn admin_routes(state: AppState) -> Router<AppState> {
+    Router::new()
+        .route("/replay", post(handler::replay_events))
+        .route("/config", get(handler::get_config))
+        .route_layer(middleware::from_fn_with_state(state, require_admin_key))
+}
@@ -0,0 +1,486 @@
+use axum::{extract::State, http::StatusCode};
+use naisu_core::{addr::to_sui_bytes32, EvmChain};
+use naisu_sui::{build_sui_burn_params, circle::CctpClient, CctpPackages, SuiBurnTxParams};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::common::extract::ApiJson;
+use crate::common::quote::{self, BridgeQuote};
+use crate::common::response::{ApiErrorResponse, ApiResponse, ApiSuccessResponse};
+use crate::state::AppState;
+
+/// Request to burn USDC on Sui and mint it on an EVM chain
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct InitBridgeRequest {
+    /// Sender's Sui address
+    pub sender: String,
+    /// USDC amount to burn (raw, 6 decimals)
+    pub amount: String,
+    /// Recipient EVM address
+    pub evm_destination: String,
+    /// Destination chain (default from config)
+    pub evm_chain: Option<EvmChain>,
+    /// Seconds the transaction stays valid, 30 to 3600 (default from config)
+    pub deadline_secs: Option<u64>,
+    /// Minimum USDC to receive on the destination (default: the quoted
+    /// `amount_out`)
+    pub min_amount_out: Option<String>,
+    /// Quote from `POST /bridge/quote` the user agreed to
+    pub quote_id: String,
+}
+
+/// Request for the current terms of a Sui → EVM transfer
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct QuoteRequest {
+    /// USDC amount to burn (raw, 6 decimals)
+    pub amount: String,
+    /// Destination chain (default from config)
+    pub evm_chain: Option<EvmChain>,
+}
+
+/// Arguments for the Sui `deposit_for_burn` Move call
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct BridgeTxParams {
+    #[serde(flatten)]
+    pub burn: SuiBurnTxParams,
+    pub state_object: String,
+    pub message_transmitter_state: String,
+    pub treasury: String,
+    pub min_amount_out: String,
+    /// Absolute unix timestamp (seconds) after which the call aborts
+    pub deadline: u64,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct InitBridgeResponse {
+    pub tx_params: BridgeTxParams,
+    pub summary: String,
+}
+
+/// POST /bridge/quote — price a Sui → EVM transfer and hold the terms briefly
+#[utoipa::path(
+    post,
+    path = "/bridge/quote",
+    tag = "bridge",
+    request_body = QuoteRequest,
+    responses(
+        (status = 200, description = "Quote, usable until `expires_at`", body = ApiSuccessResponse<BridgeQuote>),
+        (status = 400, description = "Invalid amount or destination chain", body = ApiErrorResponse),
+        (status = 502, description = "Circle's fee API is unavailable", body = ApiErrorResponse)
+    )
+)]
+pub async fn quote_sui_to_evm_bridge(
+    State(state): State<AppState>,
+    ApiJson(request): ApiJson<QuoteRequest>,
+) -> ApiResponse<BridgeQuote> {
+    let amount: u64 = request.amount.parse().map_err(|_| {
+        ApiErrorResponse::new(format!("Invalid amount: {}", request.amount))
+            .with_code(StatusCode::BAD_REQUEST)
+    })?;
+    let evm_chain = request.evm_chain.unwrap_or(state.config.evm.default_chain);
+
+    let pricing = current_price(&state, evm_chain, amount).await?;
+    let quote = state
+        .quotes
+        .write()
+        .await
+        .issue(amount, evm_chain, pricing, unix_now());
+    Ok(ApiSuccessResponse::new(quote))
+}
+
+/// POST /bridge/sui-to-evm — build the unsigned CCTP burn for the user to sign
+#[utoipa::path(
+    post,
+    path = "/bridge/sui-to-evm",
+    tag = "bridge",
+    request_body = InitBridgeRequest,
+    responses(
+        (status = 200, description = "Unsigned burn parameters", body = ApiSuccessResponse<InitBridgeResponse>),
+        (status = 400, description = "Invalid request", body = ApiErrorResponse),
+        (status = 409, description = "Quote expired or rate no longer meets min_amount_out", body = ApiErrorResponse),
+        (status = 422, description = "Invalid EVM destination address, or request doesn't match its quote", body = ApiErrorResponse),
+        (status = 502, description = "Circle's fee API is unavailable", body = ApiErrorResponse)
+    )
+)]
+pub async fn init_sui_to_evm_bridge(
+    State(state): State<AppState>,
+    ApiJson(mut request): ApiJson<InitBridgeRequest>,
+) -> ApiResponse<InitBridgeResponse> {
+    let now = unix_now();
+
+    to_sui_bytes32(&request.evm_destination).map_err(|e| {
+        ApiErrorResponse::new(format!("Invalid evm_destination: {}", e))
+            .with_code(StatusCode::UNPROCESSABLE_ENTITY)
+    })?;
+
+    let evm_chain = request.evm_chain.unwrap_or(state.config.evm.default_chain);
+
+    {
+        let quotes = state.quotes.read().await;
+        let quote = quotes
+            .get(&request.quote_id, now)
+            .ok_or_else(quote_expired)?;
+        if quote.amount != request.amount || quote.evm_chain != evm_chain {
+            return Err(ApiErrorResponse::new(format!(
+                "Request does not match quote {}",
+                request.quote_id
+            ))
+            .with_code(StatusCode::UNPROCESSABLE_ENTITY));
+        }
+        request
+            .min_amount_out
+            .get_or_insert_with(|| quote.amount_out.clone());
+    }
+
+    let tx_params = build_tx_params(
+        &CctpPackages::for_network(state.config.network),
+        &request,
+        evm_chain,
+        now,
+        state.config.bridge.deadline_window_secs,
+    )
+    .map_err(|e| ApiErrorResponse::new(e).with_code(StatusCode::BAD_REQUEST))?;
+
+    // The fee may have moved since the user saw it
+    let amount = parse_raw("amount", &tx_params.burn.amount_raw)?;
+    let min_amount_out = parse_raw("min_amount_out", &tx_params.min_amount_out)?;
+    let (_, amount_out) = current_price(&state, evm_chain, amount).await?;
+    if amount_out < min_amount_out {
+        return Err(quote_expired());
+    }
+
+    let summary = format!(
+        "Burn {} USDC on Sui → Mint on {} (domain {}), valid until {}",
+        request.amount,
+        evm_chain.name(),
+        tx_params.burn.dest_domain,
+        tx_params.deadline
+    );
+
+    Ok(ApiSuccessResponse::new(InitBridgeResponse {
+        tx_params,
+        summary,
+    }))
+}
+
+/// `(fee, amount_out)` for bridging `amount` from Sui to `evm_chain` right now
+async fn current_price(
+    state: &AppState,
+    evm_chain: EvmChain,
+    amount: u64,
+) -> Result<(u64, u64), ApiErrorResponse> {
+    let dest_domain = evm_chain.cctp_domain().ok_or_else(|| {
+        ApiErrorResponse::new(format!("{} has no CCTP domain", evm_chain.name()))
+            .with_code(StatusCode::BAD_REQUEST)
+    })?;
+    let cctp = CctpClient::new(&state.config.bridge.cctp_api_url)
+        .with_timeouts(state.config.bridge.http_timeouts);
+    quote::price(
+        &cctp,
+        state.config.network.cctp_domain(),
+        dest_domain,
+        amount,
+    )
+    .await
+    .map_err(|e| {
+        ApiErrorResponse::new(format!("Could not price the transfer: {}", e))
+            .with_code(StatusCode::BAD_GATEWAY)
+    })
+}
+
+/// Raw USDC amount `value` of `field`
+fn parse_raw(field: &str, value: &str) -> Result<u64, ApiErrorResponse> {
+    value.parse().map_err(|_| {
+        ApiErrorResponse::new(format!("Invalid {}: {}", field, value))
+            .with_code(StatusCode::BAD_REQUEST)
+    })
+}
+
+fn quote_expired() -> ApiErrorResponse {
+    ApiErrorResponse::new("quote expired, please refresh").with_code(StatusCode::CONFLICT)
+}
+
+fn unix_now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+/// Accepted validity windows for a burn: long enough to sign and submit,
+/// short enough that a stale transaction can't be replayed much later
+const DEADLINE_WINDOW_SECS: std::ops::RangeInclusive<u64> = 30..=3600;
+
+/// Build the `deposit_for_burn` arguments with an absolute deadline computed from `now`
+fn build_tx_params(
+    packages: &CctpPackages,
+    request: &InitBridgeRequest,
+    evm_chain: EvmChain,
+    now: u64,
+    default_window_secs: u64,
+) -> Result<BridgeTxParams, String> {
+    let amount: u64 = request
+        .amount
+        .parse()
+        .map_err(|_| format!("Invalid amount: {}", request.amount))?;
+
+    let min_amount_out = match &request.min_amount_out {
+        Some(min) => min
+            .parse::<u64>()
+            .map_err(|_| format!("Invalid min_amount_out: {}", min))?,
+        None => amount,
+    };
+    if min_amount_out > amount {
+        return Err(format!(
+            "min_amount_out {} exceeds bridged amount {}",
+            min_amount_out, amount
+        ));
+    }
+
+    let burn = build_sui_burn_params(packages, amount, evm_chain, &request.evm_destination)
+        .map_err(|e| e.to_string())?;
+
+    let window = request.deadline_secs.unwrap_or(default_window_secs);
+    if !DEADLINE_WINDOW_SECS.contains(&window) {
+        return Err(format!(
+            "deadline_secs must be between {} and {}, got {}",
+            DEADLINE_WINDOW_SECS.start(),
+            DEADLINE_WINDOW_SECS.end(),
+            window
+        ));
+    }
+    let deadline = now
+        .checked_add(window)
+        .ok_or_else(|| format!("deadline_secs {} overflows the clock", window))?;
+
+    Ok(BridgeTxParams {
+        burn,
+        state_object: packages.state_object.clone(),
+        message_transmitter_state: packages.message_transmitter_state.clone(),
+        treasury: packages.usdc_treasury.clone(),
+        min_amount_out: min_amount_out.to_string(),
+        deadline,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use naisu_core::SuiNetwork;
+    use std::collections::HashMap;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const NOW: u64 = 1_770_000_000;
+
+    fn testnet() -> CctpPackages {
+        CctpPackages::for_network(SuiNetwork::Testnet)
+    }
+
+    fn request() -> InitBridgeRequest {
+        InitBridgeRequest {
+            sender: "0x2".to_string(),
+            amount: "1000000".to_string(),
+            evm_destination: "0x1234567890123456789012345678901234567890".to_string(),
+            evm_chain: None,
+            deadline_secs: None,
+            min_amount_out: None,
+            quote_id: "unquoted".to_string(),
+        }
+    }
+
+    /// State whose Circle API charges `bps` on Sui → Base Sepolia transfers
+    async fn state_with_fee(server: &MockServer, bps: f64) -> AppState {
+        Mock::given(method("GET"))
+            .and(path("/v2/burn/USDC/fees/8/6"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                { "finalityThreshold": 2000, "minimumFee": bps }
+            ])))
+            .mount(server)
+            .await;
+        let mut config = crate::config::Config::load(SuiNetwork::Testnet, &HashMap::new()).unwrap();
+        config.bridge.cctp_api_url = server.uri();
+        config.evm.default_chain = EvmChain::BaseSepolia;
+        AppState::with_config(config)
+    }
+
+    async fn quote(state: &AppState) -> BridgeQuote {
+        let request = QuoteRequest {
+            amount: "1000000".to_string(),
+            evm_chain: None,
+        };
+        quote_sui_to_evm_bridge(State(state.clone()), ApiJson(request))
+            .await
+            .unwrap()
+            .data
+    }
+
+    fn with_quote(quote: &BridgeQuote) -> InitBridgeRequest {
+        InitBridgeRequest {
+            quote_id: quote.quote_id.clone(),
+            ..request()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_quote_is_priced_by_circle() {
+        let server = MockServer::start().await;
+        let state = state_with_fee(&server, 1.0).await;
+
+        let quote = quote(&state).await;
+        assert_eq!(quote.fee, "100");
+        assert_eq!(quote.amount_out, "999900");
+
+        let ok = init_sui_to_evm_bridge(State(state), ApiJson(with_quote(&quote)))
+            .await
+            .unwrap()
+            .data;
+        assert_eq!(ok.tx_params.min_amount_out, "999900");
+    }
+
+    #[tokio::test]
+    async fn test_expired_quote_is_rejected() {
+        let server = MockServer::start().await;
+        let state = state_with_fee(&server, 0.0).await;
+        let ttl = state.config.bridge.quote_ttl_secs;
+        let expired = state.quotes.write().await.issue(
+            1_000_000,
+            EvmChain::BaseSepolia,
+            (0, 1_000_000),
+            unix_now() - ttl - 1,
+        );
+
+        let err = init_sui_to_evm_bridge(State(state.clone()), ApiJson(with_quote(&expired)))
+            .await
+            .unwrap_err();
+        assert_eq!(err.code, 409);
+        assert_eq!(err.message, "quote expired, please refresh");
+
+        let unknown = InitBridgeRequest {
+            quote_id: "unknown".to_string(),
+            ..request()
+        };
+        let err = init_sui_to_evm_bridge(State(state), ApiJson(unknown))
+            .await
+            .unwrap_err();
+        assert_eq!(err.code, 409);
+    }
+
+    #[tokio::test]
+    async fn test_fee_rise_since_quote_is_rejected() {
+        let server = MockServer::start().await;
+        let state = state_with_fee(&server, 1.0).await;
+        let stale = state.quotes.write().await.issue(
+            1_000_000,
+            EvmChain::BaseSepolia,
+            (0, 1_000_000),
+            unix_now(),
+        );
+
+        let err = init_sui_to_evm_bridge(State(state), ApiJson(with_quote(&stale)))
+            .await
+            .unwrap_err();
+        assert_eq!(err.code, 409);
+    }
+
+    #[tokio::test]
+    async fn test_fee_api_outage_is_a_bad_gateway() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(503))
+            .mount(&server)
+            .await;
+        let mut config = crate::config::Config::load(SuiNetwork::Testnet, &HashMap::new()).unwrap();
+        config.bridge.cctp_api_url = server.uri();
+        let state = AppState::with_config(config);
+
+        let request = QuoteRequest {
+            amount: "1000000".to_string(),
+            evm_chain: Some(EvmChain::BaseSepolia),
+        };
+        let err = quote_sui_to_evm_bridge(State(state), ApiJson(request))
+            .await
+            .unwrap_err();
+        assert_eq!(err.code, 502);
+    }
+
+    #[test]
+    fn test_deadline_relative_to_clock() {
+        let params =
+            build_tx_params(&testnet(), &request(), EvmChain::BaseSepolia, NOW, 600).unwrap();
+        assert_eq!(params.deadline, NOW + 600);
+        assert_eq!(params.min_amount_out, "1000000");
+        assert_eq!(params.burn.dest_domain, 6);
+
+        let custom = InitBridgeRequest {
+            deadline_secs: Some(120),
+            ..request()
+        };
+        assert_eq!(
+            build_tx_params(&testnet(), &custom, EvmChain::BaseSepolia, NOW, 600)
+                .unwrap()
+                .deadline,
+            NOW + 120
+        );
+    }
+
+    #[test]
+    fn test_deadline_window_is_bounded() {
+        let with_window = |secs: u64| InitBridgeRequest {
+            deadline_secs: Some(secs),
+            ..request()
+        };
+        for secs in [0, 29, 3601, u64::MAX] {
+            assert!(
+                build_tx_params(
+                    &testnet(),
+                    &with_window(secs),
+                    EvmChain::BaseSepolia,
+                    NOW,
+                    600
+                )
+                .is_err(),
+                "{} secs accepted",
+                secs
+            );
+        }
+        // An in-range window still can't wrap the clock
+        assert!(
+            build_tx_params(&testnet(), &request(), EvmChain::BaseSepolia, u64::MAX, 600).is_err()
+        );
+    }
+
+    #[test]
+    fn test_mainnet_params_carry_no_testnet_ids() {
+        let mainnet = build_tx_params(
+            &CctpPackages::for_network(SuiNetwork::Mainnet),
+            &request(),
+            EvmChain::Base,
+            NOW,
+            600,
+        )
+        .unwrap();
+        let json = serde_json::to_string(&mainnet).unwrap();
+
+        let testnet = testnet();
+        for id in [
+            &testnet.token_messenger_minter,
+            &testnet.message_transmitter,
+            &testnet.usdc_coin_type,
+            &testnet.state_object,
+            &testnet.message_transmitter_state,
+            &testnet.usdc_treasury,
+        ] {
+            assert!(!json.contains(id.as_str()), "mainnet params contain {}", id);
+        }
+    }
+
+    #[test]
+    fn test_rejects_min_amount_above_amount() {
+        let req = InitBridgeRequest {
+            min_amount_out: Some("1000001".to_string()),
+            ..request()
+        };
+        assert!(build_tx_params(&testnet(), &req, EvmChain::BaseSepolia, NOW, 600).is_err());
+    }
+}
@@ -0,0 +1,14 @@
+use axum::routing::post;
+use axum::Router;
+
+use crate::state::AppState;
+
+use super::handler;
+
+/// Quoting and the quoted Sui → EVM burn, mounted only when quotes are
+/// enabled (a burn needs a quote)
+pub fn bridge_routes() -> Router<AppState> {
+    Router::new()
+        .route("/quote", post(handler::quote_sui_to_evm_bridge))
+        .route("/sui-to-evm", post(handler::init_sui_to_evm_bridge))
+}
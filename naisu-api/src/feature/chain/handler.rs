@@ -0,0 +1,91 @@
+use naisu_core::{EvmChain, SuiNetwork};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::common::response::{ApiResponse, ApiSuccessResponse};
+
+/// Chain metadata for the frontend
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ChainInfo {
+    pub id: String,
+    pub name: String,
+    pub chain_type: String,
+    /// EVM chain ID (None for Sui)
+    pub chain_id: Option<u64>,
+    pub native_symbol: String,
+    pub native_decimals: u8,
+    pub explorer_url: String,
+    pub is_testnet: bool,
+    /// Circle CCTP domain, if USDC can be bridged via CCTP
+    pub cctp_domain: Option<u32>,
+}
+
+impl From<EvmChain> for ChainInfo {
+    fn from(chain: EvmChain) -> Self {
+        Self {
+            id: chain.as_str().to_string(),
+            name: chain.name().to_string(),
+            chain_type: "evm".to_string(),
+            chain_id: Some(chain.chain_id()),
+            native_symbol: chain.native_symbol().to_string(),
+            native_decimals: chain.native_decimals(),
+            explorer_url: chain.explorer_url().to_string(),
+            is_testnet: chain.is_testnet(),
+            cctp_domain: chain.cctp_domain(),
+        }
+    }
+}
+
+impl From<SuiNetwork> for ChainInfo {
+    fn from(network: SuiNetwork) -> Self {
+        Self {
+            id: format!("sui_{}", network.as_str()),
+            name: network.name().to_string(),
+            chain_type: "sui".to_string(),
+            chain_id: None,
+            native_symbol: "SUI".to_string(),
+            native_decimals: 9,
+            explorer_url: network.explorer_url().to_string(),
+            is_testnet: network.is_testnet(),
+            cctp_domain: Some(network.cctp_domain()),
+        }
+    }
+}
+
+/// GET /chains — list supported chains with their metadata
+#[utoipa::path(
+    get,
+    path = "/chains",
+    tag = "chains",
+    responses((status = 200, description = "Supported chains", body = ApiSuccessResponse<Vec<ChainInfo>>))
+)]
+pub async fn list_chains() -> ApiResponse<Vec<ChainInfo>> {
+    let chains = EvmChain::ALL
+        .into_iter()
+        .map(ChainInfo::from)
+        .chain([SuiNetwork::Mainnet, SuiNetwork::Testnet].map(ChainInfo::from))
+        .collect();
+
+    Ok(ApiSuccessResponse::new(chains))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_base_chain_metadata() {
+        let chains = list_chains().await.unwrap().data;
+
+        let base = chains.iter().find(|c| c.id == "base").unwrap();
+        assert_eq!(base.chain_id, Some(8453));
+        assert_eq!(base.cctp_domain, Some(6));
+        assert_eq!(base.explorer_url, "https://basescan.org");
+        assert!(!base.is_testnet);
+
+        let sui = chains.iter().find(|c| c.id == "sui_testnet").unwrap();
+        assert_eq!(sui.native_symbol, "SUI");
+        assert_eq!(sui.native_decimals, 9);
+        assert!(sui.is_testnet);
+    }
+}
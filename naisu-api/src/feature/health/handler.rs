@@ -1,18 +1,35 @@
+use axum::extract::State;
+use naisu_core::{EvmChain, SuiNetwork};
+use utoipa::ToSchema;
+
 use crate::common::response::{ApiResponse, ApiSuccessResponse};
+use crate::state::AppState;
 
 /// Public health check endpoint
+#[utoipa::path(
+    get,
+    path = "/health",
+    tag = "health",
+    responses((status = 200, description = "Service is healthy"))
+)]
 pub async fn public_health_check() -> ApiResponse<()> {
     Ok(ApiSuccessResponse::new(()).with_message("Service is healthy"))
 }
 
 /// Detailed health check with version info
-#[derive(Debug, serde::Serialize)]
+#[derive(Debug, serde::Serialize, ToSchema)]
 pub struct HealthDetails {
     pub status: String,
     pub version: String,
     pub service: String,
 }
 
+#[utoipa::path(
+    get,
+    path = "/health/detailed",
+    tag = "health",
+    responses((status = 200, description = "Service status and version", body = ApiSuccessResponse<HealthDetails>))
+)]
 pub async fn detailed_health_check() -> ApiResponse<HealthDetails> {
     let health = HealthDetails {
         status: "healthy".to_string(),
@@ -22,3 +39,64 @@ pub async fn detailed_health_check() -> ApiResponse<HealthDetails> {
 
     Ok(ApiSuccessResponse::new(health))
 }
+
+/// Deployed build and the networks it is wired to
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct VersionInfo {
+    /// Crate version
+    pub version: String,
+    /// Commit the binary was built from (`unknown` outside a git checkout)
+    pub git_sha: String,
+    /// Resolved `NETWORK`, i.e. the Sui network
+    #[schema(value_type = String, example = "testnet")]
+    pub network: SuiNetwork,
+    pub evm_chain: EvmChain,
+    pub evm_chain_id: u64,
+}
+
+#[utoipa::path(
+    get,
+    path = "/version",
+    tag = "health",
+    responses((status = 200, description = "Build version, commit and networks", body = ApiSuccessResponse<VersionInfo>))
+)]
+pub async fn get_version(State(state): State<AppState>) -> ApiResponse<VersionInfo> {
+    Ok(ApiSuccessResponse::new(VersionInfo {
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        git_sha: env!("GIT_SHA").to_string(),
+        network: state.config.network,
+        evm_chain: state.config.evm.default_chain,
+        evm_chain_id: state.config.evm.chain_id,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use axum::{body::Body, http::Request};
+    use naisu_core::SuiNetwork;
+    use tower::ServiceExt;
+
+    use crate::config::Config;
+    use crate::route::app_routes;
+    use crate::state::AppState;
+
+    #[tokio::test]
+    async fn test_version_reports_package_version() {
+        let response = app_routes(AppState::with_config(
+            Config::load(SuiNetwork::Testnet, &HashMap::new()).unwrap(),
+        ))
+        .oneshot(Request::get("/api/v1/version").body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+        assert_eq!(response.status(), 200);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body = String::from_utf8(body.to_vec()).unwrap();
+        assert!(body.contains(&format!("\"version\":\"{}\"", env!("CARGO_PKG_VERSION"))));
+        assert!(body.contains("\"git_sha\""));
+    }
+}
@@ -9,3 +9,7 @@ pub fn health_routes() -> Router<AppState> {
         .route("/", get(handler::public_health_check))
         .route("/detailed", get(handler::detailed_health_check))
 }
+
+pub fn version_routes() -> Router<AppState> {
+    Router::new().route("/", get(handler::get_version))
+}
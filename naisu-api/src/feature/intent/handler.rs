@@ -1,121 +1,1315 @@
 //! Intent Handlers
 //!
-//! API endpoints for querying intents (cached/indexed)
+//! API endpoints for creating and querying intents
 
-use axum::{extract::Query, Json};
+use axum::{
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode},
+    response::sse::{Event, KeepAlive, Sse},
+};
+use futures_util::{stream, Stream, StreamExt};
+use naisu_core::{
+    addr::normalize_sui_address, is_native_token, AllocationOutcome, BridgeFailure,
+    CreateIntentRequest, Direction, EvmChain, FieldError, Intent, IntentStatus, RequestedDeposit,
+    ValidIntent, WithdrawIntentRequest, YieldStrategy,
+};
+use naisu_sui::{
+    AttestationStatus, CctpClient, PositionObject, ProgrammableTransactionBlock, ProtocolError,
+    ProtocolFactory, WithdrawFrom,
+};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::convert::Infallible;
+use tokio::sync::broadcast::error::RecvError;
+use utoipa::{IntoParams, ToSchema};
 
-use crate::common::response::{success::ApiSuccessResponse, ApiResponse};
+use super::plan::{plan_evm_to_sui, plan_sui_to_evm, DepositTarget, PlanContext, UnsignedStep};
+use crate::common::balance::native_balance;
+use crate::common::extract::ApiJson;
+use crate::common::lifi::{LiFiClient, LiFiTransfer, LIFI_API_URL};
+use crate::common::price::{usdc_value, PriceError};
+use crate::common::receipt::{IntentReceipt, ReceiptScheme};
+use crate::common::response::{ApiErrorResponse, ApiResponse, ApiSuccessResponse};
+use crate::common::transitions::StatusTransition;
+use crate::state::AppState;
 
 /// Intent response
-#[derive(Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct IntentResponse {
-    pub intent_id: String,
-    pub user: String,
-    pub amount: String,
-    pub min_apy: u64,
-    pub deadline: u64,
-    pub status: String,
-    pub target_protocol: String,
-    pub created_at: u64,
-    pub tx_digest: String,
+    pub id: String,
+    pub direction: Direction,
+    pub status: IntentStatus,
+    pub source_address: String,
+    pub dest_address: String,
+    /// None for withdraw-only intents
+    pub evm_chain: Option<EvmChain>,
+    pub input_token: String,
+    pub input_amount: String,
+    pub usdc_amount: Option<String>,
+    pub strategy: Option<YieldStrategy>,
+    /// Per-strategy outcome of a split intent
+    pub allocations: Vec<AllocationOutcome>,
+    pub position_object_id: Option<String>,
+    pub swap_tx_hash: Option<String>,
+    pub bridge_tx_hash: Option<String>,
+    pub bridge_nonce: Option<String>,
+    pub dest_tx_hash: Option<String>,
+    pub mint_tx_hash: Option<String>,
+    pub deposit_tx_hash: Option<String>,
+    /// Swap of the minted USDC into `output_token` (SuiToEvm)
+    pub output_swap_tx_hash: Option<String>,
+    /// WETH unwrap, when native ETH is delivered
+    pub unwrap_tx_hash: Option<String>,
+    pub error_message: Option<String>,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub archived: bool,
+    pub priority: Option<u8>,
+    /// Unix time after which an unfinished bridge is failed
+    pub bridge_deadline: Option<i64>,
+    /// Why an unattested bridge was failed
+    pub bridge_failure: Option<BridgeFailure>,
+    /// Token delivered on the EVM chain (SuiToEvm); unset means USDC
+    pub output_token: Option<String>,
+    /// Amount of `output_token` delivered, once completed
+    pub output_amount: Option<String>,
+}
+
+impl From<Intent> for IntentResponse {
+    fn from(intent: Intent) -> Self {
+        Self {
+            id: intent.id,
+            direction: intent.direction,
+            status: intent.status,
+            source_address: intent.source_address,
+            dest_address: intent.dest_address,
+            evm_chain: intent.evm_chain,
+            input_token: intent.input_token,
+            input_amount: intent.input_amount,
+            usdc_amount: intent.usdc_amount,
+            strategy: intent.strategy,
+            allocations: intent.allocations,
+            position_object_id: intent.position_object_id,
+            swap_tx_hash: intent.swap_tx_hash,
+            bridge_tx_hash: intent.bridge_tx_hash,
+            bridge_nonce: intent.bridge_nonce,
+            dest_tx_hash: intent.dest_tx_hash,
+            mint_tx_hash: intent.mint_tx_hash,
+            deposit_tx_hash: intent.deposit_tx_hash,
+            output_swap_tx_hash: intent.output_swap_tx_hash,
+            unwrap_tx_hash: intent.unwrap_tx_hash,
+            error_message: intent.error_message,
+            created_at: intent.created_at,
+            updated_at: intent.updated_at,
+            archived: intent.archived,
+            priority: intent.priority,
+            bridge_deadline: intent.bridge_deadline,
+            bridge_failure: intent.bridge_failure,
+            output_token: intent.output_token,
+            output_amount: intent.output_amount,
+        }
+    }
 }
 
 /// Query parameters for listing intents
-#[derive(Deserialize)]
+#[derive(Deserialize, IntoParams)]
 pub struct ListIntentsQuery {
-    pub status: Option<String>, // "open", "fulfilled", "expired"
+    /// Filter by status (any `IntentStatus` value)
+    #[param(value_type = Option<IntentStatus>)]
+    pub status: Option<String>,
+    /// Filter by direction
+    pub direction: Option<Direction>,
+    /// Maximum number of intents to return (default 20, at most 100)
     pub limit: Option<usize>,
+    /// Number of matching intents to skip (default 0)
+    pub offset: Option<usize>,
+    /// Include archived intents (default false)
+    #[serde(default)]
+    pub include_archived: bool,
+}
+
+/// One page of intents, newest first
+#[derive(Debug, Serialize, ToSchema)]
+pub struct IntentListResponse {
+    pub intents: Vec<IntentResponse>,
+    /// Intents matching the filters, across all pages
+    pub total: usize,
+    /// Whether intents remain past this page
+    pub has_more: bool,
+    /// `offset` of the next page, when there is one
+    pub next_offset: Option<usize>,
+}
+
+/// Check an EvmToSui deposit into one strategy: enabled, its protocol
+/// configured, and the input's USDC value within its deposit limits
+pub(crate) async fn validate_strategy(
+    state: &AppState,
+    request: &CreateIntentRequest,
+    strategy: YieldStrategy,
+    amount: u128,
+) -> Result<(), ApiErrorResponse> {
+    let value = usdc_value(
+        state.prices.as_ref(),
+        request.evm_chain,
+        &request.input_token,
+        amount,
+    )
+    .await
+    .map_err(|e| match e {
+        PriceError::NotFound { .. } => ApiErrorResponse::new(format!(
+            "input_token {} cannot be valued against the deposit limits",
+            request.input_token
+        ))
+        .with_code(StatusCode::UNPROCESSABLE_ENTITY),
+        e => ApiErrorResponse::new(format!("Could not price input_token: {}", e))
+            .with_code(StatusCode::SERVICE_UNAVAILABLE),
+    })?;
+    check_strategy(state, strategy, value)
+}
+
+/// Where a validated EvmToSui request deposits, checked against config and
+/// the chain
+pub(crate) async fn validate_target(
+    state: &AppState,
+    request: &CreateIntentRequest,
+    amount: u128,
+    deposit: RequestedDeposit,
+) -> Result<DepositTarget, ApiErrorResponse> {
+    match deposit {
+        RequestedDeposit::Split(parts) => {
+            for (strategy, amount) in &parts {
+                check_strategy(state, *strategy, *amount as u128)?;
+            }
+            Ok(DepositTarget::Split(parts))
+        }
+        RequestedDeposit::Strategy(strategy) => {
+            validate_strategy(state, request, strategy, amount).await?;
+            let position = validate_position(state, request, strategy).await?;
+            Ok(DepositTarget::Strategy { strategy, position })
+        }
+    }
+}
+
+/// Look up the request's `position_object_id` and check it is a position of
+/// `strategy`'s protocol the destination address can deposit into
+async fn validate_position(
+    state: &AppState,
+    request: &CreateIntentRequest,
+    strategy: YieldStrategy,
+) -> Result<Option<PositionObject>, ApiErrorResponse> {
+    let Some(object_id) = request.position_object_id.as_deref() else {
+        return Ok(None);
+    };
+    let (position, _) = lookup_position(state, strategy, object_id, &request.dest_address).await?;
+    Ok(Some(position))
+}
+
+/// Fetch `object_id` and check it is a position of `strategy`'s protocol
+/// usable by `owner`, with the `ObligationKey` `owner` holds for it when the
+/// position is a shared obligation
+async fn lookup_position(
+    state: &AppState,
+    strategy: YieldStrategy,
+    object_id: &str,
+    owner: &str,
+) -> Result<(PositionObject, Option<PositionObject>), ApiErrorResponse> {
+    let object_id = normalize_sui_address(object_id).map_err(|e| {
+        ApiErrorResponse::new("position_object_id is not a Sui object id")
+            .with_code(StatusCode::BAD_REQUEST)
+            .with_error(e.to_string())
+    })?;
+
+    let invalid = |e: ProtocolError| {
+        let code = match e {
+            ProtocolError::NotConfigured(_) => StatusCode::SERVICE_UNAVAILABLE,
+            _ => StatusCode::UNPROCESSABLE_ENTITY,
+        };
+        ApiErrorResponse::new(format!(
+            "position_object_id is not a {} position of {}",
+            strategy.name(),
+            owner
+        ))
+        .with_code(code)
+        .with_error(e.to_string())
+    };
+
+    let client = state.sui_client();
+    let object = client.get_object(&object_id).await?;
+    let protocols = &state.config.sui.protocols;
+    let position =
+        ProtocolFactory::position_object(strategy, &object, owner, protocols).map_err(invalid)?;
+
+    // A shared obligation is only usable by whoever holds its key
+    let mut key = None;
+    if let PositionObject::Shared { .. } = position {
+        if let Some(key_type) =
+            ProtocolFactory::position_key_type(strategy, protocols).map_err(invalid)?
+        {
+            let keys = client.get_owned_objects(owner, &key_type).await?;
+            key = Some(ProtocolFactory::check_position_key(&position, &keys).map_err(invalid)?);
+        }
+    }
+    Ok((position, key))
+}
+
+/// A native-ETH input must leave the configured gas reserve in the sender's
+/// wallet, or the user couldn't pay for the wrap, swap and burn
+async fn check_gas_reserve(
+    state: &AppState,
+    request: &CreateIntentRequest,
+    amount: u128,
+) -> Result<(), ApiErrorResponse> {
+    if !is_native_token(&request.input_token) {
+        return Ok(());
+    }
+
+    let evm = &state.config.evm;
+    let rpc_url = if request.evm_chain == evm.default_chain {
+        evm.rpc_url.as_str()
+    } else {
+        request.evm_chain.default_rpc_url()
+    };
+    let balance = native_balance(&state.http, rpc_url, &request.source_address)
+        .await
+        .map_err(|e| {
+            ApiErrorResponse::new("Cannot check the native ETH balance")
+                .with_code(StatusCode::SERVICE_UNAVAILABLE)
+                .with_error(e)
+        })?;
+
+    let reserve = evm.native_gas_reserve_wei;
+    if amount.saturating_add(reserve) > balance {
+        return Err(ApiErrorResponse::new(format!(
+            "input_amount {} wei leaves less than the {} wei gas reserve (balance {})",
+            amount, reserve, balance
+        ))
+        .with_code(StatusCode::UNPROCESSABLE_ENTITY));
+    }
+    Ok(())
+}
+
+/// `strategy` is enabled, its protocol configured, and `amount` within its
+/// deposit limits, given the deposit's value in USDC base units
+fn check_strategy(
+    state: &AppState,
+    strategy: YieldStrategy,
+    usdc_value: u128,
+) -> Result<(), ApiErrorResponse> {
+    if !state.config.strategies.is_enabled(strategy) {
+        return Err(
+            ApiErrorResponse::new(format!("Strategy {} is disabled", strategy.name()))
+                .with_code(StatusCode::FORBIDDEN),
+        );
+    }
+    if !ProtocolFactory::is_available(strategy, &state.config.sui.protocols) {
+        return Err(ApiErrorResponse::new(format!(
+            "Strategy {} is temporarily unavailable",
+            strategy.name()
+        ))
+        .with_code(StatusCode::SERVICE_UNAVAILABLE));
+    }
+    state
+        .config
+        .strategies
+        .check_deposit(strategy, usdc_value)
+        .map_err(|e| ApiErrorResponse::new(e).with_code(StatusCode::UNPROCESSABLE_ENTITY))
+}
+
+/// POST /intents — create a new intent
+#[utoipa::path(
+    post,
+    path = "/intents",
+    tag = "intents",
+    request_body = CreateIntentRequest,
+    responses(
+        (status = 201, description = "Intent created", body = ApiSuccessResponse<IntentResponse>),
+        (status = 400, description = "Invalid request", body = ApiErrorResponse),
+        (status = 403, description = "Strategy disabled", body = ApiErrorResponse),
+        (status = 404, description = "Position object not found", body = ApiErrorResponse),
+        (status = 422, description = "Invalid fields (all listed in `fields`), amount outside the strategy's deposit limits, or position object of another protocol or owner", body = ApiErrorResponse),
+        (status = 503, description = "Strategy protocol not configured", body = ApiErrorResponse)
+    )
+)]
+pub async fn create_intent(
+    State(state): State<AppState>,
+    ApiJson(mut request): ApiJson<CreateIntentRequest>,
+) -> ApiResponse<IntentResponse> {
+    let valid = request.validate().map_err(invalid_fields)?;
+
+    let id = uuid::Uuid::new_v4().to_string();
+    let priority = request.priority;
+
+    let mut intent = match valid {
+        ValidIntent::EvmToSui {
+            input_amount,
+            deposit,
+        } => {
+            check_gas_reserve(&state, &request, input_amount).await?;
+            match validate_target(&state, &request, input_amount, deposit).await? {
+                DepositTarget::Strategy { strategy, position } => {
+                    let mut intent = Intent::new_evm_to_sui(
+                        id,
+                        request.source_address,
+                        request.dest_address,
+                        request.evm_chain,
+                        request.input_token,
+                        request.input_amount,
+                        strategy,
+                    );
+                    intent.position_object_id = position.map(|p| p.object_id().to_string());
+                    intent
+                }
+                DepositTarget::Split(_) => Intent::new_evm_to_sui_split(
+                    id,
+                    request.source_address,
+                    request.dest_address,
+                    request.evm_chain,
+                    request.input_token,
+                    request.input_amount,
+                    request.allocations.unwrap_or_default(),
+                ),
+            }
+        }
+        ValidIntent::SuiToEvm { .. } => {
+            let mut intent = Intent::new_sui_to_evm(
+                id,
+                request.source_address,
+                request.dest_address,
+                request.evm_chain,
+                request.input_token,
+                request.input_amount,
+            );
+            intent.output_token = request.output_token;
+            intent.max_slippage_bps = request.max_slippage_bps;
+            intent
+        }
+        ValidIntent::SuiWithdraw { .. } => return Err(withdraw_elsewhere()),
+    };
+    intent.priority = priority;
+
+    tracing::info!(
+        intent_id = %intent.id,
+        direction = ?intent.direction,
+        "New intent created"
+    );
+
+    state.upsert_intent(intent.clone()).await?;
+
+    Ok(ApiSuccessResponse::new(IntentResponse::from(intent))
+        .with_code(StatusCode::CREATED)
+        .with_message("Intent created"))
+}
+
+/// 422 listing every field `CreateIntentRequest::validate` rejected
+fn invalid_fields(fields: Vec<FieldError>) -> ApiErrorResponse {
+    ApiErrorResponse::new(format!("{} invalid field(s)", fields.len()))
+        .with_code(StatusCode::UNPROCESSABLE_ENTITY)
+        .with_fields(fields)
+}
+
+/// Withdraw intents have their own endpoint, which builds the withdraw PTB
+fn withdraw_elsewhere() -> ApiErrorResponse {
+    ApiErrorResponse::new("sui_withdraw intents are created with POST /intents/withdraw")
+        .with_code(StatusCode::BAD_REQUEST)
+}
+
+/// A withdraw intent and the transaction its owner signs
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct WithdrawIntentResponse {
+    pub intent: IntentResponse,
+    /// Unsigned withdraw PTB, transferring the USDC to the owner
+    #[schema(value_type = Object)]
+    pub tx: ProgrammableTransactionBlock,
+}
+
+/// POST /intents/withdraw — exit a yield position back to Sui USDC, without
+/// bridging
+///
+/// Disabled strategies can still be withdrawn from; only the protocol has to
+/// be configured.
+#[utoipa::path(
+    post,
+    path = "/intents/withdraw",
+    tag = "intents",
+    request_body = WithdrawIntentRequest,
+    responses(
+        (status = 201, description = "Withdraw intent created", body = ApiSuccessResponse<WithdrawIntentResponse>),
+        (status = 400, description = "Invalid request", body = ApiErrorResponse),
+        (status = 422, description = "Strategy cannot be withdrawn to USDC", body = ApiErrorResponse),
+        (status = 503, description = "Strategy protocol not configured", body = ApiErrorResponse)
+    )
+)]
+pub async fn create_withdraw_intent(
+    State(state): State<AppState>,
+    ApiJson(request): ApiJson<WithdrawIntentRequest>,
+) -> ApiResponse<WithdrawIntentResponse> {
+    let sui_address = normalize_sui_address(&request.sui_address)
+        .map_err(|e| ApiErrorResponse::new(e.to_string()).with_code(StatusCode::BAD_REQUEST))?;
+    let amount: u64 = match request.amount.parse() {
+        Ok(0) => {
+            return Err(ApiErrorResponse::new("amount must be greater than zero")
+                .with_code(StatusCode::BAD_REQUEST));
+        }
+        Ok(amount) => amount,
+        Err(_) => {
+            return Err(
+                ApiErrorResponse::new("amount must be an integer in USDC's smallest unit")
+                    .with_code(StatusCode::BAD_REQUEST),
+            );
+        }
+    };
+
+    let strategy = request.strategy;
+    let position = match request.position_object_id.as_deref() {
+        Some(object_id) => Some(lookup_position(&state, strategy, object_id, &sui_address).await?),
+        None => None,
+    };
+    let from = match &position {
+        None => WithdrawFrom::Pool,
+        Some((obligation, Some(key))) => WithdrawFrom::Obligation { obligation, key },
+        Some((account_cap, None)) => WithdrawFrom::AccountCap(account_cap),
+    };
+
+    let network = state.config.network;
+    let tx = ProtocolFactory::build_withdraw_ptb(
+        strategy,
+        amount,
+        from,
+        &sui_address,
+        &state.config.sui.protocols,
+        network,
+    )
+    .map_err(|e| match e {
+        ProtocolError::NotConfigured(_) => ApiErrorResponse::new(format!(
+            "Strategy {} is temporarily unavailable",
+            strategy.name()
+        ))
+        .with_code(StatusCode::SERVICE_UNAVAILABLE)
+        .with_error(e.to_string()),
+        _ => ApiErrorResponse::new(format!(
+            "Strategy {} cannot be withdrawn to USDC",
+            strategy.name()
+        ))
+        .with_code(StatusCode::UNPROCESSABLE_ENTITY)
+        .with_error(e.to_string()),
+    })?;
+
+    let mut intent = Intent::new_sui_withdraw(
+        uuid::Uuid::new_v4().to_string(),
+        sui_address,
+        network.usdc_coin_type().to_string(),
+        amount.to_string(),
+        strategy,
+    );
+    intent.priority = request.priority;
+    intent.position_object_id = position.map(|(p, _)| p.object_id().to_string());
+
+    tracing::info!(
+        intent_id = %intent.id,
+        strategy = %strategy.name(),
+        "New withdraw intent created"
+    );
+
+    state.upsert_intent(intent.clone()).await?;
+
+    Ok(ApiSuccessResponse::new(WithdrawIntentResponse {
+        intent: IntentResponse::from(intent),
+        tx: tx.build(),
+    })
+    .with_code(StatusCode::CREATED)
+    .with_message("Withdraw intent created"))
+}
+
+/// Signing plan for an intent that hasn't been created yet
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct IntentPlanResponse {
+    pub direction: Direction,
+    /// Transactions in signing order
+    pub steps: Vec<UnsignedStep>,
+}
+
+/// POST /intents/plan — every unsigned transaction for an intent, upfront
+#[utoipa::path(
+    post,
+    path = "/intents/plan",
+    tag = "intents",
+    request_body = CreateIntentRequest,
+    responses(
+        (status = 200, description = "Ordered signing plan", body = ApiSuccessResponse<IntentPlanResponse>),
+        (status = 400, description = "Invalid request", body = ApiErrorResponse),
+        (status = 403, description = "Strategy disabled", body = ApiErrorResponse),
+        (status = 422, description = "Invalid fields (all listed in `fields`), amount outside limits, native input eating into the gas reserve, or strategy not plannable", body = ApiErrorResponse),
+        (status = 503, description = "Strategy protocol not configured", body = ApiErrorResponse)
+    )
+)]
+pub async fn plan_intent(
+    State(state): State<AppState>,
+    ApiJson(mut request): ApiJson<CreateIntentRequest>,
+) -> ApiResponse<IntentPlanResponse> {
+    let valid = request.validate().map_err(invalid_fields)?;
+
+    let ctx = PlanContext {
+        network: state.config.network,
+        protocols: &state.config.sui.protocols,
+    };
+    let steps = match valid {
+        ValidIntent::EvmToSui {
+            input_amount,
+            deposit,
+        } => {
+            check_gas_reserve(&state, &request, input_amount).await?;
+            let target = validate_target(&state, &request, input_amount, deposit).await?;
+            plan_evm_to_sui(&ctx, &request, input_amount, &target)?
+        }
+        ValidIntent::SuiToEvm { input_amount } => plan_sui_to_evm(&ctx, &request, input_amount)?,
+        ValidIntent::SuiWithdraw { .. } => return Err(withdraw_elsewhere()),
+    };
+
+    Ok(ApiSuccessResponse::new(IntentPlanResponse {
+        direction: request.direction,
+        steps,
+    }))
 }
 
-/// List intents (cached from blockchain)
+/// Largest page `GET /intents` returns
+pub const MAX_LIST_LIMIT: usize = 100;
+
+/// GET /intents — list stored intents, newest first
+#[utoipa::path(
+    get,
+    path = "/intents",
+    tag = "intents",
+    params(ListIntentsQuery),
+    responses(
+        (status = 200, description = "A page of intents, newest first", body = ApiSuccessResponse<IntentListResponse>),
+        (status = 400, description = "Unknown status filter", body = ApiErrorResponse)
+    )
+)]
 pub async fn list_intents(
+    State(state): State<AppState>,
     Query(params): Query<ListIntentsQuery>,
-) -> ApiResponse<Json<ApiSuccessResponse<Vec<IntentResponse>>>> {
-    // In production: query from database (cached)
-    // For now: mock data showing structure
-    
-    let mut intents = vec![
-        IntentResponse {
-            intent_id: "0x56241772c0fc5bf95d2e18ed2e8129f1a2ae4b592b21b3a66e67d09b851d20b6".to_string(),
-            user: "0xf800cb70f9f90d4f9858efbfe3ecdf0c1540d36c185807532892a98883e9c7fa".to_string(),
-            amount: "1000000000".to_string(),
-            min_apy: 720,
-            deadline: 1770326616245,
-            status: "open".to_string(),
-            target_protocol: "any".to_string(),
-            created_at: 1770287442164,
-            tx_digest: "BpJnnnSRkjUNqFR27rHexcCEf9Dr6uwhiUi2UCkAPhzj".to_string(),
-        },
-        IntentResponse {
-            intent_id: "0x6053a19f8240c8c6134e1955f443ee9fa207aa57f18258711b83a6611bbee01c".to_string(),
-            user: "0xf800cb70f9f90d4f9858efbfe3ecdf0c1540d36c185807532892a98883e9c7fa".to_string(),
-            amount: "1000".to_string(),
-            min_apy: 720,
-            deadline: 1770326616245,
-            status: "fulfilled".to_string(),
-            target_protocol: "scallop".to_string(),
-            created_at: 1770287538404,
-            tx_digest: "t6uFYkEcB1DFjNmodqRGVC2rUhuFc4cX5YaqdJwEA94".to_string(),
-        },
-    ];
-    
-    // Filter by status if provided
-    if let Some(status) = params.status {
+) -> ApiResponse<IntentListResponse> {
+    let status = match params.status.as_deref().map(str::parse::<IntentStatus>) {
+        Some(Err(e)) => {
+            return Err(ApiErrorResponse::new(e).with_code(StatusCode::BAD_REQUEST));
+        }
+        Some(Ok(status)) => Some(status),
+        None => None,
+    };
+
+    let mut intents = state.list_intents().await;
+
+    if !params.include_archived {
+        intents.retain(|i| !i.archived);
+    }
+
+    // Filter by status / direction if provided
+    if let Some(status) = status {
         intents.retain(|i| i.status == status);
     }
-    
-    // Apply limit
-    let limit = params.limit.unwrap_or(20);
-    intents.truncate(limit);
-    
-    Ok(ApiSuccessResponse::new(intents))
+    if let Some(direction) = params.direction {
+        intents.retain(|i| i.direction == direction);
+    }
+
+    // Ids break ties, so intents created in the same second page stably
+    intents.sort_by(|a, b| {
+        b.created_at
+            .cmp(&a.created_at)
+            .then_with(|| a.id.cmp(&b.id))
+    });
+
+    // Count before paging, so clients know how much is left
+    let total = intents.len();
+    let offset = params.offset.unwrap_or(0);
+    let limit = params.limit.unwrap_or(20).min(MAX_LIST_LIMIT);
+    let page: Vec<_> = intents
+        .into_iter()
+        .skip(offset)
+        .take(limit)
+        .map(IntentResponse::from)
+        .collect();
+    let end = offset.saturating_add(page.len());
+    let has_more = end < total;
+
+    Ok(ApiSuccessResponse::new(IntentListResponse {
+        intents: page,
+        total,
+        has_more,
+        next_offset: has_more.then_some(end),
+    }))
 }
 
-/// Get single intent by ID
+/// GET /intents/:id — get single intent by ID
+#[utoipa::path(
+    get,
+    path = "/intents/{id}",
+    tag = "intents",
+    params(("id" = String, Path, description = "Intent ID")),
+    responses(
+        (status = 200, description = "Intent found", body = ApiSuccessResponse<IntentResponse>),
+        (status = 404, description = "Intent not found", body = ApiErrorResponse)
+    )
+)]
 pub async fn get_intent(
-    axum::extract::Path(intent_id): axum::extract::Path<String>,
-) -> ApiResponse<Json<ApiSuccessResponse<IntentResponse>>> {
-    // Mock: in production query from DB
-    let intent = IntentResponse {
-        intent_id: intent_id.clone(),
-        user: "0xf800cb70f9f90d4f9858efbfe3ecdf0c1540d36c185807532892a98883e9c7fa".to_string(),
-        amount: "1000000000".to_string(),
-        min_apy: 720,
-        deadline: 1770326616245,
-        status: "open".to_string(),
-        target_protocol: "any".to_string(),
-        created_at: 1770287442164,
-        tx_digest: "BpJnnnSRkjUNqFR27rHexcCEf9Dr6uwhiUi2UCkAPhzj".to_string(),
+    State(state): State<AppState>,
+    Path(intent_id): Path<String>,
+) -> ApiResponse<IntentResponse> {
+    let intent_id = Intent::canonical_id(&intent_id);
+    state
+        .get_intent(&intent_id)
+        .await
+        .map(|intent| ApiSuccessResponse::new(IntentResponse::from(intent)))
+        .ok_or_else(|| {
+            ApiErrorResponse::new(format!("Intent not found: {}", intent_id))
+                .with_code(StatusCode::NOT_FOUND)
+        })
+}
+
+/// Where an intent stands, without the rest of its record
+#[derive(Debug, Serialize, ToSchema)]
+pub struct IntentStatusResponse {
+    pub id: String,
+    pub status: IntentStatus,
+    pub error_message: Option<String>,
+    pub updated_at: i64,
+}
+
+impl From<Intent> for IntentStatusResponse {
+    fn from(intent: Intent) -> Self {
+        Self {
+            id: intent.id,
+            status: intent.status,
+            error_message: intent.error_message,
+            updated_at: intent.updated_at,
+        }
+    }
+}
+
+/// GET /intents/:id/status — current status of a single intent
+#[utoipa::path(
+    get,
+    path = "/intents/{id}/status",
+    tag = "intents",
+    params(("id" = String, Path, description = "Intent ID")),
+    responses(
+        (status = 200, description = "Intent status", body = ApiSuccessResponse<IntentStatusResponse>),
+        (status = 404, description = "Intent not found", body = ApiErrorResponse)
+    )
+)]
+pub async fn get_intent_status(
+    State(state): State<AppState>,
+    Path(intent_id): Path<String>,
+) -> ApiResponse<IntentStatusResponse> {
+    let intent_id = Intent::canonical_id(&intent_id);
+    state
+        .get_intent(&intent_id)
+        .await
+        .map(|intent| ApiSuccessResponse::new(IntentStatusResponse::from(intent)))
+        .ok_or_else(|| {
+            ApiErrorResponse::new(format!("Intent not found: {}", intent_id))
+                .with_code(StatusCode::NOT_FOUND)
+        })
+}
+
+/// Most ids one batch status request may ask for
+pub const MAX_BATCH_IDS: usize = 100;
+
+/// Intent ids to look up in one request
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct BatchStatusRequest {
+    pub ids: Vec<String>,
+}
+
+/// Intents found, keyed by the id as requested, and the ids that weren't
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BatchStatusResponse {
+    pub intents: BTreeMap<String, IntentStatusResponse>,
+    pub not_found: Vec<String>,
+}
+
+/// POST /intents/status/batch — look up several intents at once
+#[utoipa::path(
+    post,
+    path = "/intents/status/batch",
+    tag = "intents",
+    request_body = BatchStatusRequest,
+    responses(
+        (status = 200, description = "Intents found and ids not found", body = ApiSuccessResponse<BatchStatusResponse>),
+        (status = 400, description = "Too many ids", body = ApiErrorResponse)
+    )
+)]
+pub async fn get_intent_status_batch(
+    State(state): State<AppState>,
+    ApiJson(request): ApiJson<BatchStatusRequest>,
+) -> ApiResponse<BatchStatusResponse> {
+    if request.ids.len() > MAX_BATCH_IDS {
+        return Err(ApiErrorResponse::new(format!(
+            "At most {} ids per request, got {}",
+            MAX_BATCH_IDS,
+            request.ids.len()
+        ))
+        .with_code(StatusCode::BAD_REQUEST));
+    }
+
+    // Answer under the id the caller sent, whatever its on-chain spelling
+    let requested: BTreeMap<String, &str> = request
+        .ids
+        .iter()
+        .map(|id| (Intent::canonical_id(id), id.as_str()))
+        .collect();
+    let (found, missing) = state
+        .get_intents(requested.keys().map(String::as_str))
+        .await;
+
+    Ok(ApiSuccessResponse::new(BatchStatusResponse {
+        intents: found
+            .into_iter()
+            .map(|intent| (requested[&intent.id].to_string(), intent.into()))
+            .collect(),
+        not_found: missing
+            .into_iter()
+            .map(|id| requested[id].to_string())
+            .collect(),
+    }))
+}
+
+/// POST /intents/:id/archive — hide a finished intent from default listings.
+/// Requires `Authorization: Bearer <ADMIN_API_KEY>`.
+#[utoipa::path(
+    post,
+    path = "/intents/{id}/archive",
+    tag = "intents",
+    params(("id" = String, Path, description = "Intent ID")),
+    responses(
+        (status = 200, description = "Intent archived", body = ApiSuccessResponse<IntentResponse>),
+        (status = 401, description = "Invalid or missing admin key", body = ApiErrorResponse),
+        (status = 403, description = "Admin API is disabled", body = ApiErrorResponse),
+        (status = 404, description = "Intent not found", body = ApiErrorResponse),
+        (status = 409, description = "Intent is still in progress", body = ApiErrorResponse)
+    )
+)]
+pub async fn archive_intent(
+    State(state): State<AppState>,
+    Path(intent_id): Path<String>,
+) -> ApiResponse<IntentResponse> {
+    let intent_id = Intent::canonical_id(&intent_id);
+    match state.archive_intent(&intent_id).await? {
+        Some(Ok(intent)) => {
+            Ok(ApiSuccessResponse::new(IntentResponse::from(intent))
+                .with_message("Intent archived"))
+        }
+        Some(Err(status)) => Err(ApiErrorResponse::new(format!(
+            "Cannot archive intent in status {}",
+            status.as_str()
+        ))
+        .with_code(StatusCode::CONFLICT)),
+        None => Err(
+            ApiErrorResponse::new(format!("Intent not found: {}", intent_id))
+                .with_code(StatusCode::NOT_FOUND),
+        ),
+    }
+}
+
+/// Executed deposit transaction reported by the client
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct RecordDepositRequest {
+    /// Digest of the deposit PTB signed by the destination address
+    pub tx_digest: String,
+}
+
+/// POST /intents/:id/deposit — record the executed deposit PTB and the
+/// position it opened
+#[utoipa::path(
+    post,
+    path = "/intents/{id}/deposit",
+    tag = "intents",
+    params(("id" = String, Path, description = "Intent ID")),
+    request_body = RecordDepositRequest,
+    responses(
+        (status = 200, description = "Deposit recorded", body = ApiSuccessResponse<IntentResponse>),
+        (status = 404, description = "Intent not found", body = ApiErrorResponse),
+        (status = 409, description = "Intent has no single-strategy deposit", body = ApiErrorResponse),
+        (status = 422, description = "Transaction failed or was not sent by the destination address", body = ApiErrorResponse)
+    )
+)]
+pub async fn record_deposit(
+    State(state): State<AppState>,
+    Path(intent_id): Path<String>,
+    ApiJson(request): ApiJson<RecordDepositRequest>,
+) -> ApiResponse<IntentResponse> {
+    let intent_id = Intent::canonical_id(&intent_id);
+    let intent = state.get_intent(&intent_id).await.ok_or_else(|| {
+        ApiErrorResponse::new(format!("Intent not found: {}", intent_id))
+            .with_code(StatusCode::NOT_FOUND)
+    })?;
+    let strategy = match (intent.direction, intent.strategy) {
+        (Direction::EvmToSui, Some(strategy)) => strategy,
+        _ => {
+            return Err(
+                ApiErrorResponse::new("Intent has no single-strategy deposit to record")
+                    .with_code(StatusCode::CONFLICT),
+            )
+        }
+    };
+
+    let tx = state
+        .sui_client()
+        .get_transaction(&request.tx_digest)
+        .await?;
+    let sent_by_dest = match (
+        tx.sender().map(normalize_sui_address),
+        normalize_sui_address(&intent.dest_address),
+    ) {
+        (Some(Ok(sender)), Ok(dest)) => sender == dest,
+        _ => false,
+    };
+    if !tx.succeeded() || !sent_by_dest {
+        return Err(ApiErrorResponse::new(
+            "tx_digest is not a successful transaction of the destination address",
+        )
+        .with_code(StatusCode::UNPROCESSABLE_ENTITY));
+    }
+
+    let created = ProtocolFactory::created_position_id(strategy, &tx, &state.config.sui.protocols);
+    let intent = state
+        .update_intent(&intent_id, |intent| {
+            if intent.position_object_id.is_none() {
+                intent.position_object_id = created;
+            }
+            intent.deposit_tx_hash = Some(tx.digest.clone());
+            intent.dest_tx_hash = Some(tx.digest.clone());
+            intent.clone()
+        })
+        .await?
+        .ok_or_else(|| {
+            ApiErrorResponse::new(format!("Intent not found: {}", intent_id))
+                .with_code(StatusCode::NOT_FOUND)
+        })?;
+    Ok(ApiSuccessResponse::new(IntentResponse::from(intent)).with_message("Deposit recorded"))
+}
+
+/// A stage of the CCTP flow, in execution order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BridgeStep {
+    /// `depositForBurn` on the source chain
+    Burn,
+    /// Circle signing the burn message
+    Attestation,
+    /// `receiveMessage` on the destination chain
+    Mint,
+}
+
+/// Progress of a single bridge step
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum StepState {
+    Pending,
+    InProgress,
+    Done,
+    Failed,
+}
+
+/// One entry of the bridge timeline
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+pub struct BridgeStepStatus {
+    pub name: BridgeStep,
+    pub state: StepState,
+    pub tx_hash: Option<String>,
+}
+
+/// Timeline for the burn → attestation → mint flow, derived from the intent's
+/// status, its recorded tx hashes and the (possibly live) attestation status
+fn bridge_steps(intent: &Intent, attestation: Option<AttestationStatus>) -> Vec<BridgeStepStatus> {
+    use StepState::*;
+
+    // Withdraw-only intents have no bridge timeline
+    if !intent.direction.is_bridged() {
+        return Vec::new();
+    }
+
+    let burn_done = intent.bridge_tx_hash.is_some()
+        || matches!(
+            intent.status,
+            IntentStatus::Bridging
+                | IntentStatus::BridgeCompleted
+                | IntentStatus::Deposited
+                | IntentStatus::Completed
+        );
+    let attested = attestation == Some(AttestationStatus::Complete);
+    let minted = matches!(
+        intent.status,
+        IntentStatus::BridgeCompleted | IntentStatus::Deposited | IntentStatus::Completed
+    );
+
+    let mut states = match (burn_done, attested, minted) {
+        (_, _, true) => [Done, Done, Done],
+        (true, true, false) => [Done, Done, InProgress],
+        (true, false, false) => [Done, InProgress, Pending],
+        (false, _, false) if intent.status == IntentStatus::SwapCompleted => {
+            [InProgress, Pending, Pending]
+        }
+        (false, _, false) => [Pending, Pending, Pending],
+    };
+
+    // A failed intent stopped at its first unfinished step; cancelled ones
+    // simply never started the rest
+    if intent.status == IntentStatus::Failed {
+        if let Some(state) = states.iter_mut().find(|s| **s != Done) {
+            *state = Failed;
+        }
+        for state in states.iter_mut().skip_while(|s| **s != Failed).skip(1) {
+            *state = Pending;
+        }
+    } else if intent.status == IntentStatus::Cancelled {
+        for state in states.iter_mut().filter(|s| **s == InProgress) {
+            *state = Pending;
+        }
+    }
+
+    let [burn, attestation, mint] = states;
+    vec![
+        BridgeStepStatus {
+            name: BridgeStep::Burn,
+            state: burn,
+            tx_hash: intent.bridge_tx_hash.clone(),
+        },
+        BridgeStepStatus {
+            name: BridgeStep::Attestation,
+            state: attestation,
+            tx_hash: None,
+        },
+        BridgeStepStatus {
+            name: BridgeStep::Mint,
+            state: mint,
+            tx_hash: intent.mint_tx_hash.clone(),
+        },
+    ]
+}
+
+/// Bridge progress for an intent
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct BridgeStatusResponse {
+    pub intent_id: String,
+    pub status: IntentStatus,
+    pub bridge_tx_hash: Option<String>,
+    pub bridge_nonce: Option<String>,
+    /// Unix time after which an unfinished bridge is failed
+    pub bridge_deadline: Option<i64>,
+    /// Why an unattested bridge was failed
+    pub bridge_failure: Option<BridgeFailure>,
+    /// CCTP attestation progress, if the burn has been submitted
+    #[schema(value_type = Option<String>, example = "complete")]
+    pub attestation: Option<AttestationStatus>,
+    /// Li.Fi transfer status, for a bridge routed through Li.Fi (live only)
+    pub lifi: Option<LiFiTransfer>,
+    /// Whether `attestation` or `lifi` comes from a live lookup
+    pub live: bool,
+    /// Burn / attestation / mint timeline
+    pub steps: Vec<BridgeStepStatus>,
+}
+
+impl From<&Intent> for BridgeStatusResponse {
+    fn from(intent: &Intent) -> Self {
+        let attestation = match intent.status {
+            IntentStatus::Bridging => Some(AttestationStatus::PendingConfirmations),
+            IntentStatus::BridgeCompleted | IntentStatus::Deposited | IntentStatus::Completed => {
+                Some(AttestationStatus::Complete)
+            }
+            _ => None,
+        };
+
+        Self {
+            intent_id: intent.id.clone(),
+            status: intent.status,
+            bridge_tx_hash: intent.bridge_tx_hash.clone(),
+            bridge_nonce: intent.bridge_nonce.clone(),
+            bridge_deadline: intent.bridge_deadline,
+            bridge_failure: intent.bridge_failure,
+            attestation,
+            lifi: None,
+            live: false,
+            steps: bridge_steps(intent, attestation),
+        }
+    }
+}
+
+/// Query parameters for bridge status
+#[derive(Deserialize, IntoParams)]
+pub struct BridgeStatusQuery {
+    /// Query Circle (CCTP) or Li.Fi instead of returning the cached status
+    #[serde(default)]
+    pub live: bool,
+}
+
+/// GET /intents/:id/events — live status transitions (server-sent events).
+/// Each event's id is the transition's per-intent sequence number; a client
+/// reconnecting with `Last-Event-ID` first gets the transitions it missed.
+#[utoipa::path(
+    get,
+    path = "/intents/{id}/events",
+    tag = "intents",
+    params(
+        ("id" = String, Path, description = "Intent ID"),
+        ("Last-Event-ID" = Option<u64>, Header, description = "Last sequence number received")
+    ),
+    responses(
+        (status = 200, description = "Stream of `status` events", content_type = "text/event-stream", body = StatusTransition),
+        (status = 404, description = "Intent not found", body = ApiErrorResponse)
+    )
+)]
+pub async fn stream_intent_events(
+    State(state): State<AppState>,
+    Path(intent_id): Path<String>,
+    headers: HeaderMap,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, ApiErrorResponse> {
+    let intent_id = Intent::canonical_id(&intent_id);
+    if state.get_intent(&intent_id).await.is_none() {
+        return Err(
+            ApiErrorResponse::new(format!("Intent not found: {}", intent_id))
+                .with_code(StatusCode::NOT_FOUND),
+        );
+    }
+
+    let last_seq = headers
+        .get("last-event-id")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.trim().parse::<u64>().ok());
+
+    // Snapshot and subscribe under one lock so nothing falls in between
+    let (backlog, rx) = {
+        let mut log = state.transitions.write().await;
+        (log.since(&intent_id, last_seq), log.subscribe(&intent_id))
     };
-    
-    Ok(ApiSuccessResponse::new(intent))
+    let replayed_up_to = backlog.last().map(|t| t.seq).or(last_seq).unwrap_or(0);
+
+    let live = stream::unfold(rx, |mut rx| async move {
+        match rx.recv().await {
+            Ok(transition) => Some((transition, rx)),
+            // A lagging client ends its stream and resumes via Last-Event-ID
+            Err(RecvError::Lagged(_)) | Err(RecvError::Closed) => None,
+        }
+    })
+    .filter(move |t| std::future::ready(t.seq > replayed_up_to));
+
+    let events = stream::iter(backlog).chain(live).map(|t| {
+        Ok(Event::default()
+            .id(t.seq.to_string())
+            .event("status")
+            .data(serde_json::to_string(&t).unwrap_or_default()))
+    });
+
+    Ok(Sse::new(events).keep_alive(KeepAlive::default()))
+}
+
+/// A receipt with its signature over [`IntentReceipt::canonical_bytes`]
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct SignedReceipt {
+    pub receipt: IntentReceipt,
+    pub scheme: ReceiptScheme,
+    /// Hex ed25519 verifying key; omitted for HMAC
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_key: Option<String>,
+    /// Hex signature
+    pub signature: String,
+}
+
+/// GET /intents/:id/receipt — signed completion receipt of a finished intent
+#[utoipa::path(
+    get,
+    path = "/intents/{id}/receipt",
+    tag = "intents",
+    params(("id" = String, Path, description = "Intent ID")),
+    responses(
+        (status = 200, description = "Signed receipt", body = ApiSuccessResponse<SignedReceipt>),
+        (status = 404, description = "Intent not found", body = ApiErrorResponse),
+        (status = 409, description = "Intent is still in progress", body = ApiErrorResponse),
+        (status = 503, description = "Receipt signing is not configured", body = ApiErrorResponse)
+    )
+)]
+pub async fn get_intent_receipt(
+    State(state): State<AppState>,
+    Path(intent_id): Path<String>,
+) -> ApiResponse<SignedReceipt> {
+    let intent_id = Intent::canonical_id(&intent_id);
+    let intent = state.get_intent(&intent_id).await.ok_or_else(|| {
+        ApiErrorResponse::new(format!("Intent not found: {}", intent_id))
+            .with_code(StatusCode::NOT_FOUND)
+    })?;
+    if !intent.status.is_terminal() {
+        return Err(ApiErrorResponse::new(format!(
+            "No receipt for intent in status {}",
+            intent.status.as_str()
+        ))
+        .with_code(StatusCode::CONFLICT));
+    }
+    let signer = state.config.receipts.signer.as_ref().ok_or_else(|| {
+        ApiErrorResponse::new("Receipt signing is not configured")
+            .with_code(StatusCode::SERVICE_UNAVAILABLE)
+    })?;
+
+    let receipt = IntentReceipt::new(&intent);
+    let signature = signer.sign(&receipt.canonical_bytes());
+
+    Ok(ApiSuccessResponse::new(SignedReceipt {
+        receipt,
+        scheme: signer.scheme(),
+        public_key: signer.public_key(),
+        signature,
+    }))
+}
+
+/// GET /intents/:id/bridge — bridge status, optionally refreshed from Circle
+/// or Li.Fi
+#[utoipa::path(
+    get,
+    path = "/intents/{id}/bridge",
+    tag = "intents",
+    params(("id" = String, Path, description = "Intent ID"), BridgeStatusQuery),
+    responses(
+        (status = 200, description = "Bridge status", body = ApiSuccessResponse<BridgeStatusResponse>),
+        (status = 404, description = "Intent not found", body = ApiErrorResponse)
+    )
+)]
+pub async fn get_bridge_status(
+    State(state): State<AppState>,
+    Path(intent_id): Path<String>,
+    Query(params): Query<BridgeStatusQuery>,
+) -> ApiResponse<BridgeStatusResponse> {
+    let intent_id = Intent::canonical_id(&intent_id);
+    let intent = state.get_intent(&intent_id).await.ok_or_else(|| {
+        ApiErrorResponse::new(format!("Intent not found: {}", intent_id))
+            .with_code(StatusCode::NOT_FOUND)
+    })?;
+
+    let mut response = BridgeStatusResponse::from(&intent);
+
+    // Only in-flight bridges have anything to look up. CCTP burns record a
+    // nonce; a bridge transaction without one went through Li.Fi.
+    if params.live && intent.status == IntentStatus::Bridging {
+        if let Some(nonce) = intent.bridge_nonce.as_deref() {
+            let source_domain = match intent.direction {
+                Direction::EvmToSui => intent.evm_chain.and_then(|c| c.cctp_domain()),
+                Direction::SuiToEvm => Some(state.config.network.cctp_domain()),
+                Direction::SuiWithdraw => None,
+            };
+
+            if let Some(source_domain) = source_domain {
+                let client = CctpClient::new(&state.config.bridge.cctp_api_url)
+                    .with_timeouts(state.config.bridge.http_timeouts);
+                match client.get_attestation(source_domain, nonce).await {
+                    Ok(message) => {
+                        response.attestation = message.map(|m| m.status);
+                        response.live = true;
+                        response.steps = bridge_steps(&intent, response.attestation);
+                    }
+                    Err(e) => {
+                        tracing::warn!(intent_id = %intent.id, error = %e, "Live attestation lookup failed");
+                    }
+                }
+            }
+        } else if let Some(tx_hash) = intent.bridge_tx_hash.as_deref() {
+            let base_url = state
+                .config
+                .bridge
+                .lifi_api_url
+                .as_deref()
+                .unwrap_or(LIFI_API_URL);
+            let client = LiFiClient::new(base_url).with_timeouts(state.config.bridge.http_timeouts);
+            match client.get_status(tx_hash).await {
+                Ok(transfer) => {
+                    response.lifi = Some(transfer);
+                    response.live = true;
+                }
+                Err(e) => {
+                    tracing::warn!(intent_id = %intent.id, error = %e, "Live Li.Fi status lookup failed");
+                }
+            }
+        }
+    }
+
+    Ok(ApiSuccessResponse::new(response))
+}
+
+/// Intent counts over some time window
+#[derive(Debug, Serialize, ToSchema)]
+pub struct IntentCounts {
+    pub total: u64,
+    /// Count per `IntentStatus` (every status present, zero if none)
+    pub by_status: BTreeMap<String, u64>,
+    /// Count per `Direction`
+    pub by_direction: BTreeMap<String, u64>,
+}
+
+impl IntentCounts {
+    /// Fold intents created at or after `since` (all of them when `None`)
+    fn from_intents(intents: &[Intent], since: Option<i64>) -> Self {
+        let init = Self {
+            total: 0,
+            by_status: IntentStatus::ALL
+                .iter()
+                .map(|s| (s.as_str().to_string(), 0))
+                .collect(),
+            by_direction: Direction::ALL
+                .iter()
+                .map(|d| (d.as_str().to_string(), 0))
+                .collect(),
+        };
+
+        intents
+            .iter()
+            .filter(|i| since.is_none_or(|since| i.created_at >= since))
+            .fold(init, |mut counts, intent| {
+                counts.total += 1;
+                *counts
+                    .by_status
+                    .entry(intent.status.as_str().to_string())
+                    .or_default() += 1;
+                *counts
+                    .by_direction
+                    .entry(intent.direction.as_str().to_string())
+                    .or_default() += 1;
+                counts
+            })
+    }
 }
 
 /// Intent stats
-#[derive(Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct IntentStats {
-    pub total_intents: u64,
-    pub open_intents: u64,
-    pub fulfilled_intents: u64,
-    pub total_volume_sui: String,
-    pub avg_apy: f64,
-}
-
-pub async fn get_stats() -> ApiResponse<Json<ApiSuccessResponse<IntentStats>>> {
-    let stats = IntentStats {
-        total_intents: 15,
-        open_intents: 3,
-        fulfilled_intents: 12,
-        total_volume_sui: "45.5".to_string(),
-        avg_apy: 8.25,
-    };
-    
-    Ok(ApiSuccessResponse::new(stats))
+    pub all_time: IntentCounts,
+    pub last_24h: IntentCounts,
+}
+
+impl IntentStats {
+    fn compute(intents: &[Intent], now: i64) -> Self {
+        Self {
+            all_time: IntentCounts::from_intents(intents, None),
+            last_24h: IntentCounts::from_intents(intents, Some(now - 24 * 60 * 60)),
+        }
+    }
+}
+
+/// GET /intents/stats — intent counts by status and direction
+#[utoipa::path(
+    get,
+    path = "/intents/stats",
+    tag = "intents",
+    responses((status = 200, description = "Aggregate intent stats", body = ApiSuccessResponse<IntentStats>))
+)]
+pub async fn get_stats(State(state): State<AppState>) -> ApiResponse<IntentStats> {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or_default();
+
+    let intents = state.list_intents().await;
+
+    Ok(ApiSuccessResponse::new(IntentStats::compute(&intents, now)))
 }
 
 /// Solver bids for an intent
-#[derive(Serialize)]
+#[derive(Serialize, ToSchema)]
 pub struct BidResponse {
     pub solver: String,
     pub protocol: String,
@@ -123,9 +1317,14 @@ pub struct BidResponse {
     pub timestamp: u64,
 }
 
-pub async fn get_intent_bids(
-    axum::extract::Path(_intent_id): axum::extract::Path<String>,
-) -> ApiResponse<Json<ApiSuccessResponse<Vec<BidResponse>>>> {
+#[utoipa::path(
+    get,
+    path = "/intents/{id}/bids",
+    tag = "intents",
+    params(("id" = String, Path, description = "Intent ID")),
+    responses((status = 200, description = "Solver bids for the intent", body = ApiSuccessResponse<Vec<BidResponse>>))
+)]
+pub async fn get_intent_bids(Path(_intent_id): Path<String>) -> ApiResponse<Vec<BidResponse>> {
     // Mock bids
     let bids = vec![
         BidResponse {
@@ -141,6 +1340,1183 @@ pub async fn get_intent_bids(
             timestamp: 1770287451000,
         },
     ];
-    
+
     Ok(ApiSuccessResponse::new(bids))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::price::{AssetChain, StaticPriceOracle, NATIVE_TOKEN};
+    use crate::config::{Config, DepositLimits};
+    use naisu_core::{StrategyAllocation, SuiNetwork};
+    use naisu_sui::testing::configured_protocols;
+    use naisu_sui::ProtocolConfig;
+    use std::collections::HashMap;
+
+    fn evm_to_sui_request(strategy: YieldStrategy) -> CreateIntentRequest {
+        CreateIntentRequest {
+            direction: Direction::EvmToSui,
+            source_address: "0x1234567890123456789012345678901234567890".to_string(),
+            dest_address: "0x0000000000000000000000000000000000000000000000000000000000000001"
+                .to_string(),
+            evm_chain: EvmChain::BaseSepolia,
+            input_token: "0x036CbD53842c5426634e7929541eC2318f3dCF7e".to_string(),
+            input_amount: "1000000".to_string(),
+            strategy: Some(strategy),
+            allocations: None,
+            priority: None,
+            position_object_id: None,
+            output_token: None,
+            max_slippage_bps: None,
+        }
+    }
+
+    fn state_with_enabled(strategies: &[YieldStrategy]) -> AppState {
+        let mut config = Config::load(SuiNetwork::Testnet, &HashMap::new()).unwrap();
+        config.strategies.enabled_strategies = strategies.iter().copied().collect();
+        config.sui.protocols = configured_protocols();
+        AppState::with_config(config)
+    }
+
+    fn split_request(scallop: &str, navi: &str) -> CreateIntentRequest {
+        let mut request = evm_to_sui_request(YieldStrategy::ScallopUsdc);
+        request.strategy = None;
+        request.input_amount = "10000000".to_string();
+        request.allocations = Some(vec![
+            StrategyAllocation {
+                strategy: YieldStrategy::ScallopUsdc,
+                amount: scallop.to_string(),
+            },
+            StrategyAllocation {
+                strategy: YieldStrategy::NaviUsdc,
+                amount: navi.to_string(),
+            },
+        ]);
+        request
+    }
+
+    #[tokio::test]
+    async fn test_create_split_intent_records_allocations() {
+        let state = state_with_enabled(&[YieldStrategy::ScallopUsdc, YieldStrategy::NaviUsdc]);
+
+        let intent = create_intent(State(state), ApiJson(split_request("6000000", "4000000")))
+            .await
+            .unwrap()
+            .data;
+
+        assert_eq!(intent.strategy, None);
+        let split: Vec<_> = intent
+            .allocations
+            .iter()
+            .map(|a| (a.strategy, a.amount.as_str(), a.status))
+            .collect();
+        assert_eq!(
+            split,
+            [
+                (
+                    YieldStrategy::ScallopUsdc,
+                    "6000000",
+                    naisu_core::AllocationStatus::Pending
+                ),
+                (
+                    YieldStrategy::NaviUsdc,
+                    "4000000",
+                    naisu_core::AllocationStatus::Pending
+                ),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_split_intent_rejects_wrong_sum() {
+        let state = state_with_enabled(&[YieldStrategy::ScallopUsdc, YieldStrategy::NaviUsdc]);
+
+        let err = create_intent(
+            State(state.clone()),
+            ApiJson(split_request("6000000", "3999999")),
+        )
+        .await
+        .unwrap_err();
+
+        assert_eq!(err.code, 422);
+        assert!(state.list_intents().await.is_empty());
+    }
+
+    async fn sui_rpc_with_object(type_: &str, owner: serde_json::Value) -> wiremock::MockServer {
+        use wiremock::matchers::{body_partial_json, method};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let sui = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(body_partial_json(
+                serde_json::json!({ "method": "sui_getObject" }),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": { "data": {
+                    "objectId": format!("0x{:0>64}", "ca9"),
+                    "version": "12",
+                    "digest": "9WzSXdwbky8tNbH7juvyaui4QzMUYEjdCEKMrMgLhXHT",
+                    "type": type_,
+                    "owner": owner
+                } }
+            })))
+            .mount(&sui)
+            .await;
+        sui
+    }
+
+    #[tokio::test]
+    async fn test_create_intent_deposits_into_existing_position() {
+        let mut request = evm_to_sui_request(YieldStrategy::NaviUsdc);
+        request.position_object_id = Some("0xca9".to_string());
+        let sui = sui_rpc_with_object(
+            &format!("0x{:0>64}::account::AccountCap", "4a71"),
+            serde_json::json!({ "AddressOwner": request.dest_address }),
+        )
+        .await;
+        let mut config = Config::load(SuiNetwork::Testnet, &HashMap::new()).unwrap();
+        config.strategies.enabled_strategies = [YieldStrategy::NaviUsdc].into_iter().collect();
+        config.sui.protocols = configured_protocols();
+        config.sui.rpc_url = sui.uri();
+        let state = AppState::with_config(config);
+
+        let intent = create_intent(State(state.clone()), ApiJson(request.clone()))
+            .await
+            .unwrap()
+            .data;
+        assert_eq!(intent.position_object_id, Some(format!("0x{:0>64}", "ca9")));
+
+        // The same object is not a Scallop obligation
+        request.strategy = Some(YieldStrategy::ScallopUsdc);
+        let mut config = (*state.config).clone();
+        config.strategies.enabled_strategies = [YieldStrategy::ScallopUsdc].into_iter().collect();
+        let err = create_intent(State(AppState::with_config(config)), ApiJson(request))
+            .await
+            .unwrap_err();
+        assert_eq!(err.code, 422);
+    }
+
+    #[tokio::test]
+    async fn test_shared_obligation_requires_destination_to_hold_its_key() {
+        use wiremock::matchers::{body_partial_json, method};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let mut request = evm_to_sui_request(YieldStrategy::ScallopUsdc);
+        request.position_object_id = Some("0xca9".to_string());
+        let sui = sui_rpc_with_object(
+            &format!("0x{:0>64}::obligation::Obligation", "5ca1"),
+            serde_json::json!({ "Shared": { "initial_shared_version": 3 } }),
+        )
+        .await;
+        Mock::given(method("POST"))
+            .and(body_partial_json(
+                serde_json::json!({ "method": "suix_getOwnedObjects" }),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": { "data": [], "nextCursor": null, "hasNextPage": false }
+            })))
+            .mount(&sui)
+            .await;
+        let mut config = Config::load(SuiNetwork::Testnet, &HashMap::new()).unwrap();
+        config.strategies.enabled_strategies = [YieldStrategy::ScallopUsdc].into_iter().collect();
+        config.sui.protocols = configured_protocols();
+        config.sui.rpc_url = sui.uri();
+
+        let err = create_intent(State(AppState::with_config(config)), ApiJson(request))
+            .await
+            .unwrap_err();
+        assert_eq!(err.code, 422);
+    }
+
+    #[tokio::test]
+    async fn test_record_deposit_stores_opened_position() {
+        use wiremock::matchers::{body_partial_json, method};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let request = evm_to_sui_request(YieldStrategy::NaviUsdc);
+        let sui = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(body_partial_json(
+                serde_json::json!({ "method": "sui_getTransactionBlock" }),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": {
+                    "digest": "deposit",
+                    "transaction": { "data": { "sender": request.dest_address } },
+                    "effects": {
+                        "status": { "status": "success" },
+                        "gasUsed": { "computationCost": "1", "storageCost": "1" }
+                    },
+                    "objectChanges": [{
+                        "type": "created",
+                        "objectType": format!("0x{:0>64}::account::AccountCap", "4a71"),
+                        "objectId": "0xca9"
+                    }]
+                }
+            })))
+            .mount(&sui)
+            .await;
+        let mut config = Config::load(SuiNetwork::Testnet, &HashMap::new()).unwrap();
+        config.strategies.enabled_strategies = [YieldStrategy::NaviUsdc].into_iter().collect();
+        config.sui.protocols = configured_protocols();
+        config.sui.rpc_url = sui.uri();
+        let state = AppState::with_config(config);
+        let intent = create_intent(State(state.clone()), ApiJson(request))
+            .await
+            .unwrap()
+            .data;
+        assert_eq!(intent.position_object_id, None);
+
+        let recorded = record_deposit(
+            State(state),
+            Path(intent.id),
+            ApiJson(RecordDepositRequest {
+                tx_digest: "deposit".to_string(),
+            }),
+        )
+        .await
+        .unwrap()
+        .data;
+        assert_eq!(recorded.position_object_id.as_deref(), Some("0xca9"));
+    }
+
+    #[tokio::test]
+    async fn test_bridge_deadline_waits_for_the_burn() {
+        let state = state_with_enabled(&[YieldStrategy::ScallopUsdc]);
+        let intent = create_intent(
+            State(state),
+            ApiJson(evm_to_sui_request(YieldStrategy::ScallopUsdc)),
+        )
+        .await
+        .unwrap()
+        .data;
+        assert_eq!(intent.bridge_deadline, None);
+    }
+
+    #[tokio::test]
+    async fn test_create_withdraw_intent_skips_bridge() {
+        // Exiting is allowed even after the strategy is disabled
+        let state = state_with_enabled(&[]);
+        let request = WithdrawIntentRequest {
+            sui_address: "0x2".to_string(),
+            strategy: YieldStrategy::NaviUsdc,
+            amount: "1000000".to_string(),
+            position_object_id: None,
+            priority: None,
+        };
+
+        let response = create_withdraw_intent(State(state.clone()), ApiJson(request))
+            .await
+            .unwrap()
+            .data;
+
+        let intent = response.intent;
+        assert_eq!(intent.direction, Direction::SuiWithdraw);
+        assert_eq!(intent.dest_address, normalize_sui_address("0x2").unwrap());
+        assert_eq!(intent.input_token, state.config.network.usdc_coin_type());
+        assert_eq!(response.tx.commands.len(), 2);
+        assert!(state.get_intent(&intent.id).await.is_some());
+
+        let err = plan_intent(
+            State(state),
+            ApiJson(CreateIntentRequest {
+                direction: Direction::SuiWithdraw,
+                dest_address: "0x2".to_string(),
+                source_address: "0x2".to_string(),
+                ..evm_to_sui_request(YieldStrategy::NaviUsdc)
+            }),
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(err.code, 400);
+    }
+
+    #[tokio::test]
+    async fn test_withdraw_amount_is_validated_and_stored_parsed() {
+        let state = state_with_enabled(&[]);
+        let request = |amount: &str| WithdrawIntentRequest {
+            sui_address: "0x2".to_string(),
+            strategy: YieldStrategy::NaviUsdc,
+            amount: amount.to_string(),
+            position_object_id: None,
+            priority: None,
+        };
+
+        for amount in ["0", "000", "-5", "1.5", ""] {
+            let err = create_withdraw_intent(State(state.clone()), ApiJson(request(amount)))
+                .await
+                .unwrap_err();
+            assert_eq!(err.code, 400, "{}", amount);
+        }
+        assert!(state.list_intents().await.is_empty());
+
+        let intent = create_withdraw_intent(State(state), ApiJson(request("+0001000000")))
+            .await
+            .unwrap()
+            .data
+            .intent;
+        assert_eq!(intent.input_amount, "1000000");
+    }
+
+    #[tokio::test]
+    async fn test_withdraw_from_obligation_uses_senders_key() {
+        use wiremock::matchers::{body_partial_json, method};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let owner = normalize_sui_address("0x2").unwrap();
+        let sui = sui_rpc_with_object(
+            &format!("0x{:0>64}::obligation::Obligation", "5ca1"),
+            serde_json::json!({ "Shared": { "initial_shared_version": 3 } }),
+        )
+        .await;
+        Mock::given(method("POST"))
+            .and(body_partial_json(
+                serde_json::json!({ "method": "suix_getOwnedObjects" }),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": {
+                    "data": [{ "data": {
+                        "objectId": format!("0x{:0>64}", "6e1"),
+                        "version": "5",
+                        "digest": "9WzSXdwbky8tNbH7juvyaui4QzMUYEjdCEKMrMgLhXHT",
+                        "type": format!("0x{:0>64}::obligation::ObligationKey", "5ca1"),
+                        "owner": { "AddressOwner": owner },
+                        "content": { "fields": { "ownership": { "fields": {
+                            "of": format!("0x{:0>64}", "ca9")
+                        } } } }
+                    } }],
+                    "nextCursor": null,
+                    "hasNextPage": false
+                }
+            })))
+            .mount(&sui)
+            .await;
+        let mut config = Config::load(SuiNetwork::Testnet, &HashMap::new()).unwrap();
+        config.sui.protocols = configured_protocols();
+        config.sui.rpc_url = sui.uri();
+        let state = AppState::with_config(config);
+
+        let response = create_withdraw_intent(
+            State(state),
+            ApiJson(WithdrawIntentRequest {
+                sui_address: "0x2".to_string(),
+                strategy: YieldStrategy::ScallopUsdc,
+                amount: "1000000".to_string(),
+                position_object_id: Some("0xca9".to_string()),
+                priority: None,
+            }),
+        )
+        .await
+        .unwrap()
+        .data;
+
+        assert_eq!(response.intent.evm_chain, None);
+        assert_eq!(
+            response.intent.position_object_id,
+            Some(format!("0x{:0>64}", "ca9"))
+        );
+        let calls: Vec<_> = response
+            .tx
+            .commands
+            .iter()
+            .filter_map(|c| match c {
+                naisu_sui::PtbCommand::MoveCall(call) => Some(call.function.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(calls, ["withdraw_collateral"]);
+    }
+
+    #[tokio::test]
+    async fn test_create_intent_rejects_disabled_strategy() {
+        let state = state_with_enabled(&[YieldStrategy::ScallopUsdc]);
+
+        let err = create_intent(
+            State(state.clone()),
+            ApiJson(evm_to_sui_request(YieldStrategy::NaviUsdc)),
+        )
+        .await
+        .unwrap_err();
+
+        assert_eq!(err.code, 403);
+        assert!(state.list_intents().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_create_intent_accepts_enabled_strategy() {
+        let state = state_with_enabled(&[YieldStrategy::ScallopUsdc]);
+
+        let created = create_intent(
+            State(state.clone()),
+            ApiJson(evm_to_sui_request(YieldStrategy::ScallopUsdc)),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(created.code, 201);
+        assert!(state.get_intent(&created.data.id).await.is_some());
+    }
+
+    fn state_with_usdc_limits(min: u128, max: u128) -> AppState {
+        let mut config = Config::load(SuiNetwork::Testnet, &HashMap::new()).unwrap();
+        config.strategies.enabled_strategies = [YieldStrategy::ScallopUsdc].into_iter().collect();
+        config.sui.protocols = configured_protocols();
+        config.strategies.deposit_limits.insert(
+            YieldStrategy::ScallopUsdc,
+            DepositLimits {
+                min_deposit: Some(min),
+                max_deposit: Some(max),
+            },
+        );
+        AppState::with_config(config)
+    }
+
+    #[tokio::test]
+    async fn test_create_intent_rejects_deposit_below_min() {
+        let state = state_with_usdc_limits(1_000_000, 10_000_000_000);
+        let mut request = evm_to_sui_request(YieldStrategy::ScallopUsdc);
+        request.input_amount = "999999".to_string();
+
+        let err = create_intent(State(state.clone()), ApiJson(request))
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.code, 422);
+        assert!(err.message.contains("minimum deposit of 1000000"));
+        assert!(state.list_intents().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_create_intent_rejects_deposit_above_max() {
+        let state = state_with_usdc_limits(1_000_000, 10_000_000_000);
+        let mut request = evm_to_sui_request(YieldStrategy::ScallopUsdc);
+        request.input_amount = "10000000001".to_string();
+
+        let err = create_intent(State(state.clone()), ApiJson(request))
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.code, 422);
+        assert!(err.message.contains("maximum deposit of 10000000000"));
+        assert!(state.list_intents().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_deposit_limits_apply_to_weth_usdc_value() {
+        let chain = EvmChain::BaseSepolia;
+        let state = state_with_usdc_limits(1_000_000, 10_000_000_000).with_price_oracle(
+            StaticPriceOracle::new().with_price(AssetChain::Evm(chain), NATIVE_TOKEN, 2000.0),
+        );
+        let weth_request = |amount: &str| {
+            let mut request = evm_to_sui_request(YieldStrategy::ScallopUsdc);
+            request.evm_chain = chain;
+            request.input_token = chain.weth_address().to_string();
+            request.input_amount = amount.to_string();
+            request
+        };
+
+        // 1 WETH is 10^18 base units, far past the max, but worth 2000 USDC
+        let created = create_intent(
+            State(state.clone()),
+            ApiJson(weth_request("1000000000000000000")),
+        )
+        .await
+        .unwrap();
+        assert_eq!(created.code, 201);
+
+        // 0.0001 WETH is worth 0.2 USDC
+        let err = create_intent(
+            State(state.clone()),
+            ApiJson(weth_request("100000000000000")),
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(err.code, 422);
+        assert!(err.message.contains("worth 200000 USDC units"));
+    }
+
+    #[tokio::test]
+    async fn test_create_intent_normalizes_addresses() {
+        let state = state_with_enabled(&[YieldStrategy::ScallopUsdc]);
+
+        let mut ids = Vec::new();
+        for source in [
+            "0x5AAEB6053f3e94c9b9a09f33669435e7ef1beaed",
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+        ] {
+            let mut request = evm_to_sui_request(YieldStrategy::ScallopUsdc);
+            request.source_address = source.to_string();
+            request.dest_address = "0x2".to_string();
+            let created = create_intent(State(state.clone()), ApiJson(request))
+                .await
+                .unwrap();
+            ids.push(created.data.id);
+        }
+
+        let creator = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+        assert_eq!(state.list_intents_by_creator(creator).await.len(), 2);
+
+        let intent = state.get_intent(&ids[0]).await.unwrap();
+        assert_eq!(intent.source_address, creator);
+        assert_eq!(
+            intent.dest_address,
+            "0x0000000000000000000000000000000000000000000000000000000000000002"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_archived_intent_hidden_from_list_but_fetchable() {
+        let state = state_with_enabled(&[YieldStrategy::ScallopUsdc]);
+        let created = create_intent(
+            State(state.clone()),
+            ApiJson(evm_to_sui_request(YieldStrategy::ScallopUsdc)),
+        )
+        .await
+        .unwrap();
+        let id = created.data.id;
+
+        // In-flight intents can't be archived
+        let err = archive_intent(State(state.clone()), Path(id.clone()))
+            .await
+            .unwrap_err();
+        assert_eq!(err.code, 409);
+
+        state
+            .update_intent_status(&id, IntentStatus::Completed)
+            .await
+            .unwrap();
+        let archived = archive_intent(State(state.clone()), Path(id.clone()))
+            .await
+            .unwrap();
+        assert!(archived.data.archived);
+
+        let list = |include_archived| {
+            list_intents(
+                State(state.clone()),
+                Query(ListIntentsQuery {
+                    status: None,
+                    direction: None,
+                    limit: None,
+                    offset: None,
+                    include_archived,
+                }),
+            )
+        };
+        assert!(list(false).await.unwrap().data.intents.is_empty());
+        assert_eq!(list(true).await.unwrap().data.intents.len(), 1);
+
+        let fetched = get_intent(State(state), Path(id)).await.unwrap();
+        assert!(fetched.data.archived);
+    }
+
+    #[tokio::test]
+    async fn test_list_total_counts_beyond_the_page() {
+        let state = state_with_enabled(&[YieldStrategy::ScallopUsdc]);
+        for _ in 0..5 {
+            create_intent(
+                State(state.clone()),
+                ApiJson(evm_to_sui_request(YieldStrategy::ScallopUsdc)),
+            )
+            .await
+            .unwrap();
+        }
+
+        let page_of = |limit, offset| {
+            list_intents(
+                State(state.clone()),
+                Query(ListIntentsQuery {
+                    status: Some("pending".to_string()),
+                    direction: Some(Direction::EvmToSui),
+                    limit: Some(limit),
+                    offset,
+                    include_archived: false,
+                }),
+            )
+        };
+        let page = |offset| page_of(2, offset);
+
+        let first = page(None).await.unwrap().data;
+        assert_eq!(first.intents.len(), 2);
+        assert_eq!(first.total, 5);
+        assert!(first.has_more);
+        assert_eq!(first.next_offset, Some(2));
+
+        let last = page(Some(4)).await.unwrap().data;
+        assert_eq!(last.intents.len(), 1);
+        assert_eq!(last.total, 5);
+        assert!(!last.has_more);
+        assert_eq!(last.next_offset, None);
+
+        // Intents created in the same second still page without repeats
+        let mut seen = std::collections::HashSet::new();
+        for offset in [None, Some(2), Some(4)] {
+            for intent in page(offset).await.unwrap().data.intents {
+                assert!(seen.insert(intent.id), "intent listed twice");
+            }
+        }
+        assert_eq!(seen.len(), 5);
+    }
+
+    #[tokio::test]
+    async fn test_list_limit_is_capped() {
+        let state = state_with_enabled(&[YieldStrategy::ScallopUsdc]);
+        for _ in 0..(MAX_LIST_LIMIT + 1) {
+            create_intent(
+                State(state.clone()),
+                ApiJson(evm_to_sui_request(YieldStrategy::ScallopUsdc)),
+            )
+            .await
+            .unwrap();
+        }
+
+        let list = list_intents(
+            State(state),
+            Query(ListIntentsQuery {
+                status: None,
+                direction: None,
+                limit: Some(usize::MAX),
+                offset: None,
+                include_archived: false,
+            }),
+        )
+        .await
+        .unwrap()
+        .data;
+        assert_eq!(list.intents.len(), MAX_LIST_LIMIT);
+        assert_eq!(list.next_offset, Some(MAX_LIST_LIMIT));
+    }
+
+    #[tokio::test]
+    async fn test_batch_status_partitions_found_and_missing() {
+        let state = state_with_enabled(&[YieldStrategy::ScallopUsdc]);
+        let created = create_intent(
+            State(state.clone()),
+            ApiJson(evm_to_sui_request(YieldStrategy::ScallopUsdc)),
+        )
+        .await
+        .unwrap();
+        let id = created.data.id;
+
+        let batch = get_intent_status_batch(
+            State(state.clone()),
+            ApiJson(BatchStatusRequest {
+                ids: vec![id.clone(), "missing-1".to_string(), "missing-2".to_string()],
+            }),
+        )
+        .await
+        .unwrap()
+        .data;
+        assert_eq!(batch.intents.len(), 1);
+        assert_eq!(batch.intents[&id].status, IntentStatus::Pending);
+        assert_eq!(batch.not_found, ["missing-1", "missing-2"]);
+
+        // Each entry is what GET /intents/{id}/status answers
+        let single = get_intent_status(State(state.clone()), Path(id.clone()))
+            .await
+            .unwrap()
+            .data;
+        assert_eq!(
+            serde_json::to_value(&batch.intents[&id]).unwrap(),
+            serde_json::to_value(&single).unwrap()
+        );
+
+        let err = get_intent_status_batch(
+            State(state),
+            ApiJson(BatchStatusRequest {
+                ids: vec![id; MAX_BATCH_IDS + 1],
+            }),
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(err.code, 400);
+    }
+
+    #[test]
+    fn test_stats_counts_by_status_and_window() {
+        const NOW: i64 = 1_770_000_000;
+        let request = evm_to_sui_request(YieldStrategy::ScallopUsdc);
+
+        let intent = |id: &str, status: IntentStatus, age_secs: i64| {
+            let mut intent = Intent::new_evm_to_sui(
+                id.to_string(),
+                request.source_address.clone(),
+                request.dest_address.clone(),
+                EvmChain::BaseSepolia,
+                request.input_token.clone(),
+                request.input_amount.clone(),
+                YieldStrategy::ScallopUsdc,
+            );
+            intent.status = status;
+            intent.created_at = NOW - age_secs;
+            intent
+        };
+        let mut old_sui = Intent::new_sui_to_evm(
+            "4".to_string(),
+            request.dest_address.clone(),
+            request.source_address.clone(),
+            EvmChain::BaseSepolia,
+            request.input_token.clone(),
+            request.input_amount.clone(),
+        );
+        old_sui.created_at = NOW - 3 * 24 * 60 * 60;
+
+        let intents = vec![
+            intent("1", IntentStatus::Pending, 60),
+            intent("2", IntentStatus::Bridging, 3600),
+            intent("3", IntentStatus::Completed, 2 * 24 * 60 * 60),
+            old_sui,
+        ];
+
+        let stats = IntentStats::compute(&intents, NOW);
+
+        assert_eq!(stats.all_time.total, 4);
+        assert_eq!(stats.all_time.by_status["pending"], 2);
+        assert_eq!(stats.all_time.by_status["completed"], 1);
+        assert_eq!(stats.all_time.by_status["failed"], 0);
+        assert_eq!(stats.all_time.by_direction["sui_to_evm"], 1);
+
+        assert_eq!(stats.last_24h.total, 2);
+        assert_eq!(stats.last_24h.by_status["bridging"], 1);
+        assert_eq!(stats.last_24h.by_status["completed"], 0);
+        assert_eq!(stats.last_24h.by_direction["sui_to_evm"], 0);
+    }
+
+    #[tokio::test]
+    async fn test_live_bridge_status_reports_complete_attestation() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let circle = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v2/messages/6"))
+            .and(query_param("nonce", "7"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "messages": [{
+                    "message": "0x01",
+                    "attestation": "0x02",
+                    "status": "complete"
+                }]
+            })))
+            .mount(&circle)
+            .await;
+
+        let mut config = Config::load(SuiNetwork::Testnet, &HashMap::new()).unwrap();
+        config.bridge.cctp_api_url = circle.uri();
+        let state = AppState::with_config(config);
+
+        let request = evm_to_sui_request(YieldStrategy::ScallopUsdc);
+        let mut intent = Intent::new_evm_to_sui(
+            "intent-1".to_string(),
+            request.source_address,
+            request.dest_address,
+            EvmChain::BaseSepolia,
+            request.input_token,
+            request.input_amount,
+            YieldStrategy::ScallopUsdc,
+        );
+        intent.bridge_nonce = Some("7".to_string());
+        intent.set_status(IntentStatus::Bridging);
+        state.upsert_intent(intent).await.unwrap();
+
+        let cached = get_bridge_status(
+            State(state.clone()),
+            Path("intent-1".to_string()),
+            Query(BridgeStatusQuery { live: false }),
+        )
+        .await
+        .unwrap()
+        .data;
+        assert_eq!(
+            cached.attestation,
+            Some(AttestationStatus::PendingConfirmations)
+        );
+        assert!(!cached.live);
+
+        let live = get_bridge_status(
+            State(state),
+            Path("intent-1".to_string()),
+            Query(BridgeStatusQuery { live: true }),
+        )
+        .await
+        .unwrap()
+        .data;
+        assert_eq!(live.attestation, Some(AttestationStatus::Complete));
+        assert!(live.live);
+        assert_eq!(live.steps[1].state, StepState::Done);
+        assert_eq!(live.steps[2].state, StepState::InProgress);
+    }
+
+    #[tokio::test]
+    async fn test_live_bridge_status_asks_lifi_without_a_cctp_nonce() {
+        use crate::common::lifi::LiFiStatus;
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let lifi = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/status"))
+            .and(query_param("txHash", "0xburn"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "status": "DONE",
+                "substatus": "COMPLETED",
+                "sending": { "txHash": "0xburn" },
+                "receiving": { "txHash": "0xreceived" }
+            })))
+            .expect(1)
+            .mount(&lifi)
+            .await;
+
+        let mut config = Config::load(SuiNetwork::Testnet, &HashMap::new()).unwrap();
+        config.bridge.lifi_api_url = Some(lifi.uri());
+        let state = AppState::with_config(config);
+        state
+            .upsert_intent(bridging_intent(IntentStatus::Bridging))
+            .await
+            .unwrap();
+
+        let live = get_bridge_status(
+            State(state),
+            Path("intent-1".to_string()),
+            Query(BridgeStatusQuery { live: true }),
+        )
+        .await
+        .unwrap()
+        .data;
+        assert!(live.live);
+        let transfer = live.lifi.unwrap();
+        assert_eq!(transfer.status, LiFiStatus::Done);
+        assert_eq!(transfer.substatus.as_deref(), Some("COMPLETED"));
+        assert_eq!(transfer.receiving_tx_hash.as_deref(), Some("0xreceived"));
+        // The cached CCTP view is left alone
+        assert_eq!(
+            live.attestation,
+            Some(AttestationStatus::PendingConfirmations)
+        );
+    }
+
+    fn bridging_intent(status: IntentStatus) -> Intent {
+        let request = evm_to_sui_request(YieldStrategy::ScallopUsdc);
+        let mut intent = Intent::new_evm_to_sui(
+            "intent-1".to_string(),
+            request.source_address,
+            request.dest_address,
+            EvmChain::BaseSepolia,
+            request.input_token,
+            request.input_amount,
+            YieldStrategy::ScallopUsdc,
+        );
+        intent.bridge_tx_hash = Some("0xburn".to_string());
+        intent.set_status(status);
+        intent
+    }
+
+    #[test]
+    fn test_bridging_intent_shows_burn_done_attestation_in_progress() {
+        let response = BridgeStatusResponse::from(&bridging_intent(IntentStatus::Bridging));
+
+        let states: Vec<_> = response.steps.iter().map(|s| (s.name, s.state)).collect();
+        assert_eq!(
+            states,
+            [
+                (BridgeStep::Burn, StepState::Done),
+                (BridgeStep::Attestation, StepState::InProgress),
+                (BridgeStep::Mint, StepState::Pending),
+            ]
+        );
+        assert_eq!(response.steps[0].tx_hash.as_deref(), Some("0xburn"));
+    }
+
+    #[test]
+    fn test_mint_step_keeps_mint_hash_after_deposit() {
+        let mut intent = bridging_intent(IntentStatus::Completed);
+        intent.mint_tx_hash = Some("0xmint".to_string());
+        intent.deposit_tx_hash = Some("0xdeposit".to_string());
+        intent.dest_tx_hash = Some("0xdeposit".to_string());
+
+        let response = BridgeStatusResponse::from(&intent);
+        assert_eq!(response.steps[2].name, BridgeStep::Mint);
+        assert_eq!(response.steps[2].tx_hash.as_deref(), Some("0xmint"));
+    }
+
+    #[test]
+    fn test_failed_intent_marks_first_unfinished_step() {
+        let mut intent = bridging_intent(IntentStatus::Bridging);
+        intent.fail("attestation timed out".to_string());
+
+        let response = BridgeStatusResponse::from(&intent);
+        let states: Vec<_> = response.steps.iter().map(|s| s.state).collect();
+        assert_eq!(
+            states,
+            [StepState::Done, StepState::Failed, StepState::Pending]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_evm_to_sui_usdc_plan_orders_steps() {
+        use super::super::plan::PlanStepKind;
+
+        let mut config = Config::load(SuiNetwork::Testnet, &HashMap::new()).unwrap();
+        config.strategies.enabled_strategies = [YieldStrategy::ScallopUsdc].into_iter().collect();
+        config.sui.protocols.scallop_package = Some("0x5ca1".to_string());
+        config.sui.protocols.scallop_market = Some("0xa4e7".to_string());
+        let state = AppState::with_config(config);
+
+        let plan = plan_intent(
+            State(state.clone()),
+            ApiJson(evm_to_sui_request(YieldStrategy::ScallopUsdc)),
+        )
+        .await
+        .unwrap()
+        .data;
+
+        let steps: Vec<_> = plan.steps.iter().map(|s| (s.kind, s.deferred)).collect();
+        assert_eq!(
+            steps,
+            [
+                (PlanStepKind::Approval, false),
+                (PlanStepKind::Burn, false),
+                (PlanStepKind::Mint, true),
+                (PlanStepKind::Deposit, false),
+            ]
+        );
+        assert_eq!(plan.steps[0].chain, "basesepolia");
+        assert_eq!(plan.steps[3].chain, "sui");
+        assert!(plan.steps[2].tx.is_none());
+        assert!(plan.steps[1].tx.as_ref().unwrap()["data"]
+            .as_str()
+            .unwrap()
+            .starts_with("0x6fd3504e"));
+
+        // Planning doesn't create an intent
+        assert!(state.list_intents().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_native_eth_input_plans_leading_wrap() {
+        use super::super::plan::PlanStepKind;
+        use wiremock::matchers::{body_partial_json, method};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let rpc = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(body_partial_json(
+                serde_json::json!({ "method": "eth_getBalance" }),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "jsonrpc": "2.0", "id": 1, "result": "0xde0b6b3a7640000", // 1 ETH
+            })))
+            .mount(&rpc)
+            .await;
+        let mut config = Config::load(SuiNetwork::Testnet, &HashMap::new()).unwrap();
+        config.strategies.enabled_strategies = [YieldStrategy::ScallopUsdc].into_iter().collect();
+        config.sui.protocols = configured_protocols();
+        config.evm.rpc_url = rpc.uri();
+        config.evm.native_gas_reserve_wei = 1_000_000_000_000_000;
+        let chain = config.evm.default_chain;
+        let state = AppState::with_config(config).with_price_oracle(
+            StaticPriceOracle::new().with_price(AssetChain::Evm(chain), NATIVE_TOKEN, 2000.0),
+        );
+
+        let native = |amount: &str| CreateIntentRequest {
+            evm_chain: chain,
+            input_token: naisu_core::NATIVE_ETH_SENTINEL.to_string(),
+            input_amount: amount.to_string(),
+            ..evm_to_sui_request(YieldStrategy::ScallopUsdc)
+        };
+
+        let plan = plan_intent(State(state.clone()), ApiJson(native("500000000000000000")))
+            .await
+            .unwrap()
+            .data;
+        let kinds: Vec<_> = plan.steps.iter().map(|s| s.kind).collect();
+        assert_eq!(kinds[..2], [PlanStepKind::Wrap, PlanStepKind::Swap]);
+        let wrap = plan.steps[0].tx.as_ref().unwrap();
+        assert_eq!(wrap["data"], "0xd0e30db0");
+        assert_eq!(wrap["value"], "500000000000000000");
+
+        // All of the balance would leave nothing for gas
+        let err = plan_intent(State(state), ApiJson(native("1000000000000000000")))
+            .await
+            .unwrap_err();
+        assert_eq!(err.code, 422);
+    }
+
+    #[tokio::test]
+    async fn test_plan_rejects_unconfigured_protocol() {
+        let mut config = Config::load(SuiNetwork::Testnet, &HashMap::new()).unwrap();
+        config.strategies.enabled_strategies = [YieldStrategy::NaviUsdc].into_iter().collect();
+        config.sui.protocols = Default::default();
+        let state = AppState::with_config(config);
+
+        let err = plan_intent(
+            State(state),
+            ApiJson(evm_to_sui_request(YieldStrategy::NaviUsdc)),
+        )
+        .await
+        .unwrap_err();
+
+        assert_eq!(err.code, 503);
+    }
+
+    #[tokio::test]
+    async fn test_missing_scallop_package_rejects_scallop_strategies() {
+        let mut config = Config::load(SuiNetwork::Testnet, &HashMap::new()).unwrap();
+        config.strategies.enabled_strategies = YieldStrategy::ALL.into_iter().collect();
+        config.sui.protocols = ProtocolConfig {
+            scallop_package: None,
+            ..configured_protocols()
+        };
+        let state = AppState::with_config(config);
+
+        for strategy in [YieldStrategy::ScallopUsdc, YieldStrategy::ScallopSui] {
+            let err = create_intent(State(state.clone()), ApiJson(evm_to_sui_request(strategy)))
+                .await
+                .unwrap_err();
+            assert_eq!(err.code, 503);
+            assert!(err.message.contains("temporarily unavailable"));
+        }
+        assert!(state.list_intents().await.is_empty());
+
+        let created = create_intent(
+            State(state),
+            ApiJson(evm_to_sui_request(YieldStrategy::NaviUsdc)),
+        )
+        .await
+        .unwrap();
+        assert_eq!(created.code, 201);
+    }
+
+    #[tokio::test]
+    async fn test_sse_reconnect_replays_only_missed_transitions() {
+        use axum::body::Body;
+        use axum::http::Request;
+        use std::time::Duration;
+        use tower::ServiceExt;
+
+        let state = state_with_enabled(&[YieldStrategy::ScallopUsdc]);
+        let id = create_intent(
+            State(state.clone()),
+            ApiJson(evm_to_sui_request(YieldStrategy::ScallopUsdc)),
+        )
+        .await
+        .unwrap()
+        .data
+        .id;
+        for status in [
+            IntentStatus::SwapCompleted,
+            IntentStatus::Bridging,
+            IntentStatus::BridgeCompleted,
+        ] {
+            state.update_intent_status(&id, status).await.unwrap();
+        }
+
+        let seqs: Vec<u64> = state
+            .transitions
+            .read()
+            .await
+            .since(&id, None)
+            .iter()
+            .map(|t| t.seq)
+            .collect();
+
+        let response = crate::route::app_routes(state.clone())
+            .oneshot(
+                Request::get(format!("/api/v1/intents/{}/events", id))
+                    .header("last-event-id", seqs[0].to_string())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+
+        let mut body = response.into_body().into_data_stream();
+        let mut received = String::new();
+        async fn next_chunk(body: &mut axum::body::BodyDataStream) -> String {
+            let chunk = tokio::time::timeout(Duration::from_secs(2), body.next())
+                .await
+                .expect("event within timeout")
+                .unwrap()
+                .unwrap();
+            String::from_utf8(chunk.to_vec()).unwrap()
+        }
+
+        while received.matches("event: status").count() < 2 {
+            received.push_str(&next_chunk(&mut body).await);
+        }
+        assert!(!received.contains(&format!("id: {}\n", seqs[0])));
+        assert!(received.contains(&format!("id: {}\n", seqs[1])));
+        assert!(received.contains("\"to\":\"bridge_completed\""));
+
+        // Later transitions arrive live, continuing the numbering
+        state
+            .update_intent_status(&id, IntentStatus::Deposited)
+            .await
+            .unwrap();
+        let live = next_chunk(&mut body).await;
+        assert!(live.contains(&format!("id: {}\n", seqs[2] + 1)));
+        assert!(live.contains("\"to\":\"deposited\""));
+    }
+
+    #[tokio::test]
+    async fn test_receipt_verifies_and_detects_tampering() {
+        use crate::common::receipt::ReceiptSigner;
+
+        let signer = ReceiptSigner::from_key(ReceiptScheme::Ed25519, &"2a".repeat(32)).unwrap();
+        let mut config = Config::load(SuiNetwork::Testnet, &HashMap::new()).unwrap();
+        config.receipts.signer = Some(signer.clone());
+        let state = AppState::with_config(config.clone());
+
+        let mut intent = bridging_intent(IntentStatus::Pending);
+        intent.usdc_amount = Some("990000".to_string());
+        state.upsert_intent(intent).await.unwrap();
+
+        let err = get_intent_receipt(State(state.clone()), Path("intent-1".to_string()))
+            .await
+            .unwrap_err();
+        assert_eq!(err.code, 409);
+
+        state
+            .update_intent_status("intent-1", IntentStatus::Completed)
+            .await
+            .unwrap();
+        let signed = get_intent_receipt(State(state.clone()), Path("intent-1".to_string()))
+            .await
+            .unwrap()
+            .data;
+
+        assert_eq!(signed.public_key, signer.public_key());
+        assert_eq!(signed.receipt.fees.as_deref(), Some("10000"));
+        assert_eq!(
+            signed.receipt.timestamps.last().map(|t| t.status),
+            Some(IntentStatus::Completed)
+        );
+        assert!(signer.verify(&signed.receipt.canonical_bytes(), &signed.signature));
+
+        let mut tampered = signed.receipt.clone();
+        tampered.net_amount = Some("999999".to_string());
+        assert!(!signer.verify(&tampered.canonical_bytes(), &signed.signature));
+
+        // After a restart the transition log is empty; the receipt is unchanged
+        let restarted = AppState::with_config(config);
+        restarted
+            .upsert_intent(state.get_intent("intent-1").await.unwrap())
+            .await
+            .unwrap();
+        let again = get_intent_receipt(State(restarted), Path("intent-1".to_string()))
+            .await
+            .unwrap()
+            .data;
+        assert_eq!(again.receipt, signed.receipt);
+        assert_eq!(again.signature, signed.signature);
+    }
+}
@@ -0,0 +1,111 @@
+//! Ingestion of the hook's `IntentCreated` events
+//!
+//! The background listener and the admin replay endpoint both feed logs
+//! through [`replay_logs`].
+
+use naisu_core::{EvmChain, EvmLog, Intent, IntentCreatedEvent};
+use serde::Serialize;
+use tokio::task::JoinHandle;
+use utoipa::ToSchema;
+
+use crate::common::events::event_channel;
+use crate::common::listener::{BlockCursor, Checkpoint, HookListener};
+use crate::state::AppState;
+
+/// Outcome of a replay
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, ToSchema)]
+pub struct ReplaySummary {
+    /// Hook events decoded in the range
+    pub events_replayed: usize,
+    /// Intents created or modified by the replay (0 when already in sync)
+    pub intents_changed: usize,
+    /// Logs in the range that failed to decode
+    pub malformed: usize,
+}
+
+/// Feed decoded `IntentCreated` logs through intent ingestion.
+/// Idempotent: an intent that already exists is left untouched, so replaying
+/// a range twice changes nothing the second time.
+pub async fn replay_logs(state: &AppState, chain: EvmChain, logs: &[EvmLog]) -> ReplaySummary {
+    let mut summary = ReplaySummary::default();
+
+    for log in logs {
+        let event = match IntentCreatedEvent::try_from(log) {
+            Ok(event) => event,
+            Err(e) => {
+                tracing::warn!(tx = ?log.transaction_hash, error = %e, "Skipping malformed hook log");
+                summary.malformed += 1;
+                continue;
+            }
+        };
+        summary.events_replayed += 1;
+
+        // Keyed by the on-chain id, so the id in the hook event finds it
+        if state
+            .insert_intent_if_absent(Intent::from((event, chain)))
+            .await
+        {
+            summary.intents_changed += 1;
+        }
+    }
+
+    summary
+}
+
+/// Spawn the hook listener and feed its logs into intent ingestion, unless no
+/// hook address is configured
+pub fn spawn_hook_listener(state: AppState) -> Option<JoinHandle<()>> {
+    let evm = state.config.evm.clone();
+    if evm
+        .hook_address
+        .trim_start_matches("0x")
+        .trim_start_matches('0')
+        .is_empty()
+    {
+        return None;
+    }
+
+    let (tx, mut rx) = event_channel("hook", evm.event_channel_capacity);
+    let listener = HookListener::new(&evm);
+    let checkpoint = evm.checkpoint_path.clone().map(Checkpoint::new);
+    let saved = checkpoint.clone();
+    tokio::spawn(async move {
+        let resume = match &saved {
+            Some(checkpoint) => checkpoint.load().await.unwrap_or_else(|e| {
+                tracing::error!(error = %e, "Ignoring unreadable hook checkpoint");
+                None
+            }),
+            None => None,
+        };
+        let start_block = match resume.or(evm.start_block) {
+            Some(block) => block,
+            None => listener.wait_for_head().await,
+        };
+        tracing::info!(
+            mode = ?evm.listener_mode,
+            start_block,
+            resumed = resume.is_some(),
+            "Hook listener started"
+        );
+
+        let mut cursor = BlockCursor::new(start_block);
+        let e = listener.run(&mut cursor, &tx).await;
+        tracing::warn!(error = %e, "Hook listener stopped");
+    });
+
+    Some(tokio::spawn(async move {
+        while let Some(log) = rx.recv().await {
+            let block = log.block_number.as_deref().and_then(parse_block);
+            replay_logs(&state, state.config.evm.default_chain, &[log]).await;
+            if let (Some(checkpoint), Some(block)) = (&checkpoint, block) {
+                if let Err(e) = checkpoint.save(block).await {
+                    tracing::warn!(error = %e, block, "Could not save hook checkpoint");
+                }
+            }
+        }
+    }))
+}
+
+fn parse_block(value: &str) -> Option<u64> {
+    u64::from_str_radix(value.trim_start_matches("0x"), 16).ok()
+}
@@ -3,7 +3,12 @@
 //! API endpoints for querying and indexing intents
 
 pub mod handler;
+pub mod ingest;
+pub mod plan;
 pub mod route;
+pub mod sweeper;
 
 pub use handler::*;
+pub use ingest::{replay_logs, spawn_hook_listener, ReplaySummary};
 pub use route::intent_routes;
+pub use sweeper::{spawn_archive_sweeper, spawn_deadline_sweeper};
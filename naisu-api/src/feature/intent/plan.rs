@@ -0,0 +1,309 @@
+//! Signing plans
+//!
+//! Builds every unsigned transaction an intent will need, in order, without
+//! creating the intent or calling Circle. Steps whose inputs only exist after
+//! an earlier step lands (the mint needs the attestation) are returned as
+//! `deferred` with no transaction.
+
+use axum::http::StatusCode;
+use naisu_core::{
+    is_native_token, CreateIntentRequest, EvmChain, SuiNetwork, YieldStrategy,
+    DEFAULT_MAX_SLIPPAGE_BPS,
+};
+use naisu_sui::{
+    build_approve_call, build_deposit_for_burn_call, build_sui_burn_params,
+    build_weth_deposit_call, message_transmitter_address, token_messenger_address, CctpPackages,
+    Position, PositionObject, ProtocolConfig, ProtocolError, ProtocolFactory, PtbArgument,
+};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::common::response::ApiErrorResponse;
+
+/// What a plan step does
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PlanStepKind {
+    /// Wrap native ETH into WETH (`WETH.deposit`)
+    Wrap,
+    /// Swap the input token to USDC (or USDC to WETH) through the V4 hook
+    Swap,
+    /// Approve the CCTP TokenMessenger to spend USDC
+    Approval,
+    /// CCTP burn on the source chain
+    Burn,
+    /// CCTP `receive_message` on the destination chain
+    Mint,
+    /// Deposit USDC into the strategy's lending pool
+    Deposit,
+    /// Unwrap WETH into native ETH (`WETH.withdraw`)
+    Unwrap,
+}
+
+/// One transaction of a signing plan
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct UnsignedStep {
+    pub kind: PlanStepKind,
+    /// `sui` or an EVM chain key (e.g. `basesepolia`)
+    pub chain: String,
+    /// Depends on an earlier step's output; `tx` is omitted until then
+    pub deferred: bool,
+    /// EVM call (`to`, `data`), Sui Move call arguments or PTB
+    #[schema(value_type = Option<Object>)]
+    pub tx: Option<serde_json::Value>,
+    pub description: String,
+}
+
+/// Where an EvmToSui intent's bridged USDC is deposited
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DepositTarget {
+    /// One strategy, into the user's existing position if given
+    Strategy {
+        strategy: YieldStrategy,
+        position: Option<PositionObject>,
+    },
+    /// Amounts already checked to sum to the bridged USDC
+    Split(Vec<(YieldStrategy, u64)>),
+}
+
+/// Everything the planners need from config
+pub struct PlanContext<'a> {
+    pub network: SuiNetwork,
+    pub protocols: &'a ProtocolConfig,
+}
+
+/// Ordered signing plan for a validated EvmToSui request bridging
+/// `amount` of its input token
+pub fn plan_evm_to_sui(
+    ctx: &PlanContext,
+    request: &CreateIntentRequest,
+    amount: u128,
+    target: &DepositTarget,
+) -> Result<Vec<UnsignedStep>, ApiErrorResponse> {
+    let chain = request.evm_chain;
+    let usdc = chain.usdc_address();
+    let mut steps = Vec::new();
+
+    // The hook swaps ERC20s only: native ETH is wrapped first
+    let swap_input = if is_native_token(&request.input_token) {
+        steps.push(ready(
+            PlanStepKind::Wrap,
+            chain.as_str(),
+            &build_weth_deposit_call(chain, amount),
+            format!("Wrap {} wei of native ETH into WETH", amount),
+        ));
+        chain.weth_address()
+    } else {
+        request.input_token.as_str()
+    };
+
+    // Non-USDC input is swapped first, so the burn amount is only known afterwards
+    let needs_swap = !swap_input.eq_ignore_ascii_case(usdc);
+    if needs_swap {
+        steps.push(deferred(
+            PlanStepKind::Swap,
+            chain.as_str(),
+            format!("Swap {} to USDC via the V4 hook", swap_input),
+        ));
+    }
+
+    let spender = token_messenger_address(chain);
+    let bridge_steps = if needs_swap {
+        vec![
+            deferred(
+                PlanStepKind::Approval,
+                chain.as_str(),
+                "Approve swapped USDC for the CCTP TokenMessenger".to_string(),
+            ),
+            deferred(
+                PlanStepKind::Burn,
+                chain.as_str(),
+                "Burn swapped USDC for Sui".to_string(),
+            ),
+        ]
+    } else {
+        let approve = build_approve_call(usdc, spender, amount).map_err(bad_request)?;
+        let burn = build_deposit_for_burn_call(
+            chain,
+            amount,
+            ctx.network.cctp_domain(),
+            &request.dest_address,
+        )
+        .map_err(bad_request)?;
+        vec![
+            ready(
+                PlanStepKind::Approval,
+                chain.as_str(),
+                &approve,
+                format!("Approve {} USDC for the CCTP TokenMessenger", amount),
+            ),
+            ready(
+                PlanStepKind::Burn,
+                chain.as_str(),
+                &burn,
+                format!("Burn {} USDC on {} for Sui", amount, chain.name()),
+            ),
+        ]
+    };
+    steps.extend(bridge_steps);
+
+    let packages = CctpPackages::for_network(ctx.network);
+    steps.push(deferred(
+        PlanStepKind::Mint,
+        "sui",
+        format!(
+            "Receive the CCTP message on Sui ({}) once Circle attests the burn",
+            packages.message_transmitter
+        ),
+    ));
+
+    // The minted coin isn't known yet: the signer appends it as input 1
+    let minted = PtbArgument::Input { index: 1 };
+    let owner = request.dest_address.as_str();
+    let (deposit, strategies) = match target {
+        DepositTarget::Strategy { strategy, position } => {
+            let position = match position {
+                Some(existing) => Position::Existing(existing),
+                None => Position::New { owner },
+            };
+            (
+                ProtocolFactory::build_deposit_ptb(
+                    *strategy,
+                    minted,
+                    position,
+                    ctx.protocols,
+                    ctx.network,
+                ),
+                vec![*strategy],
+            )
+        }
+        DepositTarget::Split(parts) => (
+            ProtocolFactory::build_split_deposit_ptb(
+                parts,
+                minted,
+                owner,
+                ctx.protocols,
+                ctx.network,
+            ),
+            parts.iter().map(|(strategy, _)| *strategy).collect(),
+        ),
+    };
+    let names = strategies
+        .iter()
+        .map(|s| s.name())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let deposit = deposit.map_err(|e| match e {
+        ProtocolError::NotConfigured(_) => {
+            ApiErrorResponse::new(format!("Strategy {} is temporarily unavailable", names))
+                .with_code(StatusCode::SERVICE_UNAVAILABLE)
+                .with_error(e.to_string())
+        }
+        _ => ApiErrorResponse::new(format!("Strategy {} cannot be planned", names))
+            .with_code(StatusCode::UNPROCESSABLE_ENTITY)
+            .with_error(e.to_string()),
+    })?;
+    steps.push(ready(
+        PlanStepKind::Deposit,
+        "sui",
+        &deposit.build(),
+        format!(
+            "Deposit minted USDC into {} (input 1 is the minted coin)",
+            names
+        ),
+    ));
+
+    Ok(steps)
+}
+
+/// Ordered signing plan for a validated SuiToEvm request burning `amount`
+pub fn plan_sui_to_evm(
+    ctx: &PlanContext,
+    request: &CreateIntentRequest,
+    amount: u64,
+) -> Result<Vec<UnsignedStep>, ApiErrorResponse> {
+    let chain: EvmChain = request.evm_chain;
+
+    let burn = build_sui_burn_params(
+        &CctpPackages::for_network(ctx.network),
+        amount,
+        chain,
+        &request.dest_address,
+    )
+    .map_err(bad_request)?;
+
+    let mut steps = vec![
+        ready(
+            PlanStepKind::Burn,
+            "sui",
+            &burn,
+            format!("Burn {} USDC on Sui for {}", amount, chain.name()),
+        ),
+        deferred(
+            PlanStepKind::Mint,
+            chain.as_str(),
+            format!(
+                "Call receiveMessage on {} once Circle attests the burn",
+                message_transmitter_address(chain)
+            ),
+        ),
+    ];
+
+    let slippage = request.max_slippage_bps.unwrap_or(DEFAULT_MAX_SLIPPAGE_BPS);
+    match request.output_token.as_deref() {
+        None => {}
+        Some(token) if token.eq_ignore_ascii_case(chain.usdc_address()) => {}
+        // The swap output is only known once it lands, so these legs wait
+        Some(token) if is_native_token(token) => steps.extend([
+            deferred(
+                PlanStepKind::Swap,
+                chain.as_str(),
+                format!("Swap minted USDC to WETH (max slippage {} bps)", slippage),
+            ),
+            deferred(
+                PlanStepKind::Unwrap,
+                chain.as_str(),
+                format!("Unwrap WETH ({}) into native ETH", chain.weth_address()),
+            ),
+        ]),
+        Some(token) => steps.push(deferred(
+            PlanStepKind::Swap,
+            chain.as_str(),
+            format!(
+                "Swap minted USDC to {} (max slippage {} bps)",
+                token, slippage
+            ),
+        )),
+    }
+
+    Ok(steps)
+}
+
+fn ready(
+    kind: PlanStepKind,
+    chain: &str,
+    tx: &impl Serialize,
+    description: String,
+) -> UnsignedStep {
+    UnsignedStep {
+        kind,
+        chain: chain.to_string(),
+        deferred: false,
+        tx: serde_json::to_value(tx).ok(),
+        description,
+    }
+}
+
+fn deferred(kind: PlanStepKind, chain: &str, description: String) -> UnsignedStep {
+    UnsignedStep {
+        kind,
+        chain: chain.to_string(),
+        deferred: true,
+        tx: None,
+        description,
+    }
+}
+
+fn bad_request(e: impl ToString) -> ApiErrorResponse {
+    ApiErrorResponse::new(e.to_string()).with_code(StatusCode::BAD_REQUEST)
+}
@@ -1,18 +1,33 @@
 //! Intent Routes
 
 use axum::{
-    routing::{get},
+    middleware,
+    routing::{get, post},
     Router,
 };
 
-use crate::state::AppState;
 use super::handler;
+use crate::middleware::require_admin_key;
+use crate::state::AppState;
 
-/// Create intent routes
-pub fn intent_routes() -> Router<AppState> {
+/// Create intent routes; archiving requires the admin key
+pub fn intent_routes(state: AppState) -> Router<AppState> {
     Router::new()
-        .route("/intents", get(handler::list_intents))
-        .route("/intents/stats", get(handler::get_stats))
-        .route("/intents/:id", get(handler::get_intent))
-        .route("/intents/:id/bids", get(handler::get_intent_bids))
+        .route("/", post(handler::create_intent).get(handler::list_intents))
+        .route("/plan", post(handler::plan_intent))
+        .route("/withdraw", post(handler::create_withdraw_intent))
+        .route("/stats", get(handler::get_stats))
+        .route("/status/batch", post(handler::get_intent_status_batch))
+        .route("/{id}", get(handler::get_intent))
+        .route("/{id}/status", get(handler::get_intent_status))
+        .route(
+            "/{id}/archive",
+            post(handler::archive_intent)
+                .route_layer(middleware::from_fn_with_state(state, require_admin_key)),
+        )
+        .route("/{id}/deposit", post(handler::record_deposit))
+        .route("/{id}/bids", get(handler::get_intent_bids))
+        .route("/{id}/events", get(handler::stream_intent_events))
+        .route("/{id}/bridge", get(handler::get_bridge_status))
+        .route("/{id}/receipt", get(handler::get_intent_receipt))
 }
@@ -0,0 +1,205 @@
+//! Background sweeps over the intent store: auto-archival of finished
+//! intents, and flagging bridges that overran their deadline
+
+use std::time::Duration;
+
+use tokio::task::JoinHandle;
+
+use crate::state::AppState;
+
+/// How often the sweeper scans the store
+const SWEEP_INTERVAL: Duration = Duration::from_secs(300);
+
+/// How often overdue bridges are looked for; small next to the budgets
+const DEADLINE_SWEEP_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Spawn the auto-archive sweeper, unless disabled in config
+pub fn spawn_archive_sweeper(state: AppState) -> Option<JoinHandle<()>> {
+    let max_age = state.config.intents.auto_archive_after_secs;
+    if max_age == 0 {
+        return None;
+    }
+
+    Some(tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(SWEEP_INTERVAL);
+        loop {
+            ticker.tick().await;
+            let archived = state
+                .archive_terminal_before(unix_now() - max_age as i64)
+                .await;
+            if archived > 0 {
+                tracing::info!(archived, "Auto-archived finished intents");
+            }
+        }
+    }))
+}
+
+/// Spawn the sweeper flagging intents whose bridge deadline has passed
+pub fn spawn_deadline_sweeper(state: AppState) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(DEADLINE_SWEEP_INTERVAL);
+        loop {
+            ticker.tick().await;
+            let flagged = state.flag_overdue(unix_now()).await;
+            if flagged > 0 {
+                tracing::warn!(flagged, "Intents past their bridge deadline need diagnosis");
+            }
+        }
+    })
+}
+
+fn unix_now() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use naisu_core::{BridgeFailure, EvmChain, Intent, IntentStatus, SuiNetwork, YieldStrategy};
+
+    use crate::config::Config;
+    use crate::state::AppState;
+
+    #[tokio::test]
+    async fn test_archive_terminal_before_skips_recent_and_in_flight() {
+        let state =
+            AppState::with_config(Config::load(SuiNetwork::Testnet, &HashMap::new()).unwrap());
+        let intent = |id: &str, status: IntentStatus, updated_at: i64| {
+            let mut intent = Intent::new_sui_to_evm(
+                id.to_string(),
+                "0x2".to_string(),
+                "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed".to_string(),
+                EvmChain::BaseSepolia,
+                "usdc".to_string(),
+                "1000000".to_string(),
+            );
+            intent.status = status;
+            intent.updated_at = updated_at;
+            intent
+        };
+
+        state
+            .upsert_intent(intent("old-done", IntentStatus::Completed, 100))
+            .await
+            .unwrap();
+        state
+            .upsert_intent(intent("old-bridging", IntentStatus::Bridging, 100))
+            .await
+            .unwrap();
+        state
+            .upsert_intent(intent("new-failed", IntentStatus::Failed, 1_000))
+            .await
+            .unwrap();
+
+        assert_eq!(state.archive_terminal_before(500).await, 1);
+        assert!(state.get_intent("old-done").await.unwrap().archived);
+        assert!(!state.get_intent("old-bridging").await.unwrap().archived);
+        assert!(!state.get_intent("new-failed").await.unwrap().archived);
+    }
+
+    #[tokio::test]
+    async fn test_flag_overdue_only_flags_unfinished_past_deadline() {
+        let state =
+            AppState::with_config(Config::load(SuiNetwork::Testnet, &HashMap::new()).unwrap());
+        let intent = |id: &str, status: IntentStatus, deadline: Option<i64>| {
+            let mut intent = Intent::new_sui_to_evm(
+                id.to_string(),
+                "0x2".to_string(),
+                "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed".to_string(),
+                EvmChain::BaseSepolia,
+                "usdc".to_string(),
+                "1000000".to_string(),
+            );
+            intent.status = status;
+            intent.bridge_deadline = deadline;
+            intent
+        };
+
+        state
+            .upsert_intent(intent("late", IntentStatus::Bridging, Some(100)))
+            .await
+            .unwrap();
+        state
+            .upsert_intent(intent("on-time", IntentStatus::Bridging, Some(1_000)))
+            .await
+            .unwrap();
+        state
+            .upsert_intent(intent("done", IntentStatus::Completed, Some(100)))
+            .await
+            .unwrap();
+        state
+            .upsert_intent(intent("no-deadline", IntentStatus::Bridging, None))
+            .await
+            .unwrap();
+
+        assert_eq!(state.flag_overdue(500).await, 1);
+        let late = state.get_intent("late").await.unwrap();
+        assert_eq!(late.status, IntentStatus::Bridging, "still in flight");
+        assert_eq!(late.bridge_failure, Some(BridgeFailure::DeadlinePassed));
+        // Already flagged
+        assert_eq!(state.flag_overdue(600).await, 0);
+        for (id, status) in [
+            ("on-time", IntentStatus::Bridging),
+            ("done", IntentStatus::Completed),
+            ("no-deadline", IntentStatus::Bridging),
+        ] {
+            let intent = state.get_intent(id).await.unwrap();
+            assert_eq!(intent.status, status);
+            assert_eq!(intent.bridge_failure, None);
+        }
+
+        // A late mint clears the flag
+        state
+            .update_intent("late", |i| i.set_status(IntentStatus::BridgeCompleted))
+            .await
+            .unwrap();
+        assert_eq!(state.get_intent("late").await.unwrap().bridge_failure, None);
+    }
+
+    #[tokio::test]
+    async fn test_deadline_starts_at_the_burn() {
+        let state =
+            AppState::with_config(Config::load(SuiNetwork::Testnet, &HashMap::new()).unwrap());
+        // As ingested from a hook event: swapped, not burned yet
+        let intent = Intent::new_evm_to_sui(
+            "hook".to_string(),
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed".to_string(),
+            "0x2".to_string(),
+            EvmChain::Ethereum,
+            "usdc".to_string(),
+            "1000000".to_string(),
+            YieldStrategy::ScallopUsdc,
+        );
+        state.insert_intent_if_absent(intent).await;
+        assert_eq!(
+            state.get_intent("hook").await.unwrap().bridge_deadline,
+            None
+        );
+
+        let burned = state
+            .update_intent("hook", |intent| {
+                intent.bridge_tx_hash = Some("0xburn".to_string());
+                intent.set_status(IntentStatus::Bridging);
+                intent.clone()
+            })
+            .await
+            .unwrap()
+            .unwrap();
+        let deadline = state.get_intent("hook").await.unwrap().bridge_deadline;
+        assert_eq!(deadline, Some(burned.updated_at + 15 * 60));
+
+        // Later updates don't push it back
+        state
+            .update_intent("hook", |intent| intent.updated_at += 1_000)
+            .await
+            .unwrap();
+        assert_eq!(
+            state.get_intent("hook").await.unwrap().bridge_deadline,
+            deadline
+        );
+    }
+}
@@ -1,6 +1,10 @@
 //! API Feature Modules
 
+pub mod admin;
+pub mod bridge;
+pub mod chain;
 pub mod health;
+pub mod intent;
 pub mod network;
 pub mod solver;
 pub mod strategy;
@@ -7,6 +7,7 @@ use axum::{extract::State, routing::get, Json, Router};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
+use crate::common::extract::ApiJson;
 use crate::state::AppState;
 
 /// Network information response
@@ -104,7 +105,7 @@ async fn get_network_info(State(state): State<Arc<AppState>>) -> Json<NetworkInf
 /// Switch network
 async fn switch_network(
     State(state): State<Arc<AppState>>,
-    Json(request): Json<SwitchNetworkRequest>,
+    ApiJson(request): ApiJson<SwitchNetworkRequest>,
 ) -> Json<SwitchNetworkResponse> {
     let network = request.network.to_lowercase();
 
@@ -1,9 +1,11 @@
 use axum::{
-    extract::{Json, Path, State},
+    extract::{Path, State},
     http::StatusCode,
 };
+use naisu_core::Intent;
 use serde::{Deserialize, Serialize};
 
+use crate::common::extract::ApiJson;
 use crate::common::response::{ApiErrorResponse, ApiResponse, ApiSuccessResponse};
 use crate::state::{AppState, SolverBidEntry};
 
@@ -44,8 +46,9 @@ impl From<SolverBidEntry> for SolverBidResponse {
 /// POST /solvers/bids — persist a solver bid
 pub async fn post_bid(
     State(state): State<AppState>,
-    Json(bid): Json<SolverBidEntry>,
+    ApiJson(mut bid): ApiJson<SolverBidEntry>,
 ) -> ApiResponse<SolverBidEntry> {
+    bid.intent_id = Intent::canonical_id(&bid.intent_id);
     if bid.intent_id.is_empty() {
         return Err(
             ApiErrorResponse::new("intent_id is required").with_code(StatusCode::BAD_REQUEST)
@@ -77,6 +80,7 @@ pub async fn get_bids(
     State(state): State<AppState>,
     Path(intent_id): Path<String>,
 ) -> ApiResponse<Vec<SolverBidResponse>> {
+    let intent_id = Intent::canonical_id(&intent_id);
     let bids = state.get_bids_for_intent(&intent_id).await;
 
     // Convert to response DTOs
@@ -1,14 +1,22 @@
+use axum::extract::State;
+use naisu_core::YieldStrategy;
+use naisu_sui::{ProtocolConfig, ProtocolFactory};
 use serde::Serialize;
+use utoipa::ToSchema;
 
 use crate::common::response::{ApiResponse, ApiSuccessResponse};
+use crate::config::StrategyConfig;
+use crate::state::AppState;
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct StrategyData {
     pub id: String,
     pub protocol: String,
     pub asset: String,
     pub apy: f64,
     pub risk_score: u8,
+    /// Whether users may currently select this strategy
+    pub enabled: bool,
 }
 
 /// Hardcoded fallback matching MOCK_RATES used by the solver bots
@@ -20,6 +28,7 @@ fn mock_strategies() -> Vec<StrategyData> {
             asset: "SUI".to_string(),
             apy: 8.5,
             risk_score: 3,
+            enabled: true,
         },
         StrategyData {
             id: "scallop_usdc".to_string(),
@@ -27,6 +36,7 @@ fn mock_strategies() -> Vec<StrategyData> {
             asset: "USDC".to_string(),
             apy: 7.2,
             risk_score: 2,
+            enabled: true,
         },
         StrategyData {
             id: "navi_sui".to_string(),
@@ -34,6 +44,7 @@ fn mock_strategies() -> Vec<StrategyData> {
             asset: "SUI".to_string(),
             apy: 8.0,
             risk_score: 4,
+            enabled: true,
         },
         StrategyData {
             id: "navi_usdc".to_string(),
@@ -41,17 +52,26 @@ fn mock_strategies() -> Vec<StrategyData> {
             asset: "USDC".to_string(),
             apy: 6.8,
             risk_score: 3,
+            enabled: true,
         },
     ]
 }
 
 /// GET /strategies — returns yield strategies.
 /// Attempts live adapter fetch; on any failure returns mock data.
-pub async fn get_strategies() -> ApiResponse<Vec<StrategyData>> {
+/// Strategies outside the configured allow-list, or whose protocol package ids
+/// aren't configured, are listed with `enabled: false`.
+#[utoipa::path(
+    get,
+    path = "/strategies",
+    tag = "strategies",
+    responses((status = 200, description = "Available yield strategies", body = ApiSuccessResponse<Vec<StrategyData>>))
+)]
+pub async fn get_strategies(State(state): State<AppState>) -> ApiResponse<Vec<StrategyData>> {
     // Try real adapters via naisu-sui
     let live = fetch_live_strategies().await;
 
-    let strategies = match live {
+    let mut strategies = match live {
         Some(data) if !data.is_empty() => data,
         _ => {
             tracing::info!("Using mock strategy fallback");
@@ -59,9 +79,30 @@ pub async fn get_strategies() -> ApiResponse<Vec<StrategyData>> {
         }
     };
 
+    apply_allow_list(
+        &mut strategies,
+        &state.config.strategies,
+        &state.config.sui.protocols,
+    );
+
     Ok(ApiSuccessResponse::new(strategies))
 }
 
+/// Mark every strategy that is unknown, not in the allow-list, or missing its
+/// protocol configuration as disabled
+fn apply_allow_list(
+    strategies: &mut [StrategyData],
+    config: &StrategyConfig,
+    protocols: &ProtocolConfig,
+) {
+    for strategy in strategies {
+        strategy.enabled = strategy
+            .id
+            .parse::<YieldStrategy>()
+            .is_ok_and(|s| config.is_enabled(s) && ProtocolFactory::is_available(s, protocols));
+    }
+}
+
 /// Attempt to pull data from the real Scallop/Navi adapters.
 /// Returns None on any error so we can fall back gracefully.
 async fn fetch_live_strategies() -> Option<Vec<StrategyData>> {
@@ -89,8 +130,55 @@ async fn fetch_live_strategies() -> Option<Vec<StrategyData>> {
             asset: o.asset,
             apy: o.apy,
             risk_score: o.risk_score,
+            enabled: true,
         })
         .collect();
 
     Some(strategies)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use naisu_sui::testing::configured_protocols;
+
+    #[test]
+    fn test_disabled_strategy_still_listed() {
+        let config = StrategyConfig {
+            enabled_strategies: [YieldStrategy::ScallopUsdc, YieldStrategy::ScallopSui]
+                .into_iter()
+                .collect(),
+            deposit_limits: Default::default(),
+        };
+
+        let protocols = configured_protocols();
+
+        let mut strategies = mock_strategies();
+        apply_allow_list(&mut strategies, &config, &protocols);
+
+        assert_eq!(strategies.len(), 4);
+        for strategy in &strategies {
+            assert_eq!(strategy.enabled, strategy.protocol == "Scallop");
+        }
+    }
+
+    #[test]
+    fn test_unconfigured_protocol_disables_its_strategies() {
+        let config = StrategyConfig {
+            enabled_strategies: YieldStrategy::ALL.into_iter().collect(),
+            deposit_limits: Default::default(),
+        };
+        let protocols = ProtocolConfig {
+            navi_package: Some("0x4a71".to_string()),
+            navi_pool: Some("0x9001".to_string()),
+            ..Default::default()
+        };
+
+        let mut strategies = mock_strategies();
+        apply_allow_list(&mut strategies, &config, &protocols);
+
+        for strategy in &strategies {
+            assert_eq!(strategy.enabled, strategy.protocol == "Navi");
+        }
+    }
+}
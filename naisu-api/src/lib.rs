@@ -7,5 +7,6 @@ pub mod config;
 pub mod feature;
 pub mod logging;
 pub mod middleware;
+pub mod openapi;
 pub mod route;
 pub mod state;
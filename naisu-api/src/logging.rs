@@ -1,35 +1,3 @@
-use tracing_subscriber::{
-    fmt::{self, format::FmtSpan},
-    layer::SubscriberExt,
-    util::SubscriberInitExt,
-    EnvFilter,
-};
+//! Logging setup (shared with the agent binaries, see `naisu_agent::logging`)
 
-/// Setup the logging subscriber with JSON or pretty format based on environment
-pub fn setup_subscriber() -> impl SubscriberInitExt {
-    let env_filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
-
-    let fmt_layer = fmt::layer()
-        .with_target(true)
-        .with_thread_ids(true)
-        .with_thread_names(true)
-        .with_span_events(FmtSpan::CLOSE);
-
-    tracing_subscriber::registry()
-        .with(env_filter)
-        .with(fmt_layer)
-}
-
-/// Setup JSON logging for production environments
-pub fn setup_json_subscriber() -> impl SubscriberInitExt {
-    let env_filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
-
-    let fmt_layer = fmt::layer()
-        .json()
-        .with_target(true)
-        .with_span_events(FmtSpan::CLOSE);
-
-    tracing_subscriber::registry()
-        .with(env_filter)
-        .with(fmt_layer)
-}
+pub use naisu_agent::logging::{build_subscriber, init, LogConfig, LogFormat};
@@ -9,12 +9,16 @@ use axum::{
     middleware,
 };
 use tower_http::cors::{AllowOrigin, CorsLayer};
-use tracing::{info, Level};
-use tracing_subscriber::FmtSubscriber;
+use tracing::{error, info};
 
 use naisu_api::{
-    common::server::create_dual_stack_listener, config::Config, middleware::http_trace_middleware,
-    route::app_routes, state::AppState,
+    common::outbox::{spawn_outbox_worker, Outbox, WebhookDeliverer, DRAIN_INTERVAL},
+    common::server::create_dual_stack_listener,
+    config::Config,
+    feature::intent::{spawn_archive_sweeper, spawn_deadline_sweeper, spawn_hook_listener},
+    middleware::http_trace_middleware,
+    route::app_routes,
+    state::AppState,
 };
 
 #[tokio::main]
@@ -23,25 +27,67 @@ async fn main() -> std::io::Result<()> {
     dotenvy::dotenv().ok();
 
     // Initialize logging
-    let subscriber = FmtSubscriber::builder()
-        .with_max_level(Level::INFO)
-        .finish();
-
-    tracing::subscriber::set_global_default(subscriber).expect("Failed to set subscriber");
+    if let Err(e) = naisu_api::logging::init() {
+        eprintln!("❌ {}", e);
+        return Err(std::io::Error::new(std::io::ErrorKind::InvalidInput, e));
+    }
 
     info!("🚀 Starting Naisu API...");
 
     // Load configuration
-    let config = Arc::new(Config::from_env());
+    let config = match Config::from_env() {
+        Ok(config) => Arc::new(config),
+        Err(e) => {
+            error!(var = e.var, "❌ {}", e);
+            return Err(std::io::Error::new(std::io::ErrorKind::InvalidInput, e));
+        }
+    };
     info!(
         env = %config.rust_env,
+        network = config.network.as_str(),
         port = config.server.port,
         chain_id = config.evm.chain_id,
         "✅ Configuration loaded"
     );
 
+    // Refuse to mix mainnet and testnet components, or run production off mainnet
+    if let Err(e) = config.check_network() {
+        error!(component = e.component, "❌ {}", e);
+        return Err(std::io::Error::new(std::io::ErrorKind::InvalidInput, e));
+    }
+
     // Initialize application state
-    let app_state = AppState::new();
+    let mut app_state = AppState::with_config((*config).clone());
+    if let Some(path) = &config.intents.outbox_path {
+        match Outbox::open(path, config.intents.outbox_max_attempts).await {
+            Ok(outbox) => {
+                info!(pending = outbox.len(), path = %path.display(), "Outbox loaded");
+                app_state = app_state.with_outbox(outbox);
+            }
+            Err(e) => {
+                error!(path = %path.display(), "❌ Cannot open outbox: {}", e);
+                return Err(e);
+            }
+        }
+    }
+
+    // Deliver queued side effects
+    spawn_outbox_worker(
+        app_state.clone(),
+        Box::new(WebhookDeliverer::new()),
+        DRAIN_INTERVAL,
+    );
+
+    // Archive finished intents in the background
+    spawn_archive_sweeper(app_state.clone());
+
+    // Fail bridges that overran their deadline
+    spawn_deadline_sweeper(app_state.clone());
+
+    // Ingest hook events in the background
+    if spawn_hook_listener(app_state.clone()).is_none() {
+        info!("HOOK_ADDRESS unset, hook listener disabled");
+    }
     info!("✅ Application state initialized");
 
     // Setup CORS - handle wildcard separately
@@ -0,0 +1,45 @@
+use axum::{
+    extract::{Request, State},
+    http::{header, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+use crate::common::response::ApiErrorResponse;
+use crate::state::AppState;
+
+/// Admin route guard
+/// Requires `Authorization: Bearer <ADMIN_API_KEY>`; admin routes are
+/// disabled entirely when no key is configured
+pub async fn require_admin_key(
+    State(state): State<AppState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let Some(expected) = state.config.admin.api_key.as_deref() else {
+        return ApiErrorResponse::new("Admin API is disabled")
+            .with_code(StatusCode::FORBIDDEN)
+            .into_response();
+    };
+
+    let provided = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "));
+
+    if !provided.is_some_and(|key| constant_time_eq(key.as_bytes(), expected.as_bytes())) {
+        return ApiErrorResponse::new("Invalid or missing admin key")
+            .with_code(StatusCode::UNAUTHORIZED)
+            .into_response();
+    }
+
+    next.run(request).await
+}
+
+/// Byte equality that takes the same time wherever `a` and `b` differ, so
+/// response timing doesn't reveal how much of a guessed key was right. Only
+/// the length leaks.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
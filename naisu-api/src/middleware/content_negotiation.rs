@@ -0,0 +1,81 @@
+use axum::{extract::Request, http::header, middleware::Next, response::Response};
+
+use crate::common::response::ResponseFormat;
+
+/// Response format negotiation
+/// Encodes `ApiSuccessResponse` / `ApiErrorResponse` bodies as JSON or
+/// MessagePack according to the request's `Accept` header
+pub async fn negotiate_format(request: Request, next: Next) -> Response {
+    let format = ResponseFormat::from_accept(request.headers().get(header::ACCEPT));
+    format.scope(next.run(request)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::response::format::MSGPACK_CONTENT_TYPE;
+    use crate::common::response::{ApiErrorResponse, ApiResponse, ApiSuccessResponse};
+    use axum::{body::Body, http::StatusCode, routing::get, Router};
+    use tower::ServiceExt;
+
+    async fn chains() -> ApiResponse<Vec<String>> {
+        Ok(
+            ApiSuccessResponse::new(vec!["sui".to_string(), "base".to_string()])
+                .with_message("Supported chains"),
+        )
+    }
+
+    async fn missing() -> ApiResponse<()> {
+        Err(ApiErrorResponse::new("Intent not found").with_code(StatusCode::NOT_FOUND))
+    }
+
+    async fn get_with_accept(path: &str, accept: &str) -> (StatusCode, String, Vec<u8>) {
+        let app = Router::new()
+            .route("/chains", get(chains))
+            .route("/missing", get(missing))
+            .layer(axum::middleware::from_fn(negotiate_format));
+        let response = app
+            .oneshot(
+                Request::get(path)
+                    .header(header::ACCEPT, accept)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let status = response.status();
+        let content_type = response.headers()[header::CONTENT_TYPE]
+            .to_str()
+            .unwrap()
+            .to_string();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, content_type, body.to_vec())
+    }
+
+    #[tokio::test]
+    async fn test_msgpack_round_trips() {
+        let (status, content_type, body) = get_with_accept("/chains", MSGPACK_CONTENT_TYPE).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(content_type, MSGPACK_CONTENT_TYPE);
+        assert!(serde_json::from_slice::<serde_json::Value>(&body).is_err());
+
+        let decoded: ApiSuccessResponse<Vec<String>> = rmp_serde::from_slice(&body).unwrap();
+        assert_eq!(decoded, chains().await.unwrap());
+
+        let (status, content_type, body) = get_with_accept("/missing", MSGPACK_CONTENT_TYPE).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(content_type, MSGPACK_CONTENT_TYPE);
+        let decoded: ApiErrorResponse = rmp_serde::from_slice(&body).unwrap();
+        assert_eq!(decoded, missing().await.unwrap_err());
+    }
+
+    #[tokio::test]
+    async fn test_unsupported_accept_falls_back_to_json() {
+        let (_, content_type, body) = get_with_accept("/chains", "text/csv").await;
+        assert_eq!(content_type, "application/json");
+        let decoded: ApiSuccessResponse<Vec<String>> = serde_json::from_slice(&body).unwrap();
+        assert_eq!(decoded.data, ["sui", "base"]);
+    }
+}
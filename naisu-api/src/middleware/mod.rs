@@ -1,3 +1,7 @@
+pub mod admin_auth;
+pub mod content_negotiation;
 pub mod http_trace;
 
+pub use admin_auth::require_admin_key;
+pub use content_negotiation::negotiate_format;
 pub use http_trace::http_trace_middleware;
@@ -0,0 +1,220 @@
+//! OpenAPI Documentation
+//!
+//! Generated spec served at `/openapi.json`, Swagger UI at `/docs`.
+
+use axum::{extract::State, response::Html, routing::get, Json, Router};
+use naisu_core::{
+    AllocationOutcome, AllocationStatus, BridgeFailure, CreateIntentRequest, Direction, EvmChain,
+    FieldError, IntentStatus, StrategyAllocation, WithdrawIntentRequest, YieldStrategy,
+};
+use utoipa::OpenApi;
+
+use crate::common::response::ApiErrorResponse;
+use crate::config::FeatureConfig;
+use crate::feature::{
+    admin::handler as admin, bridge::handler as bridge, chain::handler as chain,
+    health::handler as health, intent::handler as intent, strategy::handler as strategy,
+};
+use crate::state::AppState;
+
+#[derive(OpenApi)]
+#[openapi(
+    info(title = "Naisu API", description = "Cross-chain intent API for Naisu"),
+    servers((url = "/api/v1")),
+    paths(
+        health::public_health_check,
+        health::detailed_health_check,
+        health::get_version,
+        chain::list_chains,
+        strategy::get_strategies,
+        intent::create_intent,
+        intent::plan_intent,
+        intent::create_withdraw_intent,
+        intent::list_intents,
+        intent::get_stats,
+        intent::get_intent,
+        intent::get_intent_status,
+        intent::get_intent_status_batch,
+        intent::archive_intent,
+        intent::record_deposit,
+        intent::get_intent_bids,
+        intent::get_bridge_status,
+        intent::stream_intent_events,
+        intent::get_intent_receipt,
+        bridge::quote_sui_to_evm_bridge,
+        bridge::init_sui_to_evm_bridge,
+        admin::replay_events,
+        admin::get_config,
+    ),
+    components(schemas(
+        ApiErrorResponse,
+        CreateIntentRequest,
+        WithdrawIntentRequest,
+        FieldError,
+        Direction,
+        EvmChain,
+        IntentStatus,
+        YieldStrategy,
+        StrategyAllocation,
+        AllocationOutcome,
+        AllocationStatus,
+        BridgeFailure,
+        health::HealthDetails,
+        health::VersionInfo,
+        chain::ChainInfo,
+        strategy::StrategyData,
+        intent::IntentResponse,
+        intent::RecordDepositRequest,
+        intent::IntentListResponse,
+        intent::IntentStatusResponse,
+        intent::BatchStatusRequest,
+        intent::BatchStatusResponse,
+        intent::IntentStats,
+        intent::IntentCounts,
+        intent::BidResponse,
+        intent::BridgeStatusResponse,
+        crate::common::lifi::LiFiTransfer,
+        crate::common::lifi::LiFiStatus,
+        intent::IntentPlanResponse,
+        intent::WithdrawIntentResponse,
+        intent::SignedReceipt,
+        crate::common::receipt::IntentReceipt,
+        crate::common::receipt::ReceiptScheme,
+        crate::common::receipt::ReceiptTxHashes,
+        crate::common::receipt::ReceiptTimestamp,
+        crate::common::transitions::StatusTransition,
+        crate::feature::intent::plan::UnsignedStep,
+        crate::feature::intent::plan::PlanStepKind,
+        intent::BridgeStepStatus,
+        intent::BridgeStep,
+        intent::StepState,
+        bridge::QuoteRequest,
+        crate::common::quote::BridgeQuote,
+        bridge::InitBridgeRequest,
+        bridge::InitBridgeResponse,
+        bridge::BridgeTxParams,
+        admin::ReplayRequest,
+        crate::feature::intent::ReplaySummary,
+    )),
+    tags(
+        (name = "health", description = "Service health"),
+        (name = "chains", description = "Supported chains"),
+        (name = "strategies", description = "Yield strategies"),
+        (name = "intents", description = "Cross-chain intents"),
+        (name = "bridge", description = "Unsigned bridge transactions"),
+        (name = "admin", description = "Operator tools (requires admin key)"),
+    )
+)]
+pub struct ApiDoc;
+
+/// Swagger UI page, loading its assets from the jsDelivr CDN
+const SWAGGER_UI_HTML: &str = r##"<!DOCTYPE html>
+<html lang="en">
+<head>
+  <meta charset="utf-8" />
+  <title>Naisu API Docs</title>
+  <link rel="stylesheet" href="https://cdn.jsdelivr.net/npm/swagger-ui-dist@5/swagger-ui.css" />
+</head>
+<body>
+  <div id="swagger-ui"></div>
+  <script src="https://cdn.jsdelivr.net/npm/swagger-ui-dist@5/swagger-ui-bundle.js"></script>
+  <script>
+    window.ui = SwaggerUIBundle({ url: "/openapi.json", dom_id: "#swagger-ui" });
+  </script>
+</body>
+</html>
+"##;
+
+/// Routes serving the OpenAPI spec and Swagger UI
+pub fn docs_routes() -> Router<AppState> {
+    Router::new()
+        .route("/openapi.json", get(openapi_json))
+        .route("/docs", get(swagger_ui))
+}
+
+/// The spec without the paths of disabled route groups, which aren't mounted
+pub fn spec(features: &FeatureConfig) -> utoipa::openapi::OpenApi {
+    let mut disabled = Vec::new();
+    if !features.quotes_enabled {
+        disabled.push("/bridge/");
+    }
+    if !features.solvers_enabled {
+        disabled.push("/solvers/");
+    }
+    if !features.admin_enabled {
+        disabled.push("/admin/");
+    }
+
+    let mut spec = ApiDoc::openapi();
+    spec.paths
+        .paths
+        .retain(|path, _| !disabled.iter().any(|prefix| path.starts_with(prefix)));
+    spec
+}
+
+async fn openapi_json(State(state): State<AppState>) -> Json<utoipa::openapi::OpenApi> {
+    Json(spec(&state.config.features))
+}
+
+async fn swagger_ui() -> Html<&'static str> {
+    Html(SWAGGER_UI_HTML)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use axum::{body::Body, http::Request};
+    use naisu_core::SuiNetwork;
+    use tower::ServiceExt;
+
+    use super::spec;
+    use crate::config::Config;
+    use crate::route::app_routes;
+    use crate::state::AppState;
+
+    #[tokio::test]
+    async fn test_openapi_json_lists_intent_paths() {
+        let app = app_routes(AppState::with_config(
+            Config::load(SuiNetwork::Testnet, &HashMap::new()).unwrap(),
+        ));
+
+        let response = app
+            .oneshot(Request::get("/openapi.json").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let spec: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+        let paths = spec["paths"].as_object().unwrap();
+        assert!(paths.contains_key("/intents"));
+        assert!(paths.contains_key("/intents/{id}"));
+
+        let statuses = &spec["components"]["schemas"]["IntentStatus"]["enum"];
+        assert!(statuses
+            .as_array()
+            .unwrap()
+            .contains(&serde_json::json!("bridging")));
+    }
+
+    #[test]
+    fn test_disabled_groups_are_left_out() {
+        let mut features = Config::load(SuiNetwork::Testnet, &HashMap::new())
+            .unwrap()
+            .features;
+        let all = spec(&features).paths.paths;
+        assert!(all.contains_key("/bridge/quote"));
+        assert!(all.contains_key("/admin/config"));
+
+        features.quotes_enabled = false;
+        features.admin_enabled = false;
+        let paths = spec(&features).paths.paths;
+        assert!(!paths.keys().any(|p| p.starts_with("/bridge/")));
+        assert!(!paths.keys().any(|p| p.starts_with("/admin/")));
+        assert!(paths.contains_key("/intents"));
+    }
+}
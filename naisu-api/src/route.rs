@@ -1,28 +1,54 @@
-use axum::Router;
+use axum::{extract::DefaultBodyLimit, middleware, Router};
 use std::sync::Arc;
 
 use crate::{
     feature::{
-        health::route::health_routes, network, solver::route::solver_routes,
+        admin::admin_routes,
+        bridge::route::bridge_routes,
+        chain::route::chain_routes,
+        health::route::{health_routes, version_routes},
+        intent::intent_routes,
+        network,
+        solver::route::solver_routes,
         strategy::route::strategy_routes,
     },
+    middleware::negotiate_format,
+    openapi::docs_routes,
     state::AppState,
 };
 
 /// Build all application routes
 pub fn app_routes(state: AppState) -> Router {
+    let max_body_bytes = state.config.server.max_body_bytes;
+
     // Convert to Arc for network routes
     let state_arc = Arc::new(state.clone());
 
-    let api_routes = Router::new()
+    // Optional groups are left unmounted when disabled, so they 404
+    let features = &state.config.features;
+    let mut api_routes = Router::new()
         .nest("/health", health_routes())
+        .nest("/version", version_routes())
+        .nest("/chains", chain_routes())
         .nest("/network", network::routes().with_state(state_arc))
-        .nest("/strategies", strategy_routes())
-        .nest("/solvers", solver_routes());
+        .nest("/intents", intent_routes(state.clone()))
+        .nest("/strategies", strategy_routes());
+    if features.quotes_enabled {
+        api_routes = api_routes.nest("/bridge", bridge_routes());
+    }
+    if features.solvers_enabled {
+        api_routes = api_routes.nest("/solvers", solver_routes());
+    }
+    if features.admin_enabled {
+        api_routes = api_routes.nest("/admin", admin_routes(state.clone()));
+    }
 
     Router::new()
         .nest("/api/v1", api_routes)
+        .merge(docs_routes())
         .fallback(common::handle_404)
+        .layer(DefaultBodyLimit::max(max_body_bytes))
+        .layer(middleware::from_fn(negotiate_format))
         .with_state(state)
 }
 
@@ -37,3 +63,82 @@ mod common {
             .with_message("The requested endpoint does not exist.")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use axum::{body::Body, http::Request};
+    use naisu_core::SuiNetwork;
+    use tower::ServiceExt;
+
+    use super::app_routes;
+    use crate::config::Config;
+    use crate::state::AppState;
+
+    #[tokio::test]
+    async fn test_disabled_features_are_not_mounted() {
+        let mut config = Config::load(SuiNetwork::Testnet, &HashMap::new()).unwrap();
+        config.features.quotes_enabled = false;
+        config.features.solvers_enabled = false;
+        config.features.admin_enabled = false;
+        let app = app_routes(AppState::with_config(config));
+
+        let status = |request: Request<Body>| {
+            let app = app.clone();
+            async move { app.oneshot(request).await.unwrap().status().as_u16() }
+        };
+        let post = |uri| {
+            Request::post(uri)
+                .header("content-type", "application/json")
+                .body(Body::from("{}"))
+                .unwrap()
+        };
+
+        assert_eq!(status(post("/api/v1/bridge/quote")).await, 404);
+        assert_eq!(status(post("/api/v1/bridge/sui-to-evm")).await, 404);
+        assert_eq!(status(post("/api/v1/solvers/bids")).await, 404);
+        assert_eq!(
+            status(
+                Request::get("/api/v1/admin/config")
+                    .body(Body::empty())
+                    .unwrap()
+            )
+            .await,
+            404
+        );
+
+        // Everything else stays up
+        assert_eq!(
+            status(Request::get("/api/v1/intents").body(Body::empty()).unwrap()).await,
+            200
+        );
+    }
+
+    #[tokio::test]
+    async fn test_archive_requires_admin_key() {
+        let mut config = Config::load(SuiNetwork::Testnet, &HashMap::new()).unwrap();
+        config.admin.api_key = Some("test-admin-key".to_string());
+        let app = app_routes(AppState::with_config(config));
+
+        let archive = |auth: Option<&str>| {
+            let mut request = Request::post("/api/v1/intents/0x1/archive");
+            if let Some(key) = auth {
+                request = request.header("authorization", format!("Bearer {}", key));
+            }
+            let app = app.clone();
+            async move {
+                app.oneshot(request.body(Body::empty()).unwrap())
+                    .await
+                    .unwrap()
+                    .status()
+                    .as_u16()
+            }
+        };
+
+        assert_eq!(archive(None).await, 401);
+        assert_eq!(archive(Some("wrong-key")).await, 401);
+        // Past the guard, the unknown intent is the only problem
+        assert_eq!(archive(Some("test-admin-key")).await, 404);
+    }
+}
@@ -1,10 +1,16 @@
 use std::sync::Arc;
 
 use axum::extract::FromRef;
-use naisu_core::{Intent, IntentStatus};
+use naisu_core::addr::{normalize_evm_address, normalize_sui_address};
+use naisu_core::{BridgeFailure, Direction, Intent, IntentStatus};
+use naisu_sui::{CctpPackages, SuiClient};
 use std::collections::HashMap;
-use tokio::sync::RwLock;
+use tokio::sync::{RwLock, RwLockWriteGuard};
 
+use crate::common::outbox::{Outbox, SideEffect};
+use crate::common::price::{CoinGeckoOracle, PriceOracle, COINGECKO_API_URL};
+use crate::common::quote::QuoteStore;
+use crate::common::transitions::TransitionLog;
 use crate::config::Config;
 
 /// A single solver bid persisted in memory
@@ -18,6 +24,16 @@ pub struct SolverBidEntry {
     pub timestamp: u64, // unix millis
 }
 
+/// An intent change whose side effects could not be written to the outbox.
+/// The change was rolled back.
+#[derive(Debug, thiserror::Error)]
+#[error("could not save the side effects of intent {intent_id}: {source}")]
+pub struct EffectsNotSaved {
+    pub intent_id: String,
+    #[source]
+    pub source: std::io::Error,
+}
+
 /// Application state shared across all handlers
 #[derive(Clone, FromRef)]
 pub struct AppState {
@@ -25,20 +41,70 @@ pub struct AppState {
     pub intents: Arc<RwLock<HashMap<String, Intent>>>,
     pub bids: Arc<RwLock<HashMap<String, Vec<SolverBidEntry>>>>,
     pub network: Arc<RwLock<String>>,
+    /// Side effects awaiting delivery, see `common::outbox`
+    pub outbox: Arc<RwLock<Outbox>>,
+    /// Recent status changes per intent, see `common::transitions`
+    pub transitions: Arc<RwLock<TransitionLog>>,
+    /// Recently issued bridge quotes, see `common::quote`
+    pub quotes: Arc<RwLock<QuoteStore>>,
+    /// USD prices, used to value non-USDC deposits
+    pub prices: Arc<dyn PriceOracle>,
+    /// Shared client for outbound RPC calls, with the configured timeouts
+    pub http: reqwest::Client,
 }
 
 impl AppState {
     pub fn new() -> Self {
-        let config = Arc::new(Config::from_env());
+        Self::with_config(Config::from_env().expect("invalid configuration"))
+    }
 
+    /// Build state around an explicit config (used by tests and embedders)
+    pub fn with_config(config: Config) -> Self {
+        let network = config.network.as_str().to_string();
+        let outbox = Outbox::new(config.intents.outbox_max_attempts);
+        let transitions = TransitionLog::new(
+            config.intents.event_buffer_size,
+            config.intents.event_retention_secs,
+        );
+        let quotes = QuoteStore::new(config.bridge.quote_ttl_secs);
+        let http = config.bridge.http_timeouts.build_client();
         Self {
-            config,
+            config: Arc::new(config),
             intents: Arc::new(RwLock::new(HashMap::new())),
             bids: Arc::new(RwLock::new(HashMap::new())),
-            network: Arc::new(RwLock::new("testnet".to_string())),
+            network: Arc::new(RwLock::new(network)),
+            outbox: Arc::new(RwLock::new(outbox)),
+            transitions: Arc::new(RwLock::new(transitions)),
+            quotes: Arc::new(RwLock::new(quotes)),
+            prices: Arc::new(CoinGeckoOracle::new(COINGECKO_API_URL)),
+            http,
         }
     }
 
+    /// Replace the in-memory outbox (`main` uses one opened on a file)
+    pub fn with_outbox(mut self, outbox: Outbox) -> Self {
+        self.outbox = Arc::new(RwLock::new(outbox));
+        self
+    }
+
+    /// Replace the price source (tests use a `StaticPriceOracle`)
+    pub fn with_price_oracle(mut self, oracle: impl PriceOracle + 'static) -> Self {
+        self.prices = Arc::new(oracle);
+        self
+    }
+
+    /// Client for the configured Sui fullnode
+    pub fn sui_client(&self) -> SuiClient {
+        let network = self.config.network;
+        SuiClient::new(naisu_sui::SuiConfig {
+            network,
+            rpc_url: self.config.sui.rpc_url.clone(),
+            usdc_coin_type: network.usdc_coin_type().to_string(),
+            cctp: CctpPackages::for_network(network),
+            ..naisu_sui::SuiConfig::testnet()
+        })
+    }
+
     /// Get current network
     pub fn network(&self) -> String {
         self.network
@@ -72,42 +138,381 @@ impl AppState {
         intents.get(id).cloned()
     }
 
-    /// Insert or update an intent
-    pub async fn upsert_intent(&self, intent: Intent) {
+    /// Look up several intents under one read lock. Returns the ones found and
+    /// the ids that weren't.
+    pub async fn get_intents<'a>(
+        &self,
+        ids: impl IntoIterator<Item = &'a str>,
+    ) -> (Vec<Intent>, Vec<&'a str>) {
+        let intents = self.intents.read().await;
+        let mut found = Vec::new();
+        let mut missing = Vec::new();
+        for id in ids {
+            match intents.get(id) {
+                Some(intent) => found.push(intent.clone()),
+                None => missing.push(id),
+            }
+        }
+        (found, missing)
+    }
+
+    /// Insert or replace an intent wholesale (use `update_intent` to modify one)
+    pub async fn upsert_intent(&self, mut intent: Intent) -> Result<(), EffectsNotSaved> {
+        start_bridge_clock_on_burn(&mut intent);
+        let outbox = self.outbox.write().await;
+        let mut intents = self.intents.write().await;
+        let previous = intents.get(&intent.id).cloned();
+        if let Some(before) = &previous {
+            // A copy from elsewhere may not carry the history recorded here
+            if intent.status_history.len() < before.status_history.len() {
+                intent.status_history = before.status_history.clone();
+            }
+        }
+        intent.record_status();
+        let id = intent.id.clone();
+        intents.insert(id.clone(), intent);
+        self.commit(outbox, intents, &id, previous, Vec::new())
+            .await
+    }
+
+    /// Insert an intent unless one with the same ID already exists.
+    /// Returns whether it was inserted.
+    pub async fn insert_intent_if_absent(&self, mut intent: Intent) -> bool {
+        start_bridge_clock_on_burn(&mut intent);
         let mut intents = self.intents.write().await;
+        if intents.contains_key(&intent.id) {
+            return false;
+        }
         intents.insert(intent.id.clone(), intent);
+        true
+    }
+
+    /// Mutate an intent in place under a single write lock, so concurrent
+    /// updates can't overwrite each other with stale copies.
+    /// Returns `Ok(None)` if the intent doesn't exist.
+    pub async fn update_intent<F, R>(&self, id: &str, f: F) -> Result<Option<R>, EffectsNotSaved>
+    where
+        F: FnOnce(&mut Intent) -> R,
+    {
+        self.update_intent_with_effects(id, |intent| (f(intent), Vec::new()))
+            .await
+    }
+
+    /// Like `update_intent`, but `f` also returns side effects (dedup key,
+    /// effect) to deliver through the outbox along with the change
+    pub async fn update_intent_with_effects<F, R>(
+        &self,
+        id: &str,
+        f: F,
+    ) -> Result<Option<R>, EffectsNotSaved>
+    where
+        F: FnOnce(&mut Intent) -> (R, Vec<(String, SideEffect)>),
+    {
+        let outbox = self.outbox.write().await;
+        let mut intents = self.intents.write().await;
+        let Some(intent) = intents.get_mut(id) else {
+            return Ok(None);
+        };
+        let previous = intent.clone();
+        let (result, effects) = f(intent);
+        start_bridge_clock_on_burn(intent);
+        intent.record_status();
+        self.commit(outbox, intents, id, Some(previous), effects)
+            .await?;
+        Ok(Some(result))
+    }
+
+    /// Finish a change to intent `id` made under both write locks (`previous`
+    /// is the intent before it, `None` for a new one). `effects` and the
+    /// status webhook are staged in the outbox, and the file is written after
+    /// the intents lock is released; if that fails, the change is rolled back.
+    /// The outbox lock is held throughout, so changes commit one at a time
+    /// and their transitions are numbered in the order they were applied.
+    async fn commit(
+        &self,
+        mut outbox: RwLockWriteGuard<'_, Outbox>,
+        mut intents: RwLockWriteGuard<'_, HashMap<String, Intent>>,
+        id: &str,
+        previous: Option<Intent>,
+        mut effects: Vec<(String, SideEffect)>,
+    ) -> Result<(), EffectsNotSaved> {
+        let intent = &intents[id];
+        let before = previous.as_ref().map(|p| p.status);
+        let transition = before
+            .filter(|before| *before != intent.status)
+            .map(|before| (before, intent.status, intent.updated_at));
+        if transition.is_some() {
+            effects.extend(self.status_webhook(intent));
+        }
+
+        let staged = outbox.stage(effects);
+        if !staged.is_empty() {
+            let committed = intent.clone();
+            drop(intents);
+            if let Err(source) = outbox.save().await {
+                outbox.unstage(&staged);
+                intents = self.intents.write().await;
+                // Undo only our own change, not one made since
+                if intents.get(id) == Some(&committed) {
+                    match previous {
+                        Some(previous) => intents.insert(id.to_string(), previous),
+                        None => intents.remove(id),
+                    };
+                }
+                return Err(EffectsNotSaved {
+                    intent_id: id.to_string(),
+                    source,
+                });
+            }
+        }
+
+        if let Some((before, after, at)) = transition {
+            self.transitions.write().await.record(id, before, after, at);
+        }
+        Ok(())
+    }
+
+    /// The webhook announcing that `intent` reached a terminal status, if
+    /// `INTENT_WEBHOOK_URL` is set
+    fn status_webhook(&self, intent: &Intent) -> Option<(String, SideEffect)> {
+        let url = self.config.intents.webhook_url.clone()?;
+        if !intent.status.is_terminal() {
+            return None;
+        }
+        let effect = SideEffect::Webhook {
+            url,
+            payload: serde_json::json!({
+                "intent_id": intent.id,
+                "status": intent.status,
+                "error_message": intent.error_message,
+                "updated_at": intent.updated_at,
+            }),
+        };
+        Some((format!("{}:{}", intent.id, intent.status.as_str()), effect))
+    }
+
+    /// Update intent status. `Ok(false)` if the intent doesn't exist.
+    pub async fn update_intent_status(
+        &self,
+        id: &str,
+        status: IntentStatus,
+    ) -> Result<bool, EffectsNotSaved> {
+        Ok(self
+            .update_intent(id, |intent| intent.set_status(status))
+            .await?
+            .is_some())
+    }
+
+    /// Archive a terminal intent. `None` if it doesn't exist, `Err` with the
+    /// current status if it is still in flight.
+    pub async fn archive_intent(
+        &self,
+        id: &str,
+    ) -> Result<Option<Result<Intent, IntentStatus>>, EffectsNotSaved> {
+        self.update_intent(id, |intent| {
+            if !intent.status.is_terminal() {
+                return Err(intent.status);
+            }
+            if !intent.archived {
+                intent.archive();
+            }
+            Ok(intent.clone())
+        })
+        .await
     }
 
-    /// Update intent status
-    pub async fn update_intent_status(&self, id: &str, status: IntentStatus) -> bool {
+    /// Archive every terminal intent last updated before `cutoff` (unix secs).
+    /// Returns how many were archived.
+    pub async fn archive_terminal_before(&self, cutoff: i64) -> usize {
         let mut intents = self.intents.write().await;
-        if let Some(intent) = intents.get_mut(id) {
-            intent.set_status(status);
-            true
-        } else {
-            false
+        let mut archived = 0;
+        for intent in intents
+            .values_mut()
+            .filter(|i| !i.archived && i.status.is_terminal() && i.updated_at < cutoff)
+        {
+            intent.archive();
+            archived += 1;
         }
+        archived
     }
 
-    /// List all intents
+    /// Flag every intent still bridging past its deadline (unix secs) as
+    /// needing diagnosis. They stay in flight: a late attestation can still
+    /// complete them. Returns how many were flagged.
+    pub async fn flag_overdue(&self, now: i64) -> usize {
+        let mut intents = self.intents.write().await;
+        let mut flagged = 0;
+        for intent in intents.values_mut().filter(|i| i.is_overdue(now)) {
+            intent.bridge_failure = Some(BridgeFailure::DeadlinePassed);
+            intent.updated_at = now;
+            flagged += 1;
+        }
+        flagged
+    }
+
+    /// List all intents (including archived)
     pub async fn list_intents(&self) -> Vec<Intent> {
         let intents = self.intents.read().await;
         intents.values().cloned().collect()
     }
 
-    /// List intents by creator address
+    /// List intents by creator address, in any case or padding. Stored
+    /// addresses are canonical (see `naisu_core::addr`), so only the query
+    /// is normalized.
     pub async fn list_intents_by_creator(&self, creator: &str) -> Vec<Intent> {
+        let evm = normalize_evm_address(creator).ok();
+        let sui = normalize_sui_address(creator).ok();
         let intents = self.intents.read().await;
         intents
             .values()
-            .filter(|i| i.source_address.to_lowercase() == creator.to_lowercase())
+            .filter(|i| {
+                let query = match i.direction {
+                    Direction::EvmToSui => &evm,
+                    Direction::SuiToEvm | Direction::SuiWithdraw => &sui,
+                };
+                query.as_deref() == Some(i.source_address.as_str())
+            })
             .cloned()
             .collect()
     }
 }
 
+/// Start the bridge deadline of an intent whose burn was just recorded
+fn start_bridge_clock_on_burn(intent: &mut Intent) {
+    if intent.bridge_tx_hash.is_some() {
+        intent.start_bridge_clock(intent.updated_at);
+    }
+}
+
 impl Default for AppState {
     fn default() -> Self {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    use naisu_core::{EvmChain, SuiNetwork};
+
+    #[tokio::test]
+    async fn test_concurrent_updates_are_not_lost() {
+        let state =
+            AppState::with_config(Config::load(SuiNetwork::Testnet, &HashMap::new()).unwrap());
+        state
+            .upsert_intent(Intent::new_sui_to_evm(
+                "intent-1".to_string(),
+                "0x2".to_string(),
+                "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed".to_string(),
+                EvmChain::BaseSepolia,
+                "usdc".to_string(),
+                "1000000".to_string(),
+            ))
+            .await
+            .unwrap();
+
+        let bridge = {
+            let state = state.clone();
+            tokio::spawn(async move {
+                state
+                    .update_intent("intent-1", |intent| {
+                        intent.bridge_tx_hash = Some("0xburn".to_string());
+                        intent.set_status(IntentStatus::Bridging);
+                    })
+                    .await
+            })
+        };
+        let dest = {
+            let state = state.clone();
+            tokio::spawn(async move {
+                state
+                    .update_intent("intent-1", |intent| {
+                        intent.dest_tx_hash = Some("0xmint".to_string());
+                    })
+                    .await
+            })
+        };
+        assert!(bridge.await.unwrap().unwrap().is_some());
+        assert!(dest.await.unwrap().unwrap().is_some());
+
+        let intent = state.get_intent("intent-1").await.unwrap();
+        assert_eq!(intent.status, IntentStatus::Bridging);
+        assert_eq!(intent.bridge_tx_hash.as_deref(), Some("0xburn"));
+        assert_eq!(intent.dest_tx_hash.as_deref(), Some("0xmint"));
+
+        assert!(state
+            .update_intent("missing", |_| ())
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_creator_lookup_ignores_address_spelling() {
+        let state =
+            AppState::with_config(Config::load(SuiNetwork::Testnet, &HashMap::new()).unwrap());
+        // A hook event spelling the creator in lowercase
+        let evm_id = format!("0x{:064x}", 9);
+        let mut event = naisu_core::IntentCreatedEvent::try_from(
+            &naisu_core::testing::intent_created_log(&evm_id),
+        )
+        .unwrap();
+        event.user = event.user.to_lowercase();
+        state
+            .upsert_intent(Intent::from((event, EvmChain::BaseSepolia)))
+            .await
+            .unwrap();
+        state
+            .upsert_intent(Intent::new_sui_to_evm(
+                "sui".to_string(),
+                format!("0x{:0>64}", "ab"),
+                "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed".to_string(),
+                EvmChain::BaseSepolia,
+                "usdc".to_string(),
+                "1000000".to_string(),
+            ))
+            .await
+            .unwrap();
+
+        let ids = |intents: Vec<Intent>| intents.into_iter().map(|i| i.id).collect::<Vec<_>>();
+        for evm in [
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            "0X5AAEB6053F3E94C9B9A09F33669435E7EF1BEAED",
+        ] {
+            assert_eq!(
+                ids(state.list_intents_by_creator(evm).await),
+                [evm_id.as_str()]
+            );
+        }
+        assert_eq!(ids(state.list_intents_by_creator("0xAB").await), ["sui"]);
+        assert!(state
+            .list_intents_by_creator("not-an-address")
+            .await
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_upsert_records_status_changes() {
+        let state =
+            AppState::with_config(Config::load(SuiNetwork::Testnet, &HashMap::new()).unwrap());
+        let mut intent = Intent::new_sui_to_evm(
+            "intent-1".to_string(),
+            "0x2".to_string(),
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed".to_string(),
+            EvmChain::BaseSepolia,
+            "usdc".to_string(),
+            "1000000".to_string(),
+        );
+        state.upsert_intent(intent.clone()).await.unwrap();
+        state.upsert_intent(intent.clone()).await.unwrap();
+        intent.set_status(IntentStatus::Bridging);
+        state.upsert_intent(intent).await.unwrap();
+
+        let history = state.transitions.read().await.since("intent-1", None);
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].from, IntentStatus::Pending);
+        assert_eq!(history[0].to, IntentStatus::Bridging);
+    }
+}
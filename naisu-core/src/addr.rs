@@ -0,0 +1,164 @@
+//! Address normalization
+//!
+//! Intents store addresses in one canonical form so equality and display are
+//! consistent: EIP-55 checksummed for EVM, lowercase 32-byte hex for Sui.
+
+use sha3::{Digest, Keccak256};
+
+use crate::error::NaisuError;
+
+/// Address validation failure
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum AddrError {
+    #[error("Address is not valid hex: {0}")]
+    InvalidHex(String),
+
+    #[error("Address must be 20 or 32 bytes, got {0} hex chars")]
+    InvalidLength(usize),
+
+    #[error("32-byte value is not a left-padded EVM address: {0}")]
+    NotPaddedEvmAddress(String),
+}
+
+impl From<AddrError> for NaisuError {
+    fn from(err: AddrError) -> Self {
+        NaisuError::InvalidAddress(err.to_string())
+    }
+}
+
+/// Normalize an EVM address to its EIP-55 checksummed form
+pub fn normalize_evm_address(addr: &str) -> Result<String, NaisuError> {
+    let hex = strip_hex_prefix(addr);
+    if hex.len() != 40 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(NaisuError::InvalidAddress(addr.to_string()));
+    }
+
+    let lower = hex.to_ascii_lowercase();
+    let hash = Keccak256::digest(lower.as_bytes());
+
+    let checksummed: String = lower
+        .chars()
+        .enumerate()
+        .map(|(i, c)| {
+            let nibble = (hash[i / 2] >> (if i % 2 == 0 { 4 } else { 0 })) & 0x0f;
+            if c.is_ascii_alphabetic() && nibble >= 8 {
+                c.to_ascii_uppercase()
+            } else {
+                c
+            }
+        })
+        .collect();
+
+    Ok(format!("0x{}", checksummed))
+}
+
+/// Normalize a Sui address to lowercase, 0x-prefixed, zero-padded 32 bytes
+pub fn normalize_sui_address(addr: &str) -> Result<String, NaisuError> {
+    let hex = strip_hex_prefix(addr);
+    if hex.is_empty() || hex.len() > 64 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(NaisuError::InvalidAddress(addr.to_string()));
+    }
+
+    Ok(format!("0x{:0>64}", hex.to_ascii_lowercase()))
+}
+
+/// Left-pad a 20-byte EVM address (or pass through an already padded
+/// 32-byte one) to the lowercase 0x-prefixed bytes32 form used for CCTP mint
+/// recipients
+pub fn to_sui_bytes32(addr: &str) -> Result<String, AddrError> {
+    let hex = strip_hex_prefix(addr);
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(AddrError::InvalidHex(addr.to_string()));
+    }
+    match hex.len() {
+        40 => Ok(format!("0x{:0>64}", hex.to_ascii_lowercase())),
+        // The top 12 bytes must be zero, or the mint goes to no EVM address
+        64 if hex[..24].bytes().all(|b| b == b'0') => Ok(format!("0x{}", hex.to_ascii_lowercase())),
+        64 => Err(AddrError::NotPaddedEvmAddress(addr.to_string())),
+        len => Err(AddrError::InvalidLength(len)),
+    }
+}
+
+fn strip_hex_prefix(addr: &str) -> &str {
+    let addr = addr.trim();
+    addr.strip_prefix("0x")
+        .or_else(|| addr.strip_prefix("0X"))
+        .unwrap_or(addr)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_evm_checksum() {
+        // Test vectors from EIP-55
+        for addr in [
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+            "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+        ] {
+            assert_eq!(normalize_evm_address(addr).unwrap(), addr);
+            assert_eq!(normalize_evm_address(&addr.to_lowercase()).unwrap(), addr);
+        }
+    }
+
+    #[test]
+    fn test_evm_rejects_invalid() {
+        assert!(normalize_evm_address("0x1234").is_err());
+        assert!(normalize_evm_address("0xZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZ").is_err());
+    }
+
+    #[test]
+    fn test_bytes32_from_checksummed() {
+        assert_eq!(
+            to_sui_bytes32(" 0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed ").unwrap(),
+            "0x0000000000000000000000005aaeb6053f3e94c9b9a09f33669435e7ef1beaed"
+        );
+    }
+
+    #[test]
+    fn test_bytes32_already_padded() {
+        let padded = "0x0000000000000000000000005aaeb6053f3e94c9b9a09f33669435e7ef1beaed";
+        assert_eq!(to_sui_bytes32(padded).unwrap(), padded);
+        assert_eq!(to_sui_bytes32(&padded.to_uppercase()[2..]).unwrap(), padded);
+    }
+
+    #[test]
+    fn test_bytes32_rejects_bad_input() {
+        assert_eq!(
+            to_sui_bytes32(&format!("0x{}", "a".repeat(66))),
+            Err(AddrError::InvalidLength(66))
+        );
+        assert_eq!(to_sui_bytes32("0x1234"), Err(AddrError::InvalidLength(4)));
+        // 32 bytes with a nonzero high part is not an EVM address
+        let dirty = format!(
+            "0x{}5aaeb6053f3e94c9b9a09f33669435e7ef1beaed",
+            "0".repeat(23) + "1"
+        );
+        assert!(matches!(
+            to_sui_bytes32(&dirty),
+            Err(AddrError::NotPaddedEvmAddress(_))
+        ));
+        assert!(matches!(
+            to_sui_bytes32("0xZZaeb6053F3E94C9b9A09f33669435E7Ef1BeAed"),
+            Err(AddrError::InvalidHex(_))
+        ));
+    }
+
+    #[test]
+    fn test_sui_padding() {
+        assert_eq!(
+            normalize_sui_address("0x2").unwrap(),
+            "0x0000000000000000000000000000000000000000000000000000000000000002"
+        );
+        assert_eq!(
+            normalize_sui_address("0xABC").unwrap(),
+            normalize_sui_address(
+                "0x0000000000000000000000000000000000000000000000000000000000000abc"
+            )
+            .unwrap()
+        );
+        assert!(normalize_sui_address(&format!("0x{}", "1".repeat(65))).is_err());
+    }
+}
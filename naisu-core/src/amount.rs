@@ -0,0 +1,86 @@
+//! Raw token amounts
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Token amount in the token's smallest unit (no decimals applied).
+/// Serialized as a decimal string so JSON consumers don't lose precision.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TokenAmount(u128);
+
+impl TokenAmount {
+    pub const ZERO: TokenAmount = TokenAmount(0);
+
+    pub fn from_raw(raw: u128) -> Self {
+        Self(raw)
+    }
+
+    pub fn raw(&self) -> u128 {
+        self.0
+    }
+
+    /// Decode an ABI `uint256` word; fails if the value doesn't fit in 128 bits
+    pub fn from_be_word(word: &[u8; 32]) -> Option<Self> {
+        if word[..16].iter().any(|b| *b != 0) {
+            return None;
+        }
+        let mut low = [0u8; 16];
+        low.copy_from_slice(&word[16..]);
+        Some(Self(u128::from_be_bytes(low)))
+    }
+}
+
+impl fmt::Display for TokenAmount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for TokenAmount {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.trim()
+            .parse::<u128>()
+            .map(TokenAmount)
+            .map_err(|_| format!("Invalid token amount: {}", s))
+    }
+}
+
+impl Serialize for TokenAmount {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for TokenAmount {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_display() {
+        let amount: TokenAmount = "1000000".parse().unwrap();
+        assert_eq!(amount.raw(), 1_000_000);
+        assert_eq!(amount.to_string(), "1000000");
+        assert!("-1".parse::<TokenAmount>().is_err());
+        assert!("1.5".parse::<TokenAmount>().is_err());
+    }
+
+    #[test]
+    fn test_from_be_word() {
+        let mut word = [0u8; 32];
+        word[31] = 42;
+        assert_eq!(TokenAmount::from_be_word(&word), Some(TokenAmount(42)));
+        word[0] = 1;
+        assert_eq!(TokenAmount::from_be_word(&word), None);
+    }
+}
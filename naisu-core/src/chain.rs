@@ -1,9 +1,12 @@
 //! Chain definitions for supported networks
 
+use std::time::Duration;
+
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 /// Supported EVM chains (source chains)
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum EvmChain {
     /// Ethereum Mainnet
@@ -21,6 +24,40 @@ pub enum EvmChain {
 }
 
 impl EvmChain {
+    /// Every supported EVM chain
+    pub const ALL: [EvmChain; 6] = [
+        EvmChain::Ethereum,
+        EvmChain::Base,
+        EvmChain::Arbitrum,
+        EvmChain::Optimism,
+        EvmChain::BaseSepolia,
+        EvmChain::Sepolia,
+    ];
+
+    /// Serialized key (matches the serde representation)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EvmChain::Ethereum => "ethereum",
+            EvmChain::Base => "base",
+            EvmChain::Arbitrum => "arbitrum",
+            EvmChain::Optimism => "optimism",
+            EvmChain::BaseSepolia => "basesepolia",
+            EvmChain::Sepolia => "sepolia",
+        }
+    }
+
+    /// Human-readable chain name
+    pub fn name(&self) -> &'static str {
+        match self {
+            EvmChain::Ethereum => "Ethereum",
+            EvmChain::Base => "Base",
+            EvmChain::Arbitrum => "Arbitrum One",
+            EvmChain::Optimism => "Optimism",
+            EvmChain::BaseSepolia => "Base Sepolia",
+            EvmChain::Sepolia => "Sepolia",
+        }
+    }
+
     /// Get the chain ID for this EVM chain
     pub fn chain_id(&self) -> u64 {
         match self {
@@ -33,24 +70,188 @@ impl EvmChain {
         }
     }
 
-    /// Get Li.Fi chain key
+    /// Get Li.Fi chain key (Li.Fi has no short keys for testnets, so those
+    /// use their numeric chain id)
     pub fn lifi_chain_key(&self) -> &'static str {
         match self {
             EvmChain::Ethereum => "ETH",
             EvmChain::Base => "BAS",
             EvmChain::Arbitrum => "ARB",
             EvmChain::Optimism => "OPT",
-            EvmChain::BaseSepolia => "BAS", // Li.Fi may use same key
-            EvmChain::Sepolia => "ETH",
+            EvmChain::BaseSepolia => "84532",
+            EvmChain::Sepolia => "11155111",
         }
     }
 
+    /// Chain identifier to send to Li.Fi; numeric ids are unambiguous
+    /// across mainnets and testnets, so prefer this over `lifi_chain_key`
+    pub fn lifi_chain_id(&self) -> u64 {
+        self.chain_id()
+    }
+
+    /// Resolve a Li.Fi (numeric) chain id
+    pub fn from_lifi_chain_id(id: u64) -> Option<EvmChain> {
+        EvmChain::ALL
+            .into_iter()
+            .find(|chain| chain.lifi_chain_id() == id)
+    }
+
     /// Check if this is a testnet
     pub fn is_testnet(&self) -> bool {
         matches!(self, EvmChain::BaseSepolia | EvmChain::Sepolia)
     }
+
+    /// Circle CCTP domain (testnets share their mainnet's domain)
+    pub fn cctp_domain(&self) -> Option<u32> {
+        match self {
+            EvmChain::Ethereum | EvmChain::Sepolia => Some(0),
+            EvmChain::Optimism => Some(2),
+            EvmChain::Arbitrum => Some(3),
+            EvmChain::Base | EvmChain::BaseSepolia => Some(6),
+        }
+    }
+
+    /// Public RPC endpoint used when none is configured
+    pub fn default_rpc_url(&self) -> &'static str {
+        match self {
+            EvmChain::Ethereum => "https://ethereum-rpc.publicnode.com",
+            EvmChain::Base => "https://mainnet.base.org",
+            EvmChain::Arbitrum => "https://arb1.arbitrum.io/rpc",
+            EvmChain::Optimism => "https://mainnet.optimism.io",
+            EvmChain::BaseSepolia => "https://sepolia.base.org",
+            EvmChain::Sepolia => "https://ethereum-sepolia-rpc.publicnode.com",
+        }
+    }
+
+    /// Circle-issued USDC contract address
+    pub fn usdc_address(&self) -> &'static str {
+        match self {
+            EvmChain::Ethereum => "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48",
+            EvmChain::Base => "0x833589fCD6eDb6E08f4c7C32D4f71b54bdA02913",
+            EvmChain::Arbitrum => "0xaf88d065e77c8cC2239327C5EDb3A432268e5831",
+            EvmChain::Optimism => "0x0b2C639c533813f4Aa9D7837CAf62653d097Ff85",
+            EvmChain::BaseSepolia => "0x036CbD53842c5426634e7929541eC2318f3dCF7e",
+            EvmChain::Sepolia => "0x1c7D4B196Cb0C7B01d743Fbc6116a902379C7238",
+        }
+    }
+
+    /// Canonical wrapped-ETH contract (what native ETH is wrapped into)
+    pub fn weth_address(&self) -> &'static str {
+        match self {
+            EvmChain::Ethereum => "0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2",
+            EvmChain::Base | EvmChain::Optimism | EvmChain::BaseSepolia => {
+                "0x4200000000000000000000000000000000000006"
+            }
+            EvmChain::Arbitrum => "0x82aF49447D8a07e3bd95BD0d56f35241523fBab1",
+            EvmChain::Sepolia => "0xfFf9976782d46CC05630D1f6eBAb18b2324d6B14",
+        }
+    }
+
+    /// ERC-20s accepted as input and output tokens on this chain
+    pub fn known_tokens(&self) -> Vec<KnownToken> {
+        let token = |symbol, address, decimals| KnownToken {
+            symbol,
+            address,
+            decimals,
+        };
+        let mut tokens = vec![
+            token("USDC", self.usdc_address(), 6),
+            token("WETH", self.weth_address(), 18),
+        ];
+        match self {
+            EvmChain::Ethereum => tokens.extend([
+                token("USDT", "0xdAC17F958D2ee523a2206206994597C13D831ec7", 6),
+                token("DAI", "0x6B175474E89094C44Da98b954EedeAC495271d0F", 18),
+            ]),
+            EvmChain::Base => tokens.push(token(
+                "DAI",
+                "0x50c5725949A6F0c72E6C4a641F24049A917DB0Cb",
+                18,
+            )),
+            EvmChain::Arbitrum => tokens.extend([
+                token("USDT", "0xFd086bC7CD5C481DCC9C85ebE478A1C0b69FCbb9", 6),
+                token("DAI", "0xDA10009cBd5D07dd0CeCc66161FC93D7c9000da1", 18),
+            ]),
+            EvmChain::Optimism => tokens.extend([
+                token("USDT", "0x94b008aA00579c1307B0EF2c499aD98a8ce58e58", 6),
+                token("DAI", "0xDA10009cBd5D07dd0CeCc66161FC93D7c9000da1", 18),
+            ]),
+            EvmChain::BaseSepolia | EvmChain::Sepolia => {}
+        }
+        tokens
+    }
+
+    /// The known ERC-20 at `address` (any case) on this chain
+    pub fn known_token(&self, address: &str) -> Option<KnownToken> {
+        self.known_tokens()
+            .into_iter()
+            .find(|t| t.address.eq_ignore_ascii_case(address))
+    }
+
+    /// Native gas token symbol
+    pub fn native_symbol(&self) -> &'static str {
+        "ETH"
+    }
+
+    /// Native gas token decimals
+    pub fn native_decimals(&self) -> u8 {
+        18
+    }
+
+    /// Block explorer base URL
+    pub fn explorer_url(&self) -> &'static str {
+        match self {
+            EvmChain::Ethereum => "https://etherscan.io",
+            EvmChain::Base => "https://basescan.org",
+            EvmChain::Arbitrum => "https://arbiscan.io",
+            EvmChain::Optimism => "https://optimistic.etherscan.io",
+            EvmChain::BaseSepolia => "https://sepolia.basescan.org",
+            EvmChain::Sepolia => "https://sepolia.etherscan.io",
+        }
+    }
 }
 
+/// An ERC-20 the service routes through
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KnownToken {
+    pub symbol: &'static str,
+    /// EIP-55 checksummed address
+    pub address: &'static str,
+    pub decimals: u8,
+}
+
+/// Token address standing for the native gas token (ETH) in requests
+pub const NATIVE_ETH_SENTINEL: &str = "0xEeeeeEeeeEeEeeEeEeEeeEEEeeeeEeeeeeeeEEeE";
+
+/// Naisu's Uniswap V4 intent hook deployed on Base Sepolia
+pub const BASE_SEPOLIA_HOOK_ADDRESS: &str = "0xf0093fcf07aa10de35b78d1f33c60439d11bc0c0";
+
+/// Whether an EVM `token` means native ETH: the zero address or
+/// [`NATIVE_ETH_SENTINEL`]
+pub fn is_native_token(token: &str) -> bool {
+    token.eq_ignore_ascii_case(NATIVE_ETH_SENTINEL)
+        || token.eq_ignore_ascii_case("0x0000000000000000000000000000000000000000")
+}
+
+/// How long Circle may take to attest a burn made on CCTP `domain`: burns on
+/// Ethereum wait for L1 finality, the rest are attested within minutes
+pub fn attestation_budget(domain: u32) -> Duration {
+    if EvmChain::Ethereum.cctp_domain() == Some(domain) {
+        Duration::from_secs(15 * 60)
+    } else {
+        Duration::from_secs(5 * 60)
+    }
+}
+
+/// Attestation polls, `interval` apart, that fit in `budget` (at least one)
+pub fn attestation_attempts(budget: Duration, interval: Duration) -> u32 {
+    let attempts = budget.as_millis().div_ceil(interval.as_millis().max(1));
+    attempts.clamp(1, u32::MAX as u128) as u32
+}
+
+/// Native SUI coin type (the same on every network)
+pub const SUI_COIN_TYPE: &str = "0x2::sui::SUI";
+
 /// Sui network variants
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -69,6 +270,77 @@ impl SuiNetwork {
             SuiNetwork::Devnet => "https://fullnode.devnet.sui.io:443",
         }
     }
+
+    /// Serialized key (matches the serde representation)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SuiNetwork::Mainnet => "mainnet",
+            SuiNetwork::Testnet => "testnet",
+            SuiNetwork::Devnet => "devnet",
+        }
+    }
+
+    /// Human-readable network name
+    pub fn name(&self) -> &'static str {
+        match self {
+            SuiNetwork::Mainnet => "Sui Mainnet",
+            SuiNetwork::Testnet => "Sui Testnet",
+            SuiNetwork::Devnet => "Sui Devnet",
+        }
+    }
+
+    /// Check if this is a test network
+    pub fn is_testnet(&self) -> bool {
+        !matches!(self, SuiNetwork::Mainnet)
+    }
+
+    /// Circle's native USDC coin type (devnet has none; it uses testnet's)
+    /// Source: https://developers.circle.com/stablecoins/usdc-contract-addresses
+    pub const fn usdc_coin_type(&self) -> &'static str {
+        match self {
+            SuiNetwork::Mainnet => {
+                "0xdba34672e30cb065b1f93e3ab55318768fd6fef66c15942c9f7cb846e2f900e7::usdc::USDC"
+            }
+            SuiNetwork::Testnet | SuiNetwork::Devnet => {
+                "0xa1ec7fc00a6f40db9693ad1415d0c193ad3906494428cf252621037bd7117e29::usdc::USDC"
+            }
+        }
+    }
+
+    /// Circle CCTP domain for Sui (the same on every network)
+    pub fn cctp_domain(&self) -> u32 {
+        8
+    }
+
+    /// EVM chain paired with this network by default
+    pub fn default_evm_chain(&self) -> EvmChain {
+        match self {
+            SuiNetwork::Mainnet => EvmChain::Base,
+            SuiNetwork::Testnet | SuiNetwork::Devnet => EvmChain::BaseSepolia,
+        }
+    }
+
+    /// Block explorer base URL
+    pub fn explorer_url(&self) -> &'static str {
+        match self {
+            SuiNetwork::Mainnet => "https://suiscan.xyz/mainnet",
+            SuiNetwork::Testnet => "https://suiscan.xyz/testnet",
+            SuiNetwork::Devnet => "https://suiscan.xyz/devnet",
+        }
+    }
+}
+
+impl std::str::FromStr for SuiNetwork {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "mainnet" => Ok(SuiNetwork::Mainnet),
+            "testnet" => Ok(SuiNetwork::Testnet),
+            "devnet" => Ok(SuiNetwork::Devnet),
+            _ => Err(format!("Unknown Sui network: {}", s)),
+        }
+    }
 }
 
 /// Token information
@@ -102,3 +374,82 @@ pub mod tokens {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lifi_identifiers_are_distinct() {
+        assert_ne!(
+            EvmChain::Base.lifi_chain_key(),
+            EvmChain::BaseSepolia.lifi_chain_key()
+        );
+        assert_ne!(
+            EvmChain::Base.lifi_chain_id(),
+            EvmChain::BaseSepolia.lifi_chain_id()
+        );
+
+        for chain in EvmChain::ALL {
+            assert_eq!(
+                EvmChain::from_lifi_chain_id(chain.lifi_chain_id()),
+                Some(chain)
+            );
+            assert_eq!(
+                EvmChain::ALL
+                    .iter()
+                    .filter(|other| other.lifi_chain_key() == chain.lifi_chain_key())
+                    .count(),
+                1
+            );
+        }
+        assert_eq!(EvmChain::from_lifi_chain_id(56), None);
+    }
+
+    #[test]
+    fn test_attestation_attempts_cover_the_budget() {
+        let ethereum = attestation_budget(0);
+        let base = attestation_budget(EvmChain::Base.cctp_domain().unwrap());
+        assert!(ethereum > base);
+
+        let interval = Duration::from_secs(5);
+        assert_eq!(attestation_attempts(base, interval), 60);
+        assert_eq!(attestation_attempts(ethereum, interval), 180);
+        assert_eq!(
+            attestation_attempts(Duration::from_secs(7), interval),
+            2,
+            "a partial interval still gets a poll"
+        );
+        assert_eq!(attestation_attempts(Duration::ZERO, interval), 1);
+    }
+
+    #[test]
+    fn test_known_tokens_are_checksummed_and_unique() {
+        for chain in EvmChain::ALL {
+            let tokens = chain.known_tokens();
+            for token in &tokens {
+                assert_eq!(
+                    crate::addr::normalize_evm_address(token.address)
+                        .ok()
+                        .as_deref(),
+                    Some(token.address),
+                    "{} on {}",
+                    token.symbol,
+                    chain.name()
+                );
+                assert_eq!(
+                    chain.known_token(&token.address.to_lowercase()),
+                    Some(*token)
+                );
+            }
+            let mut addresses: Vec<_> = tokens.iter().map(|t| t.address).collect();
+            addresses.sort();
+            addresses.dedup();
+            assert_eq!(addresses.len(), tokens.len());
+        }
+        assert_eq!(
+            EvmChain::Base.known_token("0x0000000000000000000000000000000000000001"),
+            None
+        );
+    }
+}
@@ -17,9 +17,21 @@ pub enum NaisuError {
     #[error("Intent not found: {0}")]
     IntentNotFound(String),
 
+    #[error("Invalid address: {0}")]
+    InvalidAddress(String),
+
+    #[error("Malformed event log: {0}")]
+    MalformedLog(String),
+
     #[error("Invalid intent state: expected {expected}, got {actual}")]
     InvalidState { expected: String, actual: String },
 
+    #[error("Attestation not ready within its polling budget: {0}")]
+    AttestationTimeout(String),
+
+    #[error("Invalid allocation: {0}")]
+    InvalidAllocation(String),
+
     #[error("Configuration error: {0}")]
     Config(String),
 
@@ -0,0 +1,240 @@
+//! Chain events consumed by the agent and API
+//!
+//! Events are decoded from raw EVM logs (as returned by `eth_getLogs`) into
+//! typed fields up front, so malformed logs are rejected at the edge instead of
+//! being parsed (or defaulted) downstream.
+
+use serde::{Deserialize, Serialize};
+use sha3::{Digest, Keccak256};
+
+use crate::addr::{normalize_evm_address, normalize_sui_address};
+use crate::amount::TokenAmount;
+use crate::error::NaisuError;
+use crate::strategy::YieldStrategy;
+
+/// Solidity signature of the V4 Hook's `IntentCreated` event.
+/// `intentId` and `user` are indexed; the remaining fields are ABI-encoded in `data`.
+pub const INTENT_CREATED_SIGNATURE: &str =
+    "IntentCreated(bytes32,address,bytes32,address,uint256,uint256,uint8,uint256)";
+
+/// Number of 32-byte words `IntentCreated` encodes in `data`
+const INTENT_CREATED_DATA_WORDS: usize = 6;
+
+/// Raw EVM log as returned by `eth_getLogs`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EvmLog {
+    pub address: String,
+    pub topics: Vec<String>,
+    pub data: String,
+    #[serde(default)]
+    pub transaction_hash: Option<String>,
+    #[serde(default)]
+    pub block_number: Option<String>,
+}
+
+/// Intent event emitted by V4 Hook (EVM side, EvmToSui trigger)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IntentCreatedEvent {
+    /// bytes32 intent id, 0x-prefixed lowercase hex
+    pub intent_id: String,
+    /// EIP-55 checksummed EVM sender
+    pub user: String,
+    /// Canonical Sui recipient
+    pub sui_destination: String,
+    /// EIP-55 checksummed input token
+    pub input_token: String,
+    pub input_amount: TokenAmount,
+    pub usdc_amount: TokenAmount,
+    pub strategy: YieldStrategy,
+    pub timestamp: u64,
+    /// Trailing data words not covered by the known layout, as raw hex
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extra_data: Vec<String>,
+}
+
+impl IntentCreatedEvent {
+    /// `topics[0]` of every `IntentCreated` log
+    pub fn topic0() -> String {
+        format!(
+            "0x{}",
+            to_hex(&Keccak256::digest(INTENT_CREATED_SIGNATURE.as_bytes()))
+        )
+    }
+}
+
+impl TryFrom<&EvmLog> for IntentCreatedEvent {
+    type Error = NaisuError;
+
+    fn try_from(log: &EvmLog) -> Result<Self, Self::Error> {
+        let [topic0, intent_id, user] = log.topics.as_slice() else {
+            return Err(malformed(format!(
+                "expected 3 topics, got {}",
+                log.topics.len()
+            )));
+        };
+        if !topic0.eq_ignore_ascii_case(&Self::topic0()) {
+            return Err(malformed(format!("unexpected topic0 {}", topic0)));
+        }
+
+        let intent_id = word(&decode_hex(intent_id)?, "intentId")?;
+        let user = word(&decode_hex(user)?, "user")?;
+
+        let data = decode_hex(&log.data)?;
+        if !data.len().is_multiple_of(32) || data.len() / 32 < INTENT_CREATED_DATA_WORDS {
+            return Err(malformed(format!(
+                "expected at least {} data words, got {} bytes",
+                INTENT_CREATED_DATA_WORDS,
+                data.len()
+            )));
+        }
+        let words: Vec<[u8; 32]> = data
+            .chunks_exact(32)
+            .map(|chunk| chunk.try_into().expect("chunks are 32 bytes"))
+            .collect();
+
+        let strategy_id = small_uint(&words[4], 1, "strategyId")? as u8;
+        let timestamp = small_uint(&words[5], 8, "timestamp")?;
+
+        Ok(Self {
+            intent_id: format!("0x{}", to_hex(&intent_id)),
+            user: evm_address(&user, "user")?,
+            sui_destination: normalize_sui_address(&to_hex(&words[0]))?,
+            input_token: evm_address(&words[1], "inputToken")?,
+            input_amount: TokenAmount::from_be_word(&words[2])
+                .ok_or_else(|| malformed("inputAmount exceeds 128 bits"))?,
+            usdc_amount: TokenAmount::from_be_word(&words[3])
+                .ok_or_else(|| malformed("usdcAmount exceeds 128 bits"))?,
+            strategy: YieldStrategy::from_id(strategy_id),
+            timestamp,
+            extra_data: words[INTENT_CREATED_DATA_WORDS..]
+                .iter()
+                .map(|w| format!("0x{}", to_hex(w)))
+                .collect(),
+        })
+    }
+}
+
+impl TryFrom<EvmLog> for IntentCreatedEvent {
+    type Error = NaisuError;
+
+    fn try_from(log: EvmLog) -> Result<Self, Self::Error> {
+        Self::try_from(&log)
+    }
+}
+
+fn malformed(msg: impl Into<String>) -> NaisuError {
+    NaisuError::MalformedLog(msg.into())
+}
+
+fn decode_hex(s: &str) -> Result<Vec<u8>, NaisuError> {
+    let hex = s.strip_prefix("0x").unwrap_or(s);
+    if !hex.len().is_multiple_of(2) {
+        return Err(malformed(format!("odd-length hex: {}", s)));
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| {
+            u8::from_str_radix(&hex[i..i + 2], 16)
+                .map_err(|_| malformed(format!("invalid hex: {}", s)))
+        })
+        .collect()
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn word(bytes: &[u8], field: &str) -> Result<[u8; 32], NaisuError> {
+    bytes
+        .try_into()
+        .map_err(|_| malformed(format!("{} is not 32 bytes", field)))
+}
+
+/// ABI `address`: 12 zero bytes followed by 20 address bytes
+fn evm_address(word: &[u8; 32], field: &str) -> Result<String, NaisuError> {
+    if word[..12].iter().any(|b| *b != 0) {
+        return Err(malformed(format!("{} is not a left-padded address", field)));
+    }
+    normalize_evm_address(&to_hex(&word[12..]))
+}
+
+/// Unsigned integer that must fit in the low `bytes` bytes of the word
+fn small_uint(word: &[u8; 32], bytes: usize, field: &str) -> Result<u64, NaisuError> {
+    if word[..32 - bytes].iter().any(|b| *b != 0) {
+        return Err(malformed(format!("{} out of range", field)));
+    }
+    Ok(word[32 - bytes..]
+        .iter()
+        .fold(0u64, |acc, b| (acc << 8) | *b as u64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn uint_word(value: u128) -> String {
+        format!("{:064x}", value)
+    }
+
+    fn intent_created_log() -> EvmLog {
+        let data = [
+            uint_word(0xabc),                                               // suiDestination
+            format!("{:0>64}", "036cbd53842c5426634e7929541ec2318f3dcf7e"), // inputToken
+            uint_word(5_000_000),                                           // inputAmount
+            uint_word(4_990_000),                                           // usdcAmount
+            uint_word(3),                                                   // strategyId
+            uint_word(1_700_000_000),                                       // timestamp
+        ]
+        .concat();
+
+        EvmLog {
+            address: "0xf0093fcf07aa10de35b78d1f33c60439d11bc0c0".to_string(),
+            topics: vec![
+                IntentCreatedEvent::topic0(),
+                format!("0x{}", uint_word(1)),
+                format!("0x{:0>64}", "5aaeb6053f3e94c9b9a09f33669435e7ef1beaed"),
+            ],
+            data: format!("0x{}", data),
+            transaction_hash: None,
+            block_number: None,
+        }
+    }
+
+    #[test]
+    fn test_decode_intent_created() {
+        let event = IntentCreatedEvent::try_from(intent_created_log()).unwrap();
+
+        assert_eq!(event.intent_id, format!("0x{}", uint_word(1)));
+        assert_eq!(event.user, "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed");
+        assert_eq!(event.sui_destination, format!("0x{:0>64}", "abc"));
+        assert_eq!(
+            event.input_token,
+            "0x036CbD53842c5426634e7929541eC2318f3dCF7e"
+        );
+        assert_eq!(event.input_amount, TokenAmount::from_raw(5_000_000));
+        assert_eq!(event.usdc_amount, TokenAmount::from_raw(4_990_000));
+        assert_eq!(event.strategy, YieldStrategy::NaviUsdc);
+        assert_eq!(event.timestamp, 1_700_000_000);
+        assert!(event.extra_data.is_empty());
+    }
+
+    #[test]
+    fn test_malformed_logs_are_rejected() {
+        let mut truncated = intent_created_log();
+        truncated.data.truncate(2 + 64 * 5);
+        assert!(matches!(
+            IntentCreatedEvent::try_from(&truncated),
+            Err(NaisuError::MalformedLog(_))
+        ));
+
+        let mut bad_timestamp = intent_created_log();
+        let len = bad_timestamp.data.len();
+        bad_timestamp.data.replace_range(len - 64..len - 62, "ff");
+        assert!(IntentCreatedEvent::try_from(&bad_timestamp).is_err());
+
+        let mut wrong_event = intent_created_log();
+        wrong_event.topics[0] = format!("0x{}", uint_word(0));
+        assert!(IntentCreatedEvent::try_from(&wrong_event).is_err());
+    }
+}
@@ -0,0 +1,88 @@
+//! EVM gas pricing modes
+
+use serde::{Deserialize, Serialize};
+
+use crate::chain::EvmChain;
+
+const WEI_PER_GWEI: f64 = 1_000_000_000.0;
+
+/// How transactions on a chain are priced
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum GasPricing {
+    /// Type-2 transactions: `maxFeePerGas` + `maxPriorityFeePerGas`
+    Eip1559 { priority_fee_gwei: f64 },
+    /// Type-0 transactions: a single `gasPrice`
+    Legacy,
+}
+
+impl GasPricing {
+    /// Default for `chain`; every supported chain is EIP-1559, L2s need only a tiny tip
+    pub fn default_for(chain: EvmChain) -> Self {
+        let priority_fee_gwei = match chain {
+            EvmChain::Ethereum | EvmChain::Sepolia => 1.5,
+            EvmChain::Base | EvmChain::Arbitrum | EvmChain::Optimism | EvmChain::BaseSepolia => {
+                0.001
+            }
+        };
+        GasPricing::Eip1559 { priority_fee_gwei }
+    }
+
+    /// Fee fields for a transaction. `network_price_wei` is the latest base fee
+    /// for EIP-1559 and `eth_gasPrice` for legacy.
+    pub fn fee_fields(&self, network_price_wei: u128) -> GasFields {
+        match *self {
+            GasPricing::Eip1559 { priority_fee_gwei } => {
+                let priority = (priority_fee_gwei * WEI_PER_GWEI) as u128;
+                GasFields::Eip1559 {
+                    // Headroom for two full blocks of base fee increases
+                    max_fee_per_gas: network_price_wei.saturating_mul(2).saturating_add(priority),
+                    max_priority_fee_per_gas: priority,
+                }
+            }
+            GasPricing::Legacy => GasFields::Legacy {
+                gas_price: network_price_wei,
+            },
+        }
+    }
+}
+
+/// Fee fields as they appear in an EVM transaction request (wei)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged, rename_all = "camelCase")]
+pub enum GasFields {
+    #[serde(rename_all = "camelCase")]
+    Eip1559 {
+        max_fee_per_gas: u128,
+        max_priority_fee_per_gas: u128,
+    },
+    #[serde(rename_all = "camelCase")]
+    Legacy { gas_price: u128 },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_base_uses_eip1559_fields() {
+        let fields = GasPricing::default_for(EvmChain::Base).fee_fields(10_000_000);
+        assert_eq!(
+            fields,
+            GasFields::Eip1559 {
+                max_fee_per_gas: 21_000_000,
+                max_priority_fee_per_gas: 1_000_000,
+            }
+        );
+
+        let json = serde_json::to_value(fields).unwrap();
+        assert!(json.get("maxFeePerGas").is_some());
+        assert!(json.get("gasPrice").is_none());
+    }
+
+    #[test]
+    fn test_legacy_uses_gas_price() {
+        let json = serde_json::to_value(GasPricing::Legacy.fee_fields(5_000_000_000)).unwrap();
+        assert_eq!(json, serde_json::json!({ "gasPrice": 5_000_000_000u64 }));
+    }
+}
@@ -1,21 +1,48 @@
 //! Intent types - bidirectional cross-chain yield migration
 
-use crate::chain::EvmChain;
+use crate::addr::{normalize_evm_address, normalize_sui_address};
+use crate::chain::{attestation_budget, is_native_token, EvmChain, SuiNetwork};
+use crate::error::NaisuError;
+use crate::event::IntentCreatedEvent;
 use crate::strategy::YieldStrategy;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 /// Direction of the cross-chain intent
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum Direction {
     /// EVM → Sui: swap to USDC on EVM, bridge via CCTP, deposit to yield on Sui
     EvmToSui,
     /// Sui → EVM: withdraw from yield on Sui, bridge via CCTP, USDC arrives on EVM
     SuiToEvm,
+    /// Sui only: withdraw from yield back to Sui USDC, no bridging
+    SuiWithdraw,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 3] = [
+        Direction::EvmToSui,
+        Direction::SuiToEvm,
+        Direction::SuiWithdraw,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Direction::EvmToSui => "evm_to_sui",
+            Direction::SuiToEvm => "sui_to_evm",
+            Direction::SuiWithdraw => "sui_withdraw",
+        }
+    }
+
+    /// Moves funds across chains via CCTP
+    pub fn is_bridged(&self) -> bool {
+        !matches!(self, Direction::SuiWithdraw)
+    }
 }
 
 /// Intent status throughout its lifecycle
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum IntentStatus {
     /// Created, waiting for initial action
@@ -37,6 +64,18 @@ pub enum IntentStatus {
 }
 
 impl IntentStatus {
+    /// Every status, in lifecycle order
+    pub const ALL: [IntentStatus; 8] = [
+        IntentStatus::Pending,
+        IntentStatus::SwapCompleted,
+        IntentStatus::Bridging,
+        IntentStatus::BridgeCompleted,
+        IntentStatus::Deposited,
+        IntentStatus::Completed,
+        IntentStatus::Failed,
+        IntentStatus::Cancelled,
+    ];
+
     pub fn as_str(&self) -> &'static str {
         match self {
             IntentStatus::Pending => "pending",
@@ -49,10 +88,115 @@ impl IntentStatus {
             IntentStatus::Cancelled => "cancelled",
         }
     }
+
+    /// No further transitions are expected from this status
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            IntentStatus::Completed | IntentStatus::Failed | IntentStatus::Cancelled
+        )
+    }
+}
+
+impl std::str::FromStr for IntentStatus {
+    type Err = String;
+
+    /// Parse the exact strings produced by [`IntentStatus::as_str`]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        IntentStatus::ALL
+            .into_iter()
+            .find(|status| status.as_str() == s)
+            .ok_or_else(|| format!("Unknown intent status: {}", s))
+    }
+}
+
+/// Share of a split EvmToSui intent that goes into one strategy
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct StrategyAllocation {
+    pub strategy: YieldStrategy,
+    /// USDC amount (raw, 6 decimals)
+    pub amount: String,
+}
+
+/// Deposit progress of one allocation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AllocationStatus {
+    Pending,
+    Deposited,
+    Failed,
+}
+
+/// Per-strategy outcome of a split intent
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct AllocationOutcome {
+    pub strategy: YieldStrategy,
+    /// USDC amount (raw, 6 decimals)
+    pub amount: String,
+    pub status: AllocationStatus,
+}
+
+/// Parse `allocations` and check that they split `total` exactly: at least
+/// one entry, each strategy once, every amount a positive integer, and the
+/// amounts summing to `total` without overflow.
+pub fn split_amounts(
+    allocations: &[StrategyAllocation],
+    total: &str,
+) -> Result<Vec<(YieldStrategy, u64)>, NaisuError> {
+    if allocations.is_empty() {
+        return Err(NaisuError::InvalidAllocation(
+            "at least one allocation is required".to_string(),
+        ));
+    }
+    let total: u64 = total.parse().map_err(|_| {
+        NaisuError::InvalidAllocation(format!("total {} is not a u64 integer", total))
+    })?;
+
+    let mut parts: Vec<(YieldStrategy, u64)> = Vec::with_capacity(allocations.len());
+    let mut sum: u64 = 0;
+    for allocation in allocations {
+        if parts.iter().any(|(s, _)| *s == allocation.strategy) {
+            return Err(NaisuError::InvalidAllocation(format!(
+                "{} is allocated more than once",
+                allocation.strategy.name()
+            )));
+        }
+        let amount = allocation
+            .amount
+            .parse::<u64>()
+            .ok()
+            .filter(|amount| *amount > 0)
+            .ok_or_else(|| {
+                NaisuError::InvalidAllocation(format!(
+                    "amount for {} must be a positive integer",
+                    allocation.strategy.name()
+                ))
+            })?;
+        sum = sum
+            .checked_add(amount)
+            .ok_or_else(|| NaisuError::InvalidAllocation("amounts overflow u64".to_string()))?;
+        parts.push((allocation.strategy, amount));
+    }
+
+    if sum != total {
+        return Err(NaisuError::InvalidAllocation(format!(
+            "allocations sum to {} but the bridged amount is {}",
+            sum, total
+        )));
+    }
+    Ok(parts)
+}
+
+/// A status an intent entered, and when
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct StatusChange {
+    pub status: IntentStatus,
+    /// Unix seconds
+    pub at: i64,
 }
 
 /// Cross-chain intent (bidirectional)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Intent {
     pub id: String,
     /// Direction of the intent
@@ -61,16 +205,27 @@ pub struct Intent {
     pub source_address: String,
     /// Destination wallet address
     pub dest_address: String,
-    /// EVM chain involved (source for EvmToSui, dest for SuiToEvm)
-    pub evm_chain: EvmChain,
+    /// EVM chain involved: source for EvmToSui, dest for SuiToEvm, None
+    /// for SuiWithdraw
+    pub evm_chain: Option<EvmChain>,
     /// Input token address on source chain
     pub input_token: String,
     /// Input amount (raw, with decimals)
     pub input_amount: String,
     /// USDC amount (the bridge token)
     pub usdc_amount: Option<String>,
-    /// Target yield strategy (Some for EvmToSui, None for SuiToEvm)
+    /// Target yield strategy (Some for single-strategy EvmToSui and the
+    /// position being exited for SuiWithdraw, None for SuiToEvm and split
+    /// intents)
     pub strategy: Option<YieldStrategy>,
+    /// Per-strategy split of the bridged USDC (empty unless split)
+    #[serde(default)]
+    pub allocations: Vec<AllocationOutcome>,
+    /// The user's obligation / account object deposited into: the one given
+    /// on the request, or the account a recorded deposit opened. Scallop
+    /// deposits without one mint sCoin and leave it `None`.
+    #[serde(default)]
+    pub position_object_id: Option<String>,
     /// Current status
     pub status: IntentStatus,
     /// Source swap tx hash (V4 swap for EvmToSui)
@@ -79,14 +234,57 @@ pub struct Intent {
     pub bridge_tx_hash: Option<String>,
     /// CCTP nonce for attestation polling
     pub bridge_nonce: Option<String>,
-    /// Destination tx hash (deposit PTB or receiveMessage)
+    /// Latest destination-side tx hash that moved the user's funds: the mint,
+    /// then the deposit PTB for EvmToSui; the withdraw for SuiWithdraw. The
+    /// output swap and unwrap have their own fields.
     pub dest_tx_hash: Option<String>,
+    /// CCTP receiveMessage (mint) tx hash on the destination chain
+    #[serde(default)]
+    pub mint_tx_hash: Option<String>,
+    /// Deposit PTB tx hash (EvmToSui)
+    #[serde(default)]
+    pub deposit_tx_hash: Option<String>,
+    /// Destination swap of the minted USDC into `output_token` (SuiToEvm)
+    #[serde(default)]
+    pub output_swap_tx_hash: Option<String>,
+    /// WETH unwrap after the output swap, when native ETH is delivered
+    #[serde(default)]
+    pub unwrap_tx_hash: Option<String>,
     /// Error message if failed
     pub error_message: Option<String>,
     /// Created timestamp (unix)
     pub created_at: i64,
     /// Last updated timestamp (unix)
     pub updated_at: i64,
+    /// Statuses entered after creation, oldest first
+    #[serde(default)]
+    pub status_history: Vec<StatusChange>,
+    /// Hidden from default listings; still fetchable by id
+    #[serde(default)]
+    pub archived: bool,
+    /// Scheduling priority requested by the client (higher runs sooner);
+    /// the scheduler counts anything above 3 as 3
+    #[serde(default)]
+    pub priority: Option<u8>,
+    /// Unix time by which the bridge should complete: the burn plus the
+    /// attestation budget for its domain. None until the burn, and for
+    /// intents that don't bridge.
+    #[serde(default)]
+    pub bridge_deadline: Option<i64>,
+    /// Why the bridge was given up on, when attestation never arrived
+    #[serde(default)]
+    pub bridge_failure: Option<BridgeFailure>,
+    /// Token delivered on the EVM chain (SuiToEvm); the minted USDC is
+    /// swapped into it unless it is USDC. Unset means USDC.
+    #[serde(default)]
+    pub output_token: Option<String>,
+    /// Amount of `output_token` delivered, once completed
+    #[serde(default)]
+    pub output_amount: Option<String>,
+    /// Slippage allowed on the output swap; [`DEFAULT_MAX_SLIPPAGE_BPS`]
+    /// when unset
+    #[serde(default)]
+    pub max_slippage_bps: Option<u16>,
 }
 
 impl Intent {
@@ -106,19 +304,148 @@ impl Intent {
             direction: Direction::EvmToSui,
             source_address: evm_address,
             dest_address: sui_address,
-            evm_chain,
+            evm_chain: Some(evm_chain),
             input_token,
             input_amount,
             usdc_amount: None,
             strategy: Some(strategy),
+            allocations: Vec::new(),
+            position_object_id: None,
             status: IntentStatus::Pending,
             swap_tx_hash: None,
             bridge_tx_hash: None,
             bridge_nonce: None,
             dest_tx_hash: None,
+            mint_tx_hash: None,
+            deposit_tx_hash: None,
+            output_swap_tx_hash: None,
+            unwrap_tx_hash: None,
             error_message: None,
             created_at: now,
             updated_at: now,
+            status_history: Vec::new(),
+            archived: false,
+            priority: None,
+            bridge_deadline: None,
+            bridge_failure: None,
+            output_token: None,
+            output_amount: None,
+            max_slippage_bps: None,
+        }
+    }
+
+    /// Create a new EVM→Sui intent whose bridged USDC is split across
+    /// several strategies in one deposit
+    pub fn new_evm_to_sui_split(
+        id: String,
+        evm_address: String,
+        sui_address: String,
+        evm_chain: EvmChain,
+        input_token: String,
+        input_amount: String,
+        allocations: Vec<StrategyAllocation>,
+    ) -> Self {
+        let now = chrono::Utc::now().timestamp();
+        Self {
+            id,
+            direction: Direction::EvmToSui,
+            source_address: evm_address,
+            dest_address: sui_address,
+            evm_chain: Some(evm_chain),
+            input_token,
+            input_amount,
+            usdc_amount: None,
+            strategy: None,
+            allocations: allocations
+                .into_iter()
+                .map(|a| AllocationOutcome {
+                    strategy: a.strategy,
+                    amount: a.amount,
+                    status: AllocationStatus::Pending,
+                })
+                .collect(),
+            position_object_id: None,
+            status: IntentStatus::Pending,
+            swap_tx_hash: None,
+            bridge_tx_hash: None,
+            bridge_nonce: None,
+            dest_tx_hash: None,
+            mint_tx_hash: None,
+            deposit_tx_hash: None,
+            output_swap_tx_hash: None,
+            unwrap_tx_hash: None,
+            error_message: None,
+            created_at: now,
+            updated_at: now,
+            status_history: Vec::new(),
+            archived: false,
+            priority: None,
+            bridge_deadline: None,
+            bridge_failure: None,
+            output_token: None,
+            output_amount: None,
+            max_slippage_bps: None,
+        }
+    }
+
+    /// Canonical form of an intent id, the store's primary key. Intents seen
+    /// on chain are keyed by the hook's bytes32 `intentId`, as lowercase `0x`
+    /// hex; ids generated by the API (UUIDs) are kept as they are.
+    pub fn canonical_id(id: &str) -> String {
+        let id = id.trim();
+        let hex = id
+            .strip_prefix("0x")
+            .or_else(|| id.strip_prefix("0X"))
+            .unwrap_or(id);
+        if hex.len() == 64 && hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            format!("0x{}", hex.to_ascii_lowercase())
+        } else {
+            id.to_string()
+        }
+    }
+
+    /// Create a new withdraw-only intent: exit `strategy` back to Sui USDC at
+    /// `sui_address`, without bridging
+    pub fn new_sui_withdraw(
+        id: String,
+        sui_address: String,
+        usdc_coin_type: String,
+        amount: String,
+        strategy: YieldStrategy,
+    ) -> Self {
+        let now = chrono::Utc::now().timestamp();
+        Self {
+            id,
+            direction: Direction::SuiWithdraw,
+            source_address: sui_address.clone(),
+            dest_address: sui_address,
+            evm_chain: None,
+            input_token: usdc_coin_type,
+            input_amount: amount,
+            usdc_amount: None,
+            strategy: Some(strategy),
+            allocations: Vec::new(),
+            position_object_id: None,
+            status: IntentStatus::Pending,
+            swap_tx_hash: None,
+            bridge_tx_hash: None,
+            bridge_nonce: None,
+            dest_tx_hash: None,
+            mint_tx_hash: None,
+            deposit_tx_hash: None,
+            output_swap_tx_hash: None,
+            unwrap_tx_hash: None,
+            error_message: None,
+            created_at: now,
+            updated_at: now,
+            status_history: Vec::new(),
+            archived: false,
+            priority: None,
+            bridge_deadline: None,
+            bridge_failure: None,
+            output_token: None,
+            output_amount: None,
+            max_slippage_bps: None,
         }
     }
 
@@ -137,26 +464,159 @@ impl Intent {
             direction: Direction::SuiToEvm,
             source_address: sui_address,
             dest_address: evm_address,
-            evm_chain,
+            evm_chain: Some(evm_chain),
             input_token,
             input_amount: input_amount.clone(),
             usdc_amount: Some(input_amount),
             strategy: None,
+            allocations: Vec::new(),
+            position_object_id: None,
             status: IntentStatus::Pending,
             swap_tx_hash: None,
             bridge_tx_hash: None,
             bridge_nonce: None,
             dest_tx_hash: None,
+            mint_tx_hash: None,
+            deposit_tx_hash: None,
+            output_swap_tx_hash: None,
+            unwrap_tx_hash: None,
             error_message: None,
             created_at: now,
             updated_at: now,
+            status_history: Vec::new(),
+            archived: false,
+            priority: None,
+            bridge_deadline: None,
+            bridge_failure: None,
+            output_token: None,
+            output_amount: None,
+            max_slippage_bps: None,
+        }
+    }
+
+    /// Deposits into more than one strategy
+    pub fn is_split(&self) -> bool {
+        !self.allocations.is_empty()
+    }
+
+    /// The split, checked against `input_amount` as it was at creation.
+    /// Splits take USDC input only, and standard CCTP mints exactly what was
+    /// burned, so this is also the amount that arrives on Sui.
+    pub fn split_allocations(&self) -> Result<Vec<(YieldStrategy, u64)>, NaisuError> {
+        let allocations: Vec<_> = self
+            .allocations
+            .iter()
+            .map(|a| StrategyAllocation {
+                strategy: a.strategy,
+                amount: a.amount.clone(),
+            })
+            .collect();
+        split_amounts(&allocations, &self.input_amount)
+    }
+
+    /// Set every allocation's status (the split deposit is one transaction)
+    pub fn set_allocation_status(&mut self, status: AllocationStatus) {
+        for allocation in &mut self.allocations {
+            allocation.status = status;
         }
     }
 
     /// Update status with timestamp
     pub fn set_status(&mut self, status: IntentStatus) {
+        // A late mint clears the overdue flag; a real diagnosis stays
+        if status == IntentStatus::BridgeCompleted
+            && self.bridge_failure == Some(BridgeFailure::DeadlinePassed)
+        {
+            self.bridge_failure = None;
+        }
         self.status = status;
         self.updated_at = chrono::Utc::now().timestamp();
+        self.record_status();
+    }
+
+    /// Append the current status to `status_history`, stamped with
+    /// `updated_at`, unless it is already the latest entry
+    pub fn record_status(&mut self) {
+        let latest = self
+            .status_history
+            .last()
+            .map_or(IntentStatus::Pending, |change| change.status);
+        if self.status != latest {
+            self.status_history.push(StatusChange {
+                status: self.status,
+                at: self.updated_at,
+            });
+        }
+    }
+
+    /// ERC-20 the minted USDC of a SuiToEvm intent is swapped into: its
+    /// `output_token`, or WETH (unwrapped afterwards) for native ETH. None
+    /// when USDC is delivered as minted.
+    pub fn swap_output_token(&self) -> Option<&str> {
+        let token = self.output_token.as_deref()?;
+        let chain = self.evm_chain?;
+        if self.direction != Direction::SuiToEvm || token.eq_ignore_ascii_case(chain.usdc_address())
+        {
+            None
+        } else if is_native_token(token) {
+            Some(chain.weth_address())
+        } else {
+            Some(token)
+        }
+    }
+
+    /// Native ETH is delivered, so the swapped WETH must be unwrapped
+    pub fn unwraps_output(&self) -> bool {
+        self.direction == Direction::SuiToEvm
+            && self.output_token.as_deref().is_some_and(is_native_token)
+    }
+
+    /// Slippage allowed on the output swap
+    pub fn max_slippage_bps(&self) -> u16 {
+        self.max_slippage_bps.unwrap_or(DEFAULT_MAX_SLIPPAGE_BPS)
+    }
+
+    /// Least output accepted for a swap quoted at `quoted`, after
+    /// `max_slippage_bps`
+    pub fn min_output_amount(&self, quoted: &str) -> Result<String, NaisuError> {
+        let quoted: u128 = quoted
+            .parse()
+            .map_err(|_| NaisuError::Evm(format!("bad output swap quote: {}", quoted)))?;
+        let keep = 10_000u128.saturating_sub(self.max_slippage_bps() as u128);
+        Ok((quoted.saturating_mul(keep) / 10_000).to_string())
+    }
+
+    /// CCTP domain the intent burns on; None if it doesn't bridge
+    pub fn source_cctp_domain(&self) -> Option<u32> {
+        match self.direction {
+            Direction::EvmToSui => self.evm_chain.and_then(|c| c.cctp_domain()),
+            // Sui's domain is the same on every network
+            Direction::SuiToEvm => Some(SuiNetwork::Mainnet.cctp_domain()),
+            Direction::SuiWithdraw => None,
+        }
+    }
+
+    /// Start the attestation budget at `burned_at` (unix secs), unless an
+    /// earlier burn already started it
+    pub fn start_bridge_clock(&mut self, burned_at: i64) {
+        if self.bridge_deadline.is_none() {
+            self.bridge_deadline = self
+                .source_cctp_domain()
+                .map(|domain| burned_at + attestation_budget(domain).as_secs() as i64);
+        }
+    }
+
+    /// Still bridging past its deadline, and not yet flagged or diagnosed
+    pub fn is_overdue(&self, now: i64) -> bool {
+        !self.status.is_terminal()
+            && self.bridge_failure.is_none()
+            && self.bridge_deadline.is_some_and(|d| d < now)
+    }
+
+    /// Hide from default listings
+    pub fn archive(&mut self) {
+        self.archived = true;
+        self.updated_at = chrono::Utc::now().timestamp();
     }
 
     /// Mark as failed with error message
@@ -164,11 +624,53 @@ impl Intent {
         self.status = IntentStatus::Failed;
         self.error_message = Some(message);
         self.updated_at = chrono::Utc::now().timestamp();
+        self.record_status();
     }
 }
 
+/// The pending EvmToSui intent for a hook `IntentCreated` event, keyed by its
+/// canonical on-chain id. The event doesn't say which chain's hook emitted
+/// it, so the chain comes with it.
+/// Addresses are stored in canonical form (see [`crate::addr`]), like those of
+/// validated requests, so lookups can compare them as plain strings. An
+/// address that doesn't parse is kept as given.
+impl From<(IntentCreatedEvent, EvmChain)> for Intent {
+    fn from((event, chain): (IntentCreatedEvent, EvmChain)) -> Self {
+        let user = normalize_evm_address(&event.user).unwrap_or(event.user);
+        let sui_destination =
+            normalize_sui_address(&event.sui_destination).unwrap_or(event.sui_destination);
+        let mut intent = Intent::new_evm_to_sui(
+            Intent::canonical_id(&event.intent_id),
+            user,
+            sui_destination,
+            chain,
+            event.input_token,
+            event.input_amount.to_string(),
+            event.strategy,
+        );
+        intent.usdc_amount = Some(event.usdc_amount.to_string());
+        intent
+    }
+}
+
+/// Slippage allowed on an output swap when the request doesn't set one
+pub const DEFAULT_MAX_SLIPPAGE_BPS: u16 = 50;
+
+/// Why a burn's attestation is overdue, once known
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BridgeFailure {
+    /// Still bridging past its deadline; nobody has looked at the burn yet
+    DeadlinePassed,
+    /// The burn is still on the source chain; Circle just never attested it
+    AttestationPending,
+    /// The burn tx is no longer on the source chain (reorged out), so
+    /// nothing was burned
+    BurnDropped,
+}
+
 /// Intent creation request from frontend
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct CreateIntentRequest {
     pub direction: Direction,
     pub source_address: String,
@@ -176,19 +678,470 @@ pub struct CreateIntentRequest {
     pub evm_chain: EvmChain,
     pub input_token: String,
     pub input_amount: String,
-    /// Required for EvmToSui, ignored for SuiToEvm
+    /// Required for EvmToSui unless `allocations` is given, ignored for SuiToEvm
     pub strategy: Option<YieldStrategy>,
+    /// Split the bridged USDC across strategies instead of `strategy`;
+    /// amounts must sum to `input_amount` (EvmToSui, USDC input only)
+    #[serde(default)]
+    pub allocations: Option<Vec<StrategyAllocation>>,
+    /// Scheduling priority, 0–3 (higher runs sooner; larger values count as
+    /// 3); computed from the intent when omitted
+    #[serde(default)]
+    pub priority: Option<u8>,
+    /// Existing Scallop obligation / Navi account object on the target
+    /// protocol to deposit into (single-strategy EvmToSui only)
+    #[serde(default)]
+    pub position_object_id: Option<String>,
+    /// Token delivered on the EVM chain (SuiToEvm only): USDC by default (no
+    /// swap), a known ERC-20 the minted USDC is swapped into, or native ETH
+    /// (`NATIVE_ETH_SENTINEL` or the zero address)
+    #[serde(default)]
+    pub output_token: Option<String>,
+    /// Slippage allowed on the output swap, in basis points (default 50)
+    #[serde(default)]
+    pub max_slippage_bps: Option<u16>,
 }
 
-/// Intent event emitted by V4 Hook (EVM side, EvmToSui trigger)
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct IntentCreatedEvent {
-    pub intent_id: String,
-    pub user: String,
-    pub sui_destination: String,
-    pub input_token: String,
-    pub input_amount: String,
-    pub usdc_amount: String,
-    pub strategy_id: u8,
-    pub timestamp: u64,
+/// Withdraw-only intent request: exit a yield position back to Sui USDC
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct WithdrawIntentRequest {
+    /// Position owner, who also receives the USDC
+    pub sui_address: String,
+    pub strategy: YieldStrategy,
+    /// USDC to withdraw, smallest unit
+    pub amount: String,
+    /// Scallop obligation or Navi AccountCap to withdraw from; the lending
+    /// pool when omitted
+    #[serde(default)]
+    pub position_object_id: Option<String>,
+    #[serde(default)]
+    pub priority: Option<u8>,
+}
+
+/// One invalid field of a request
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct FieldError {
+    /// Request field name, e.g. `dest_address`
+    pub field: String,
+    pub message: String,
+}
+
+impl FieldError {
+    fn new(field: &str, message: impl Into<String>) -> Self {
+        Self {
+            field: field.to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+/// Where a validated EvmToSui request deposits
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RequestedDeposit {
+    Strategy(YieldStrategy),
+    /// Allocations already checked to sum to `input_amount`
+    Split(Vec<(YieldStrategy, u64)>),
+}
+
+/// A request that passed [`CreateIntentRequest::validate`], with its amount
+/// parsed to the width its source chain uses
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidIntent {
+    EvmToSui {
+        input_amount: u128,
+        deposit: RequestedDeposit,
+    },
+    SuiToEvm {
+        input_amount: u64,
+    },
+    SuiWithdraw {
+        input_amount: u64,
+    },
+}
+
+impl CreateIntentRequest {
+    /// Check every field and report all violations at once, rather than
+    /// stopping at the first. On success the addresses are rewritten into
+    /// canonical form for their chains. Chain-dependent checks (strategy
+    /// enabled, deposit limits, balances) stay with the caller.
+    pub fn validate(&mut self) -> Result<ValidIntent, Vec<FieldError>> {
+        let mut errors = Vec::new();
+
+        let (source_evm, dest_evm) = match self.direction {
+            Direction::EvmToSui => (true, false),
+            Direction::SuiToEvm => (false, true),
+            Direction::SuiWithdraw => (false, false),
+        };
+        let mut canonical = Vec::with_capacity(2);
+        for (field, addr, evm) in [
+            ("source_address", &self.source_address, source_evm),
+            ("dest_address", &self.dest_address, dest_evm),
+        ] {
+            let (normalized, chain) = if evm {
+                (normalize_evm_address(addr).ok(), "an EVM")
+            } else {
+                (normalize_sui_address(addr).ok(), "a Sui")
+            };
+            match normalized {
+                Some(normalized) => canonical.push(normalized),
+                None => errors.push(FieldError::new(
+                    field,
+                    format!("{} is not {} address", addr, chain),
+                )),
+            }
+        }
+
+        // EVM tokens are uint256 but amounts travel as u128; Sui coins are u64
+        let max_amount = match self.direction {
+            Direction::EvmToSui => u128::MAX,
+            Direction::SuiToEvm | Direction::SuiWithdraw => u64::MAX as u128,
+        };
+        let input_amount = match self.input_amount.parse::<u128>() {
+            Ok(0) => {
+                errors.push(FieldError::new("input_amount", "must be greater than zero"));
+                None
+            }
+            Ok(amount) if amount > max_amount => {
+                errors.push(FieldError::new(
+                    "input_amount",
+                    format!("must be at most {}", max_amount),
+                ));
+                None
+            }
+            Ok(amount) => Some(amount),
+            Err(_) => {
+                errors.push(FieldError::new(
+                    "input_amount",
+                    "must be an integer in the token's smallest unit",
+                ));
+                None
+            }
+        };
+
+        let mut deposit = None;
+        match self.direction {
+            Direction::EvmToSui => {
+                if !is_native_token(&self.input_token)
+                    && self.evm_chain.known_token(&self.input_token).is_none()
+                {
+                    errors.push(FieldError::new(
+                        "input_token",
+                        format!(
+                            "{} is not a known token on {}",
+                            self.input_token,
+                            self.evm_chain.name()
+                        ),
+                    ));
+                }
+                match (self.strategy, &self.allocations) {
+                    (None, None) => errors.push(FieldError::new(
+                        "strategy",
+                        "required for evm_to_sui unless allocations are given",
+                    )),
+                    (Some(_), Some(_)) => errors.push(FieldError::new(
+                        "allocations",
+                        "give either strategy or allocations, not both",
+                    )),
+                    (Some(strategy), None) => deposit = Some(RequestedDeposit::Strategy(strategy)),
+                    (None, Some(allocations)) => match self.split(allocations) {
+                        Ok(parts) => deposit = Some(RequestedDeposit::Split(parts)),
+                        Err(error) => errors.push(error),
+                    },
+                }
+            }
+            Direction::SuiToEvm => {
+                if let Some(token) = self.output_token.as_deref() {
+                    if !is_native_token(token) && self.evm_chain.known_token(token).is_none() {
+                        errors.push(FieldError::new(
+                            "output_token",
+                            format!(
+                                "{} is not a known token on {}",
+                                token,
+                                self.evm_chain.name()
+                            ),
+                        ));
+                    }
+                }
+                if self.max_slippage_bps.is_some_and(|bps| bps > 10_000) {
+                    errors.push(FieldError::new(
+                        "max_slippage_bps",
+                        "must be at most 10000 (100%)",
+                    ));
+                }
+            }
+            Direction::SuiWithdraw => {}
+        }
+
+        // Every field that failed to parse pushed an error above
+        let (Some(input_amount), [source, dest]) = (input_amount, &canonical[..]) else {
+            return Err(errors);
+        };
+        let valid = match self.direction {
+            Direction::EvmToSui => deposit.map(|deposit| ValidIntent::EvmToSui {
+                input_amount,
+                deposit,
+            }),
+            // Within u64 by the range check above
+            Direction::SuiToEvm => Some(ValidIntent::SuiToEvm {
+                input_amount: input_amount as u64,
+            }),
+            Direction::SuiWithdraw => Some(ValidIntent::SuiWithdraw {
+                input_amount: input_amount as u64,
+            }),
+        };
+        match valid {
+            Some(valid) if errors.is_empty() => {
+                self.source_address = source.clone();
+                self.dest_address = dest.clone();
+                Ok(valid)
+            }
+            _ => Err(errors),
+        }
+    }
+
+    /// `allocations` as amounts splitting this request's USDC input
+    fn split(
+        &self,
+        allocations: &[StrategyAllocation],
+    ) -> Result<Vec<(YieldStrategy, u64)>, FieldError> {
+        if self.position_object_id.is_some() {
+            return Err(FieldError::new(
+                "position_object_id",
+                "cannot be combined with allocations",
+            ));
+        }
+        if !self
+            .input_token
+            .eq_ignore_ascii_case(self.evm_chain.usdc_address())
+        {
+            return Err(FieldError::new(
+                "allocations",
+                "require USDC input; a swap's output is only known after it lands",
+            ));
+        }
+        split_amounts(allocations, &self.input_amount)
+            .map_err(|e| FieldError::new("allocations", e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_canonical_id_normalizes_onchain_ids_only() {
+        let onchain = format!("0x{}", "ab".repeat(32));
+        assert_eq!(Intent::canonical_id(&onchain.to_uppercase()[2..]), onchain);
+        assert_eq!(
+            Intent::canonical_id(&format!("0X{}", "AB".repeat(32))),
+            onchain
+        );
+        let uuid = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+        assert_eq!(Intent::canonical_id(uuid), uuid);
+    }
+
+    #[test]
+    fn test_validate_reports_every_bad_field() {
+        let request = CreateIntentRequest {
+            direction: Direction::EvmToSui,
+            source_address: "not-an-address".to_string(),
+            dest_address: format!("0x{}", "ab".repeat(32)),
+            evm_chain: EvmChain::BaseSepolia,
+            input_token: EvmChain::BaseSepolia.usdc_address().to_string(),
+            input_amount: "1.5".to_string(),
+            strategy: None,
+            allocations: None,
+            priority: None,
+            position_object_id: None,
+            output_token: None,
+            max_slippage_bps: None,
+        };
+
+        let errors = request.clone().validate().unwrap_err();
+        let fields: Vec<_> = errors.iter().map(|e| e.field.as_str()).collect();
+        assert_eq!(fields, ["source_address", "input_amount", "strategy"]);
+
+        let mut valid = CreateIntentRequest {
+            source_address: "0x1234567890123456789012345678901234567890".to_string(),
+            input_amount: "1000000".to_string(),
+            strategy: Some(YieldStrategy::ScallopUsdc),
+            ..request.clone()
+        };
+        assert_eq!(
+            valid.validate(),
+            Ok(ValidIntent::EvmToSui {
+                input_amount: 1_000_000,
+                deposit: RequestedDeposit::Strategy(YieldStrategy::ScallopUsdc),
+            })
+        );
+
+        // A token address that isn't one we route, and pieces of a split
+        // that don't add up
+        let mut unknown = CreateIntentRequest {
+            input_token: "0x1234567890123456789012345678901234567890".to_string(),
+            input_amount: "1000000".to_string(),
+            strategy: None,
+            allocations: Some(vec![StrategyAllocation {
+                strategy: YieldStrategy::ScallopUsdc,
+                amount: "1".to_string(),
+            }]),
+            ..valid.clone()
+        };
+        let fields: Vec<_> = unknown
+            .validate()
+            .unwrap_err()
+            .into_iter()
+            .map(|e| e.field)
+            .collect();
+        assert_eq!(fields, ["input_token", "allocations"]);
+    }
+
+    #[test]
+    fn test_amount_must_fit_the_source_chain() {
+        let mut request = CreateIntentRequest {
+            direction: Direction::SuiToEvm,
+            source_address: "0x2".to_string(),
+            dest_address: "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed".to_string(),
+            evm_chain: EvmChain::Base,
+            input_token: "usdc".to_string(),
+            input_amount: (u64::MAX as u128 + 1).to_string(),
+            strategy: None,
+            allocations: None,
+            priority: None,
+            position_object_id: None,
+            output_token: None,
+            max_slippage_bps: None,
+        };
+        assert_eq!(
+            request.clone().validate().unwrap_err()[0].field,
+            "input_amount"
+        );
+
+        request.input_amount = u64::MAX.to_string();
+        assert_eq!(
+            request.validate(),
+            Ok(ValidIntent::SuiToEvm {
+                input_amount: u64::MAX
+            })
+        );
+        // Addresses come back canonical
+        assert_eq!(request.source_address, format!("0x{:0>64}", "2"));
+        assert_eq!(
+            request.dest_address,
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"
+        );
+    }
+
+    #[test]
+    fn test_output_token_must_be_known() {
+        let chain = EvmChain::Base;
+        let request = |output_token: &str| CreateIntentRequest {
+            direction: Direction::SuiToEvm,
+            source_address: format!("0x{}", "ab".repeat(32)),
+            dest_address: "0x1234567890123456789012345678901234567890".to_string(),
+            evm_chain: chain,
+            input_token: "usdc".to_string(),
+            input_amount: "1000000".to_string(),
+            strategy: None,
+            allocations: None,
+            priority: None,
+            position_object_id: None,
+            output_token: Some(output_token.to_string()),
+            max_slippage_bps: None,
+        };
+
+        let valid = Ok(ValidIntent::SuiToEvm {
+            input_amount: 1_000_000,
+        });
+        assert_eq!(request(chain.weth_address()).validate(), valid);
+        assert_eq!(request(crate::NATIVE_ETH_SENTINEL).validate(), valid);
+        // A well-formed address that isn't a token we route
+        let errors = request("0x1234567890123456789012345678901234567890")
+            .validate()
+            .unwrap_err();
+        assert_eq!(errors[0].field, "output_token");
+    }
+
+    #[test]
+    fn test_min_output_amount_applies_slippage() {
+        let mut intent = Intent::new_sui_to_evm(
+            "0x02".to_string(),
+            "0x2".to_string(),
+            "0x1234567890123456789012345678901234567890".to_string(),
+            EvmChain::Base,
+            "usdc".to_string(),
+            "1000000".to_string(),
+        );
+        assert_eq!(intent.min_output_amount("1000000").unwrap(), "995000");
+        intent.max_slippage_bps = Some(100);
+        assert_eq!(intent.min_output_amount("1000000").unwrap(), "990000");
+        assert!(intent.min_output_amount("lots").is_err());
+    }
+
+    #[test]
+    fn test_intent_status_round_trip() {
+        for status in IntentStatus::ALL {
+            assert_eq!(status.as_str().parse::<IntentStatus>(), Ok(status));
+        }
+    }
+
+    fn allocation(strategy: YieldStrategy, amount: &str) -> StrategyAllocation {
+        StrategyAllocation {
+            strategy,
+            amount: amount.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_split_amounts_must_sum_to_total() {
+        let allocations = [
+            allocation(YieldStrategy::ScallopUsdc, "600000"),
+            allocation(YieldStrategy::NaviUsdc, "400000"),
+        ];
+        assert_eq!(
+            split_amounts(&allocations, "1000000").unwrap(),
+            [
+                (YieldStrategy::ScallopUsdc, 600000),
+                (YieldStrategy::NaviUsdc, 400000)
+            ]
+        );
+        assert!(split_amounts(&allocations, "1000001").is_err());
+    }
+
+    #[test]
+    fn test_split_amounts_rejects_bad_entries() {
+        let scallop = allocation(YieldStrategy::ScallopUsdc, "1");
+        assert!(split_amounts(&[], "0").is_err());
+        assert!(split_amounts(&[scallop.clone(), scallop], "2").is_err());
+        assert!(split_amounts(&[allocation(YieldStrategy::NaviUsdc, "0")], "0").is_err());
+        assert!(split_amounts(
+            &[
+                allocation(YieldStrategy::NaviUsdc, &u64::MAX.to_string()),
+                allocation(YieldStrategy::ScallopUsdc, "1"),
+            ],
+            "0"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_hook_intent_addresses_are_canonical() {
+        let log = crate::testing::intent_created_log(&format!("0x{:064x}", 9));
+        let mut event = IntentCreatedEvent::try_from(&log).unwrap();
+        event.user = event.user.to_lowercase();
+        event.sui_destination = "0xAB".to_string();
+
+        let intent = Intent::from((event, EvmChain::BaseSepolia));
+        assert_eq!(
+            intent.source_address,
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"
+        );
+        assert_eq!(intent.dest_address, format!("0x{:0>64}", "ab"));
+    }
+
+    #[test]
+    fn test_intent_status_rejects_unknown() {
+        assert!("open".parse::<IntentStatus>().is_err());
+        assert!("Pending".parse::<IntentStatus>().is_err());
+    }
 }
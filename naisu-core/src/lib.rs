@@ -4,13 +4,31 @@
 //! - Intent: User's cross-chain yield migration request
 //! - Chain: Supported blockchain networks
 //! - Strategy: Yield strategies on destination chain (Sui)
+//! - Event: Typed chain events decoded from raw logs
+//! - Addr: Canonical EVM/Sui address forms
+//! - Logging: Shared tracing subscriber setup
+//! - Orchestrator: Intent state machine over injectable chain backends
+//! - Nonce: Durable CCTP burn nonces for resuming intents
 
+pub mod addr;
+pub mod amount;
 pub mod chain;
 pub mod error;
+pub mod event;
+pub mod gas;
 pub mod intent;
+pub mod nonce;
+pub mod orchestrator;
 pub mod strategy;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
 
+pub use amount::TokenAmount;
 pub use chain::*;
 pub use error::*;
+pub use event::*;
+pub use gas::{GasFields, GasPricing};
 pub use intent::*;
+pub use nonce::*;
+pub use orchestrator::*;
 pub use strategy::*;
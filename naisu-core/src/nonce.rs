@@ -0,0 +1,152 @@
+//! Durable CCTP burn nonces, keyed by intent
+//!
+//! The nonce on `Intent.bridge_nonce` lives only as long as the intent's
+//! in-memory copy. A [`NonceStore`] records it the moment the burn is
+//! observed, so an orchestrator that crashed mid-bridge can recover the nonce
+//! and resume polling the attestation instead of burning again.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::NaisuError;
+
+/// What identifies a CCTP burn for attestation polling
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BridgeNonce {
+    pub burn_tx_hash: String,
+    pub nonce: String,
+    /// keccak256 of the CCTP message, when the burn's logs were decoded
+    pub message_hash: Option<String>,
+}
+
+/// Intent id ↔ CCTP burn nonce
+#[async_trait]
+pub trait NonceStore: Send + Sync {
+    /// Record the burn for `intent_id`, replacing any earlier record
+    async fn put(&self, intent_id: &str, nonce: BridgeNonce) -> Result<(), NaisuError>;
+
+    async fn get(&self, intent_id: &str) -> Result<Option<BridgeNonce>, NaisuError>;
+}
+
+/// Process-local store; nonces are lost on restart
+#[derive(Debug, Default)]
+pub struct InMemoryNonceStore {
+    nonces: Mutex<HashMap<String, BridgeNonce>>,
+}
+
+#[async_trait]
+impl NonceStore for InMemoryNonceStore {
+    async fn put(&self, intent_id: &str, nonce: BridgeNonce) -> Result<(), NaisuError> {
+        self.nonces
+            .lock()
+            .unwrap()
+            .insert(intent_id.to_string(), nonce);
+        Ok(())
+    }
+
+    async fn get(&self, intent_id: &str) -> Result<Option<BridgeNonce>, NaisuError> {
+        Ok(self.nonces.lock().unwrap().get(intent_id).cloned())
+    }
+}
+
+/// JSON file store. Every `put` rewrites the file through a temporary file
+/// and a rename, so a crash leaves either the old or the new contents.
+/// Writes go through `tokio::fs` under an async lock, which also keeps
+/// concurrent `put`s from interleaving their renames.
+#[derive(Debug)]
+pub struct FileNonceStore {
+    path: PathBuf,
+    nonces: tokio::sync::Mutex<HashMap<String, BridgeNonce>>,
+}
+
+impl FileNonceStore {
+    /// Load `path`, or start empty if it doesn't exist yet
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self, NaisuError> {
+        let path = path.into();
+        let nonces = match std::fs::read(&path) {
+            Ok(bytes) => serde_json::from_slice(&bytes).map_err(|e| {
+                NaisuError::Database(format!("corrupt nonce store {}: {}", path.display(), e))
+            })?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => HashMap::new(),
+            Err(e) => {
+                return Err(NaisuError::Database(format!(
+                    "cannot read nonce store {}: {}",
+                    path.display(),
+                    e
+                )))
+            }
+        };
+        Ok(Self {
+            path,
+            nonces: tokio::sync::Mutex::new(nonces),
+        })
+    }
+
+    async fn write(&self, nonces: &HashMap<String, BridgeNonce>) -> Result<(), NaisuError> {
+        let bytes =
+            serde_json::to_vec(nonces).map_err(|e| NaisuError::Serialization(e.to_string()))?;
+        let tmp = self.path.with_extension("tmp");
+        let written = match tokio::fs::write(&tmp, bytes).await {
+            Ok(()) => tokio::fs::rename(&tmp, &self.path).await,
+            Err(e) => Err(e),
+        };
+        written.map_err(|e| {
+            NaisuError::Database(format!(
+                "cannot write nonce store {}: {}",
+                self.path.display(),
+                e
+            ))
+        })
+    }
+}
+
+#[async_trait]
+impl NonceStore for FileNonceStore {
+    async fn put(&self, intent_id: &str, nonce: BridgeNonce) -> Result<(), NaisuError> {
+        let mut nonces = self.nonces.lock().await;
+        let previous = nonces.insert(intent_id.to_string(), nonce);
+        if let Err(e) = self.write(&nonces).await {
+            // Keep memory and disk in agreement
+            match previous {
+                Some(previous) => nonces.insert(intent_id.to_string(), previous),
+                None => nonces.remove(intent_id),
+            };
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    async fn get(&self, intent_id: &str) -> Result<Option<BridgeNonce>, NaisuError> {
+        Ok(self.nonces.lock().await.get(intent_id).cloned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_file_store_survives_reopen() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nonces.json");
+        let nonce = BridgeNonce {
+            burn_tx_hash: "0xburn".to_string(),
+            nonce: "42".to_string(),
+            message_hash: None,
+        };
+
+        FileNonceStore::open(&path)
+            .unwrap()
+            .put("intent-1", nonce.clone())
+            .await
+            .unwrap();
+
+        let reopened = FileNonceStore::open(&path).unwrap();
+        assert_eq!(reopened.get("intent-1").await.unwrap(), Some(nonce));
+        assert_eq!(reopened.get("intent-2").await.unwrap(), None);
+    }
+}
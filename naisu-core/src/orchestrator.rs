@@ -0,0 +1,837 @@
+//! Intent orchestration
+//!
+//! Drives an intent through its lifecycle one step at a time. Every chain
+//! interaction goes through a trait object ([`EvmOps`], [`SuiOps`],
+//! [`BridgeOps`]), so the same state machine runs against real clients
+//! or in-memory mocks.
+//!
+//! EvmToSui: swap → burn → attestation + mint → deposit → completed
+//! SuiToEvm: withdraw → burn → attestation + mint → [output swap] → completed
+//! SuiWithdraw: withdraw → completed, never touching the bridge
+//!
+//! A message the destination has already received (e.g. relayed by Circle)
+//! skips the attestation and mint.
+//!
+//! Burns are recorded in a [`NonceStore`] before the intent moves on, so an
+//! orchestrator restarted mid-bridge resumes at the attestation instead of
+//! burning twice.
+//!
+//! When attestation polling runs out of budget, an EVM burn is looked up
+//! again to tell a slow attestation from a burn that was reorged out; the
+//! verdict is kept in `Intent::bridge_failure`.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tracing::{info, warn};
+
+use crate::{
+    AllocationStatus, BridgeFailure, BridgeNonce, Direction, InMemoryNonceStore, Intent,
+    IntentStatus, NaisuError, NonceStore,
+};
+
+/// Output of the step that produces the USDC to bridge
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UsdcReceipt {
+    pub tx_hash: String,
+    /// USDC obtained, smallest unit
+    pub usdc_amount: String,
+}
+
+/// Output of the destination swap out of USDC
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputReceipt {
+    pub tx_hash: String,
+    /// `intent.output_token` delivered, smallest unit
+    pub amount: String,
+}
+
+/// A CCTP burn on the source chain
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BurnReceipt {
+    pub tx_hash: String,
+    pub nonce: String,
+    /// keccak256 of the CCTP message, if known
+    pub message_hash: Option<String>,
+}
+
+/// Circle's signed burn message
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attestation {
+    pub message: String,
+    pub attestation: String,
+}
+
+/// EVM-side actions
+#[async_trait]
+pub trait EvmOps: Send + Sync {
+    /// Swap the intent's input token to USDC (through the V4 hook)
+    async fn swap_to_usdc(&self, intent: &Intent) -> Result<UsdcReceipt, NaisuError>;
+
+    /// Expected output of swapping `usdc_amount` minted USDC into `token_out`
+    async fn quote_from_usdc(
+        &self,
+        intent: &Intent,
+        token_out: &str,
+        usdc_amount: &str,
+    ) -> Result<String, NaisuError>;
+
+    /// Swap the USDC minted for a SuiToEvm intent into `token_out`, reverting
+    /// below `min_amount_out`, delivering to the destination address
+    async fn swap_from_usdc(
+        &self,
+        intent: &Intent,
+        token_out: &str,
+        min_amount_out: &str,
+    ) -> Result<OutputReceipt, NaisuError>;
+
+    /// Unwrap `amount` WETH held by the destination address into native ETH,
+    /// returning the tx hash
+    async fn unwrap_weth(&self, intent: &Intent, amount: &str) -> Result<String, NaisuError>;
+
+    /// Whether `tx_hash` is still included on `intent.evm_chain` (false once
+    /// it has been reorged out)
+    async fn tx_included(&self, intent: &Intent, tx_hash: &str) -> Result<bool, NaisuError>;
+}
+
+/// Sui-side actions
+#[async_trait]
+pub trait SuiOps: Send + Sync {
+    /// Withdraw the intent's USDC from its Sui position
+    async fn withdraw_usdc(&self, intent: &Intent) -> Result<UsdcReceipt, NaisuError>;
+
+    /// Deposit bridged USDC into the intent's strategy, or split it across
+    /// `intent.allocations` in one transaction, returning the tx hash
+    async fn deposit(&self, intent: &Intent) -> Result<String, NaisuError>;
+}
+
+/// Moves USDC between chains (burn, attest, mint)
+#[async_trait]
+pub trait BridgeOps: Send + Sync {
+    /// Burn `intent.usdc_amount` on the source chain
+    async fn burn(&self, intent: &Intent) -> Result<BurnReceipt, NaisuError>;
+
+    /// Wait for Circle to attest the burn; gives up with
+    /// [`NaisuError::AttestationTimeout`] once its polling budget is spent
+    async fn attestation(&self, intent: &Intent, nonce: &str) -> Result<Attestation, NaisuError>;
+
+    /// Whether the destination chain has already received the message for
+    /// `nonce`, i.e. someone else minted it
+    async fn already_received(&self, intent: &Intent, nonce: &str) -> Result<bool, NaisuError>;
+
+    /// Mint on the destination chain, returning the tx hash
+    async fn mint(&self, intent: &Intent, attestation: &Attestation) -> Result<String, NaisuError>;
+}
+
+/// Runs intents against injected chain backends
+#[derive(Clone)]
+pub struct IntentOrchestrator {
+    evm: Arc<dyn EvmOps>,
+    sui: Arc<dyn SuiOps>,
+    bridge: Arc<dyn BridgeOps>,
+    nonces: Arc<dyn NonceStore>,
+}
+
+impl IntentOrchestrator {
+    /// Orchestrator with an in-memory nonce store; use
+    /// [`IntentOrchestrator::with_nonce_store`] to survive restarts
+    pub fn new(evm: Arc<dyn EvmOps>, sui: Arc<dyn SuiOps>, bridge: Arc<dyn BridgeOps>) -> Self {
+        Self {
+            evm,
+            sui,
+            bridge,
+            nonces: Arc::new(InMemoryNonceStore::default()),
+        }
+    }
+
+    pub fn with_nonce_store(mut self, nonces: Arc<dyn NonceStore>) -> Self {
+        self.nonces = nonces;
+        self
+    }
+
+    /// Perform the next step of `intent` and return its new status. A failing
+    /// step marks the intent failed. Terminal intents are left untouched.
+    pub async fn step(&self, intent: &mut Intent) -> IntentStatus {
+        if intent.status.is_terminal() {
+            return intent.status;
+        }
+        match self.advance(intent).await {
+            Ok(status) => intent.set_status(status),
+            Err(e) => intent.fail(format!(
+                "{} failed: {}",
+                next_step_name(intent.direction, intent.status),
+                e
+            )),
+        }
+        intent.status
+    }
+
+    /// Step until `intent` is terminal
+    pub async fn run(&self, intent: &mut Intent) -> IntentStatus {
+        while !intent.status.is_terminal() {
+            self.step(intent).await;
+        }
+        intent.status
+    }
+
+    async fn advance(&self, intent: &mut Intent) -> Result<IntentStatus, NaisuError> {
+        use IntentStatus::*;
+
+        match (intent.direction, intent.status) {
+            (Direction::EvmToSui, Pending) => {
+                let swap = self.evm.swap_to_usdc(intent).await?;
+                intent.swap_tx_hash = Some(swap.tx_hash);
+                intent.usdc_amount = Some(swap.usdc_amount);
+                Ok(SwapCompleted)
+            }
+            (Direction::SuiWithdraw, Pending) => {
+                // The withdrawn coin is already Sui USDC at the user's address
+                let withdraw = self.sui.withdraw_usdc(intent).await?;
+                intent.dest_tx_hash = Some(withdraw.tx_hash);
+                intent.usdc_amount = Some(withdraw.usdc_amount);
+                Ok(Completed)
+            }
+            (Direction::SuiWithdraw, status) => Err(NaisuError::InvalidState {
+                expected: "pending".to_string(),
+                actual: status.as_str().to_string(),
+            }),
+            (Direction::SuiToEvm, Pending) => {
+                let withdraw = self.sui.withdraw_usdc(intent).await?;
+                intent.swap_tx_hash = Some(withdraw.tx_hash);
+                intent.usdc_amount = Some(withdraw.usdc_amount);
+                Ok(SwapCompleted)
+            }
+            (_, SwapCompleted) => {
+                // A recorded burn means we crashed after burning: don't burn again
+                if let Some(recorded) = self.nonces.get(&intent.id).await? {
+                    restore_burn(intent, recorded);
+                    return Ok(Bridging);
+                }
+                let burn = self.bridge.burn(intent).await?;
+                let recorded = BridgeNonce {
+                    burn_tx_hash: burn.tx_hash,
+                    nonce: burn.nonce,
+                    message_hash: burn.message_hash,
+                };
+                restore_burn(intent, recorded.clone());
+                // The USDC is burned either way; failing here would strand it
+                if let Err(e) = self.nonces.put(&intent.id, recorded).await {
+                    warn!(
+                        intent_id = %intent.id,
+                        burn_tx = ?intent.bridge_tx_hash,
+                        error = %e,
+                        "could not record burn nonce; a restart before the mint will not resume"
+                    );
+                }
+                Ok(Bridging)
+            }
+            (_, Bridging) => {
+                if intent.bridge_nonce.is_none() {
+                    if let Some(recorded) = self.nonces.get(&intent.id).await? {
+                        restore_burn(intent, recorded);
+                    }
+                }
+                let nonce =
+                    intent
+                        .bridge_nonce
+                        .clone()
+                        .ok_or_else(|| NaisuError::InvalidState {
+                            expected: "a bridge nonce".to_string(),
+                            actual: "none".to_string(),
+                        })?;
+                if self.bridge.already_received(intent, &nonce).await? {
+                    info!(intent_id = %intent.id, nonce, "CCTP message already received, skipping mint");
+                    return Ok(BridgeCompleted);
+                }
+                let attestation = match self.bridge.attestation(intent, &nonce).await {
+                    Err(NaisuError::AttestationTimeout(_)) => {
+                        return Err(self.diagnose_unattested_burn(intent).await);
+                    }
+                    result => result?,
+                };
+                let mint = self.bridge.mint(intent, &attestation).await?;
+                intent.mint_tx_hash = Some(mint.clone());
+                intent.dest_tx_hash = Some(mint);
+                Ok(BridgeCompleted)
+            }
+            (Direction::EvmToSui, BridgeCompleted) => {
+                if intent.is_split() {
+                    if let Err(e) = intent.split_allocations() {
+                        intent.set_allocation_status(AllocationStatus::Failed);
+                        return Err(e);
+                    }
+                }
+                match self.sui.deposit(intent).await {
+                    Ok(tx_hash) => {
+                        intent.deposit_tx_hash = Some(tx_hash.clone());
+                        intent.dest_tx_hash = Some(tx_hash);
+                        intent.set_allocation_status(AllocationStatus::Deposited);
+                        Ok(Deposited)
+                    }
+                    Err(e) => {
+                        intent.set_allocation_status(AllocationStatus::Failed);
+                        Err(e)
+                    }
+                }
+            }
+            (Direction::SuiToEvm, BridgeCompleted) => {
+                if let Some(token_out) = intent.swap_output_token().map(str::to_string) {
+                    // A resumed intent may have swapped already
+                    if intent.output_swap_tx_hash.is_none() {
+                        let minted = intent.usdc_amount.clone().unwrap_or_default();
+                        let quoted = self
+                            .evm
+                            .quote_from_usdc(intent, &token_out, &minted)
+                            .await?;
+                        let min_out = intent.min_output_amount(&quoted)?;
+                        let swap = self
+                            .evm
+                            .swap_from_usdc(intent, &token_out, &min_out)
+                            .await?;
+                        intent.output_swap_tx_hash = Some(swap.tx_hash);
+                        intent.output_amount = Some(swap.amount);
+                    }
+                    if intent.unwraps_output() && intent.unwrap_tx_hash.is_none() {
+                        let amount = intent.output_amount.clone().unwrap_or_default();
+                        intent.unwrap_tx_hash = Some(self.evm.unwrap_weth(intent, &amount).await?);
+                    }
+                } else {
+                    // Standard CCTP mints exactly what was burned
+                    intent.output_token = intent.evm_chain.map(|c| c.usdc_address().to_string());
+                    intent.output_amount = intent.usdc_amount.clone();
+                }
+                Ok(Completed)
+            }
+            (_, Deposited) => Ok(Completed),
+            (_, status) => Err(NaisuError::InvalidState {
+                expected: "a non-terminal status".to_string(),
+                actual: status.as_str().to_string(),
+            }),
+        }
+    }
+
+    /// Classify a burn Circle never attested, record the verdict on the
+    /// intent, and return the error to fail it with
+    async fn diagnose_unattested_burn(&self, intent: &mut Intent) -> NaisuError {
+        let burn = intent.bridge_tx_hash.clone().unwrap_or_default();
+
+        // Sui has no reorgs, so only an EVM burn can have disappeared
+        let included = match intent.direction {
+            Direction::EvmToSui => self.evm.tx_included(intent, &burn).await,
+            _ => Ok(true),
+        };
+
+        let (failure, message) = match included {
+            Ok(false) => (
+                BridgeFailure::BurnDropped,
+                format!(
+                    "burn tx {} was dropped from {} (reorged out); no USDC was burned",
+                    burn,
+                    intent.evm_chain.map_or("the EVM chain", |c| c.name())
+                ),
+            ),
+            Ok(true) => (
+                BridgeFailure::AttestationPending,
+                format!(
+                    "attestation still pending after its budget; burn tx {} is on chain",
+                    burn
+                ),
+            ),
+            Err(e) => (
+                BridgeFailure::AttestationPending,
+                format!(
+                    "attestation still pending after its budget; burn tx {} could not be checked: {}",
+                    burn, e
+                ),
+            ),
+        };
+        intent.bridge_failure = Some(failure);
+        NaisuError::Bridge(message)
+    }
+}
+
+/// Put a recorded burn back on the intent, starting its bridge deadline if
+/// this is the first we hear of it
+fn restore_burn(intent: &mut Intent, recorded: BridgeNonce) {
+    intent.bridge_tx_hash = Some(recorded.burn_tx_hash);
+    intent.bridge_nonce = Some(recorded.nonce);
+    intent.start_bridge_clock(chrono::Utc::now().timestamp());
+}
+
+/// Name of the step taken from `status`, for error messages
+fn next_step_name(direction: Direction, status: IntentStatus) -> &'static str {
+    match (direction, status) {
+        (Direction::EvmToSui, IntentStatus::Pending) => "swap",
+        (Direction::SuiToEvm | Direction::SuiWithdraw, IntentStatus::Pending) => "withdraw",
+        (_, IntentStatus::SwapCompleted) => "burn",
+        (_, IntentStatus::Bridging) => "attestation",
+        (Direction::SuiToEvm, IntentStatus::BridgeCompleted) => "output swap",
+        (_, IntentStatus::BridgeCompleted) => "deposit",
+        _ => "completion",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{orchestrator, Script, Step};
+    use crate::EvmChain;
+
+    fn evm_to_sui() -> Intent {
+        Intent::new_evm_to_sui(
+            "0x01".to_string(),
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed".to_string(),
+            "0x2".to_string(),
+            EvmChain::BaseSepolia,
+            "0xweth".to_string(),
+            "1000000000000000".to_string(),
+            crate::YieldStrategy::ScallopUsdc,
+        )
+    }
+
+    fn split_evm_to_sui(scallop: &str, navi: &str) -> Intent {
+        Intent::new_evm_to_sui_split(
+            "0x03".to_string(),
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed".to_string(),
+            "0x2".to_string(),
+            EvmChain::BaseSepolia,
+            "0xusdc".to_string(),
+            "990000".to_string(),
+            vec![
+                crate::StrategyAllocation {
+                    strategy: crate::YieldStrategy::ScallopUsdc,
+                    amount: scallop.to_string(),
+                },
+                crate::StrategyAllocation {
+                    strategy: crate::YieldStrategy::NaviUsdc,
+                    amount: navi.to_string(),
+                },
+            ],
+        )
+    }
+
+    fn sui_to_evm() -> Intent {
+        Intent::new_sui_to_evm(
+            "0x02".to_string(),
+            "0x2".to_string(),
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed".to_string(),
+            EvmChain::BaseSepolia,
+            "usdc".to_string(),
+            "1000000".to_string(),
+        )
+    }
+
+    fn sui_withdraw() -> Intent {
+        Intent::new_sui_withdraw(
+            "0x04".to_string(),
+            "0x2".to_string(),
+            "usdc".to_string(),
+            "1000000".to_string(),
+            crate::YieldStrategy::NaviUsdc,
+        )
+    }
+
+    async fn statuses(orchestrator: &IntentOrchestrator, intent: &mut Intent) -> Vec<IntentStatus> {
+        let mut seen = vec![intent.status];
+        while !intent.status.is_terminal() {
+            seen.push(orchestrator.step(intent).await);
+        }
+        seen
+    }
+
+    #[tokio::test]
+    async fn test_evm_to_sui_happy_path() {
+        let script = Arc::new(Script::default());
+        let mut intent = evm_to_sui();
+
+        let seen = statuses(&orchestrator(&script), &mut intent).await;
+
+        use IntentStatus::*;
+        assert_eq!(
+            seen,
+            [
+                Pending,
+                SwapCompleted,
+                Bridging,
+                BridgeCompleted,
+                Deposited,
+                Completed
+            ]
+        );
+        assert_eq!(
+            script.calls(),
+            [
+                Step::Swap,
+                Step::Burn,
+                Step::ReceivedCheck,
+                Step::Attestation,
+                Step::Mint,
+                Step::Deposit
+            ]
+        );
+        assert_eq!(intent.usdc_amount.as_deref(), Some("990000"));
+        assert_eq!(intent.bridge_nonce.as_deref(), Some("42"));
+        assert_eq!(intent.dest_tx_hash.as_deref(), Some("0xdeposit"));
+        // The deposit doesn't hide which tx minted
+        assert_eq!(intent.mint_tx_hash.as_deref(), Some("0xmint"));
+        assert_eq!(intent.deposit_tx_hash.as_deref(), Some("0xdeposit"));
+    }
+
+    #[tokio::test]
+    async fn test_evm_to_sui_fails_at_attestation() {
+        let script = Script::failing_at(Step::Attestation);
+        let mut intent = evm_to_sui();
+
+        let status = orchestrator(&script).run(&mut intent).await;
+
+        assert_eq!(status, IntentStatus::Failed);
+        assert_eq!(
+            script.calls(),
+            [
+                Step::Swap,
+                Step::Burn,
+                Step::ReceivedCheck,
+                Step::Attestation
+            ]
+        );
+        assert_eq!(intent.bridge_tx_hash.as_deref(), Some("0xburn"));
+        assert!(intent
+            .error_message
+            .as_deref()
+            .unwrap()
+            .starts_with("attestation failed"));
+    }
+
+    #[tokio::test]
+    async fn test_attestation_timeout_with_burn_on_chain_is_pending() {
+        let script = Arc::new(Script {
+            attestation_times_out: true,
+            ..Default::default()
+        });
+        let mut intent = evm_to_sui();
+
+        let status = orchestrator(&script).run(&mut intent).await;
+
+        assert_eq!(status, IntentStatus::Failed);
+        assert_eq!(
+            script.calls(),
+            [
+                Step::Swap,
+                Step::Burn,
+                Step::ReceivedCheck,
+                Step::Attestation,
+                Step::BurnLookup
+            ]
+        );
+        assert_eq!(
+            intent.bridge_failure,
+            Some(BridgeFailure::AttestationPending)
+        );
+        assert!(intent
+            .error_message
+            .as_deref()
+            .unwrap()
+            .contains("attestation still pending after its budget"));
+    }
+
+    #[tokio::test]
+    async fn test_attestation_timeout_after_reorg_reports_dropped_burn() {
+        let script = Arc::new(Script {
+            attestation_times_out: true,
+            burn_dropped: true,
+            ..Default::default()
+        });
+        let mut intent = evm_to_sui();
+
+        let status = orchestrator(&script).run(&mut intent).await;
+
+        assert_eq!(status, IntentStatus::Failed);
+        assert_eq!(intent.bridge_failure, Some(BridgeFailure::BurnDropped));
+        assert!(intent
+            .error_message
+            .as_deref()
+            .unwrap()
+            .contains("burn tx 0xburn was dropped from Base Sepolia"));
+    }
+
+    #[tokio::test]
+    async fn test_already_received_message_skips_the_mint() {
+        let script = Arc::new(Script {
+            already_received: true,
+            ..Default::default()
+        });
+        let mut intent = sui_to_evm();
+
+        let seen = statuses(&orchestrator(&script), &mut intent).await;
+
+        use IntentStatus::*;
+        assert_eq!(
+            seen,
+            [Pending, SwapCompleted, Bridging, BridgeCompleted, Completed]
+        );
+        assert_eq!(
+            script.calls(),
+            [Step::Withdraw, Step::Burn, Step::ReceivedCheck]
+        );
+        assert_eq!(intent.mint_tx_hash, None);
+        assert_eq!(intent.output_amount.as_deref(), Some("1000000"));
+    }
+
+    #[tokio::test]
+    async fn test_split_intent_deposits_every_allocation() {
+        let script = Arc::new(Script::default());
+        let mut intent = split_evm_to_sui("594000", "396000");
+
+        let status = orchestrator(&script).run(&mut intent).await;
+
+        assert_eq!(status, IntentStatus::Completed);
+        assert_eq!(script.calls().last(), Some(&Step::Deposit));
+        assert!(intent
+            .allocations
+            .iter()
+            .all(|a| a.status == AllocationStatus::Deposited));
+    }
+
+    #[tokio::test]
+    async fn test_split_intent_fails_when_allocations_miss_the_input() {
+        let script = Arc::new(Script::default());
+        let mut intent = split_evm_to_sui("600000", "400000");
+
+        let status = orchestrator(&script).run(&mut intent).await;
+
+        assert_eq!(status, IntentStatus::Failed);
+        assert!(!script.calls().contains(&Step::Deposit));
+        assert!(intent
+            .allocations
+            .iter()
+            .all(|a| a.status == AllocationStatus::Failed));
+        assert!(intent
+            .error_message
+            .as_deref()
+            .unwrap()
+            .starts_with("deposit failed"));
+    }
+
+    #[tokio::test]
+    async fn test_sui_to_evm_happy_path() {
+        let script = Arc::new(Script::default());
+        let mut intent = sui_to_evm();
+
+        let seen = statuses(&orchestrator(&script), &mut intent).await;
+
+        use IntentStatus::*;
+        assert_eq!(
+            seen,
+            [Pending, SwapCompleted, Bridging, BridgeCompleted, Completed]
+        );
+        assert_eq!(
+            script.calls(),
+            [
+                Step::Withdraw,
+                Step::Burn,
+                Step::ReceivedCheck,
+                Step::Attestation,
+                Step::Mint
+            ]
+        );
+        assert_eq!(intent.dest_tx_hash.as_deref(), Some("0xmint"));
+    }
+
+    #[tokio::test]
+    async fn test_sui_to_evm_swaps_into_requested_output_token() {
+        let chain = EvmChain::BaseSepolia;
+
+        let script = Arc::new(Script::default());
+        let mut intent = sui_to_evm();
+        intent.output_token = Some(chain.weth_address().to_string());
+        orchestrator(&script).run(&mut intent).await;
+
+        assert_eq!(
+            script.calls(),
+            [
+                Step::Withdraw,
+                Step::Burn,
+                Step::ReceivedCheck,
+                Step::Attestation,
+                Step::Mint,
+                Step::Quote,
+                Step::OutputSwap
+            ]
+        );
+        assert_eq!(intent.status, IntentStatus::Completed);
+        assert_eq!(intent.output_swap_tx_hash.as_deref(), Some("0xoutputswap"));
+        // The swap doesn't hide the mint
+        assert_eq!(intent.dest_tx_hash.as_deref(), Some("0xmint"));
+        assert_eq!(intent.mint_tx_hash.as_deref(), Some("0xmint"));
+        assert_eq!(intent.output_amount.as_deref(), Some("399000000000000"));
+        assert_eq!(intent.unwrap_tx_hash, None);
+
+        // USDC out needs no swap: the minted amount is what's delivered
+        let script = Arc::new(Script::default());
+        let mut intent = sui_to_evm();
+        intent.output_token = Some(chain.usdc_address().to_lowercase());
+        orchestrator(&script).run(&mut intent).await;
+
+        assert!(!script.calls().contains(&Step::OutputSwap));
+        assert_eq!(intent.dest_tx_hash.as_deref(), Some("0xmint"));
+        assert_eq!(intent.output_amount.as_deref(), Some("1000000"));
+    }
+
+    #[tokio::test]
+    async fn test_sui_to_evm_native_eth_output_unwraps_weth() {
+        let script = Arc::new(Script::default());
+        let mut intent = sui_to_evm();
+        intent.output_token = Some(crate::NATIVE_ETH_SENTINEL.to_string());
+        orchestrator(&script).run(&mut intent).await;
+
+        assert_eq!(intent.status, IntentStatus::Completed);
+        assert!(script
+            .calls()
+            .ends_with(&[Step::Quote, Step::OutputSwap, Step::Unwrap]));
+        assert_eq!(intent.unwrap_tx_hash.as_deref(), Some("0xunwrap"));
+        assert_eq!(
+            intent.output_token.as_deref(),
+            Some(crate::NATIVE_ETH_SENTINEL)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_retried_unwrap_does_not_swap_again() {
+        let script = Script::failing_at(Step::Unwrap);
+        let mut intent = sui_to_evm();
+        intent.output_token = Some(crate::NATIVE_ETH_SENTINEL.to_string());
+        intent.status = IntentStatus::BridgeCompleted;
+        intent.usdc_amount = Some("1000000".to_string());
+        assert!(orchestrator(&script).advance(&mut intent).await.is_err());
+        assert_eq!(intent.output_swap_tx_hash.as_deref(), Some("0xoutputswap"));
+
+        let retry = Arc::new(Script::default());
+        assert!(matches!(
+            orchestrator(&retry).advance(&mut intent).await,
+            Ok(IntentStatus::Completed)
+        ));
+        assert_eq!(retry.calls(), [Step::Unwrap]);
+    }
+
+    #[tokio::test]
+    async fn test_sui_withdraw_completes_without_bridging() {
+        let script = Arc::new(Script::default());
+        let mut intent = sui_withdraw();
+
+        let seen = statuses(&orchestrator(&script), &mut intent).await;
+
+        assert_eq!(seen, [IntentStatus::Pending, IntentStatus::Completed]);
+        assert_eq!(script.calls(), [Step::Withdraw]);
+        assert_eq!(intent.dest_tx_hash.as_deref(), Some("0xwithdraw"));
+        assert_eq!(intent.bridge_nonce, None);
+    }
+
+    #[tokio::test]
+    async fn test_sui_to_evm_fails_at_attestation() {
+        let script = Script::failing_at(Step::Attestation);
+        let mut intent = sui_to_evm();
+
+        let status = orchestrator(&script).run(&mut intent).await;
+
+        assert_eq!(status, IntentStatus::Failed);
+        assert_eq!(intent.dest_tx_hash, None);
+        assert!(intent
+            .error_message
+            .as_deref()
+            .unwrap()
+            .contains("Attestation rejected"));
+    }
+
+    #[tokio::test]
+    async fn test_restarted_orchestrator_resumes_at_attestation() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nonces.json");
+        let script = Arc::new(Script::default());
+        let mut intent = evm_to_sui();
+
+        // First run burns, then "crashes" before the new status is saved
+        let first = orchestrator(&script)
+            .with_nonce_store(Arc::new(crate::FileNonceStore::open(&path).unwrap()));
+        first.step(&mut intent).await;
+        let saved = intent.clone();
+        assert_eq!(first.step(&mut intent).await, IntentStatus::Bridging);
+        drop(first);
+
+        let mut intent = saved;
+        assert_eq!(intent.status, IntentStatus::SwapCompleted);
+        assert_eq!(intent.bridge_nonce, None);
+
+        let script = Arc::new(Script::default());
+        let restarted = orchestrator(&script)
+            .with_nonce_store(Arc::new(crate::FileNonceStore::open(&path).unwrap()));
+        let seen = statuses(&restarted, &mut intent).await;
+
+        use IntentStatus::*;
+        assert_eq!(
+            seen,
+            [
+                SwapCompleted,
+                Bridging,
+                BridgeCompleted,
+                Deposited,
+                Completed
+            ]
+        );
+        assert_eq!(
+            script.calls(),
+            [
+                Step::ReceivedCheck,
+                Step::Attestation,
+                Step::Mint,
+                Step::Deposit
+            ]
+        );
+        assert_eq!(intent.bridge_nonce.as_deref(), Some("42"));
+        assert_eq!(intent.bridge_tx_hash.as_deref(), Some("0xburn"));
+    }
+
+    /// Store whose writes always fail
+    struct ReadOnlyNonceStore;
+
+    #[async_trait]
+    impl NonceStore for ReadOnlyNonceStore {
+        async fn put(&self, _: &str, _: BridgeNonce) -> Result<(), NaisuError> {
+            Err(NaisuError::Database("disk full".to_string()))
+        }
+
+        async fn get(&self, _: &str) -> Result<Option<BridgeNonce>, NaisuError> {
+            Ok(None)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_unrecorded_burn_still_bridges() {
+        let script = Arc::new(Script::default());
+        let mut intent = evm_to_sui();
+
+        let status = orchestrator(&script)
+            .with_nonce_store(Arc::new(ReadOnlyNonceStore))
+            .run(&mut intent)
+            .await;
+
+        assert_eq!(status, IntentStatus::Completed);
+        assert_eq!(intent.bridge_nonce.as_deref(), Some("42"));
+        assert_eq!(
+            script.calls().iter().filter(|s| **s == Step::Burn).count(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn test_terminal_intent_is_not_stepped() {
+        let script = Arc::new(Script::default());
+        let mut intent = sui_to_evm();
+        intent.set_status(IntentStatus::Cancelled);
+
+        assert_eq!(
+            orchestrator(&script).step(&mut intent).await,
+            IntentStatus::Cancelled
+        );
+        assert!(script.calls().is_empty());
+    }
+}
@@ -1,9 +1,12 @@
 //! Yield strategies on Sui
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::chain::{SuiNetwork, SUI_COIN_TYPE};
 
 /// Available yield strategies on Sui
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum YieldStrategy {
     /// Scallop USDC lending pool
@@ -19,6 +22,14 @@ pub enum YieldStrategy {
 }
 
 impl YieldStrategy {
+    /// Every built-in strategy (excludes `Custom`)
+    pub const ALL: [YieldStrategy; 4] = [
+        YieldStrategy::ScallopUsdc,
+        YieldStrategy::ScallopSui,
+        YieldStrategy::NaviUsdc,
+        YieldStrategy::NaviSui,
+    ];
+
     /// Get strategy ID for on-chain encoding
     pub fn id(&self) -> u8 {
         match self {
@@ -70,12 +81,37 @@ impl YieldStrategy {
         }
     }
 
+    /// Fully-qualified Sui coin type of [`YieldStrategy::asset`] on `network`.
+    /// `None` for custom strategies, whose asset is unknown.
+    pub fn sui_coin_type(&self, network: SuiNetwork) -> Option<&'static str> {
+        match self {
+            YieldStrategy::ScallopUsdc | YieldStrategy::NaviUsdc => Some(network.usdc_coin_type()),
+            YieldStrategy::ScallopSui | YieldStrategy::NaviSui => Some(SUI_COIN_TYPE),
+            YieldStrategy::Custom(_) => None,
+        }
+    }
+
     /// Whether this strategy requires a swap on Sui (USDC -> SUI)
     pub fn requires_sui_swap(&self) -> bool {
         matches!(self, YieldStrategy::ScallopSui | YieldStrategy::NaviSui)
     }
 }
 
+impl std::str::FromStr for YieldStrategy {
+    type Err = String;
+
+    /// Parse a built-in strategy from its serialized key (e.g. `scallop_usdc`)
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "scallop_usdc" => Ok(YieldStrategy::ScallopUsdc),
+            "scallop_sui" => Ok(YieldStrategy::ScallopSui),
+            "navi_usdc" => Ok(YieldStrategy::NaviUsdc),
+            "navi_sui" => Ok(YieldStrategy::NaviSui),
+            _ => Err(format!("Unknown strategy: {}", s)),
+        }
+    }
+}
+
 /// Strategy info with APY data
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StrategyInfo {
@@ -101,3 +137,29 @@ impl StrategyInfo {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_usdc_strategies_resolve_per_network() {
+        let testnet =
+            "0xa1ec7fc00a6f40db9693ad1415d0c193ad3906494428cf252621037bd7117e29::usdc::USDC";
+        let mainnet =
+            "0xdba34672e30cb065b1f93e3ab55318768fd6fef66c15942c9f7cb846e2f900e7::usdc::USDC";
+
+        for strategy in [YieldStrategy::ScallopUsdc, YieldStrategy::NaviUsdc] {
+            assert_eq!(strategy.sui_coin_type(SuiNetwork::Testnet), Some(testnet));
+            assert_eq!(strategy.sui_coin_type(SuiNetwork::Mainnet), Some(mainnet));
+        }
+        assert_eq!(
+            YieldStrategy::NaviSui.sui_coin_type(SuiNetwork::Mainnet),
+            Some("0x2::sui::SUI")
+        );
+        assert_eq!(
+            YieldStrategy::Custom(1).sui_coin_type(SuiNetwork::Testnet),
+            None
+        );
+    }
+}
@@ -0,0 +1,209 @@
+//! Test fixtures shared across crates
+//!
+//! [`Script`] records which steps the orchestrator took and fails the one it
+//! is told to. Every backend answers with fixed receipts (a USDC amount of
+//! `990000`, burn nonce `42`, ...), so tests can assert on them.
+//! [`intent_created_log`] is a well-formed hook log. Enabled in other crates'
+//! tests through the `testing` feature.
+
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+
+use crate::{
+    Attestation, BridgeOps, BurnReceipt, EvmLog, EvmOps, Intent, IntentCreatedEvent,
+    IntentOrchestrator, NaisuError, OutputReceipt, SuiOps, UsdcReceipt,
+};
+
+/// An `IntentCreated` log from the Base Sepolia hook in block 100:
+/// 0x5aAe…eAed deposits 5 USDC (4.99 after fees) into Scallop USDC.
+/// `intent_id` is hex, with or without `0x`, and is left-padded to bytes32.
+pub fn intent_created_log(intent_id: &str) -> EvmLog {
+    let word = |v: u128| format!("{:064x}", v);
+    let data = [
+        word(0xabc),
+        format!("{:0>64}", "036cbd53842c5426634e7929541ec2318f3dcf7e"),
+        word(5_000_000),
+        word(4_990_000),
+        word(1),
+        word(1_700_000_000),
+    ]
+    .concat();
+    let id = intent_id.strip_prefix("0x").unwrap_or(intent_id);
+
+    EvmLog {
+        address: "0xf0093fcf07aa10de35b78d1f33c60439d11bc0c0".to_string(),
+        topics: vec![
+            IntentCreatedEvent::topic0(),
+            format!("0x{:0>64}", id.to_lowercase()),
+            format!("0x{:0>64}", "5aaeb6053f3e94c9b9a09f33669435e7ef1beaed"),
+        ],
+        data: format!("0x{}", data),
+        transaction_hash: None,
+        block_number: Some("0x64".to_string()),
+    }
+}
+
+/// Steps a mock can be told to fail
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    Swap,
+    Withdraw,
+    Burn,
+    Attestation,
+    Mint,
+    Deposit,
+    BurnLookup,
+    ReceivedCheck,
+    Quote,
+    OutputSwap,
+    Unwrap,
+}
+
+/// Records calls and fails the configured step
+#[derive(Default)]
+pub struct Script {
+    pub fail_at: Option<Step>,
+    pub calls: Mutex<Vec<Step>>,
+    /// Attestation polling runs out of budget
+    pub attestation_times_out: bool,
+    /// The burn tx is no longer on the source chain
+    pub burn_dropped: bool,
+    /// The destination already received the burn's message
+    pub already_received: bool,
+}
+
+impl Script {
+    pub fn failing_at(step: Step) -> Arc<Self> {
+        Arc::new(Self {
+            fail_at: Some(step),
+            ..Default::default()
+        })
+    }
+
+    pub fn call(&self, step: Step, error: fn(String) -> NaisuError) -> Result<(), NaisuError> {
+        self.calls.lock().unwrap().push(step);
+        match self.fail_at {
+            Some(fail) if fail == step => Err(error(format!("{:?} rejected", step))),
+            _ => Ok(()),
+        }
+    }
+
+    pub fn calls(&self) -> Vec<Step> {
+        self.calls.lock().unwrap().clone()
+    }
+}
+
+pub struct MockEvm(pub Arc<Script>);
+pub struct MockSui(pub Arc<Script>);
+pub struct MockBridge(pub Arc<Script>);
+
+#[async_trait]
+impl EvmOps for MockEvm {
+    async fn swap_to_usdc(&self, _: &Intent) -> Result<UsdcReceipt, NaisuError> {
+        self.0.call(Step::Swap, NaisuError::Evm)?;
+        Ok(UsdcReceipt {
+            tx_hash: "0xswap".to_string(),
+            usdc_amount: "990000".to_string(),
+        })
+    }
+
+    async fn quote_from_usdc(
+        &self,
+        intent: &Intent,
+        token_out: &str,
+        usdc_amount: &str,
+    ) -> Result<String, NaisuError> {
+        assert_eq!(Some(token_out), intent.swap_output_token());
+        assert_eq!(Some(usdc_amount), intent.usdc_amount.as_deref());
+        self.0.call(Step::Quote, NaisuError::Evm)?;
+        Ok("400000000000000".to_string())
+    }
+
+    async fn swap_from_usdc(
+        &self,
+        _: &Intent,
+        _: &str,
+        min_amount_out: &str,
+    ) -> Result<OutputReceipt, NaisuError> {
+        // The quote less the default 50 bps
+        assert_eq!(min_amount_out, "398000000000000");
+        self.0.call(Step::OutputSwap, NaisuError::Evm)?;
+        Ok(OutputReceipt {
+            tx_hash: "0xoutputswap".to_string(),
+            amount: "399000000000000".to_string(),
+        })
+    }
+
+    async fn unwrap_weth(&self, _: &Intent, amount: &str) -> Result<String, NaisuError> {
+        assert_eq!(amount, "399000000000000");
+        self.0.call(Step::Unwrap, NaisuError::Evm)?;
+        Ok("0xunwrap".to_string())
+    }
+
+    async fn tx_included(&self, _: &Intent, tx_hash: &str) -> Result<bool, NaisuError> {
+        assert_eq!(tx_hash, "0xburn");
+        self.0.call(Step::BurnLookup, NaisuError::Evm)?;
+        Ok(!self.0.burn_dropped)
+    }
+}
+
+#[async_trait]
+impl SuiOps for MockSui {
+    async fn withdraw_usdc(&self, _: &Intent) -> Result<UsdcReceipt, NaisuError> {
+        self.0.call(Step::Withdraw, NaisuError::Sui)?;
+        Ok(UsdcReceipt {
+            tx_hash: "0xwithdraw".to_string(),
+            usdc_amount: "1000000".to_string(),
+        })
+    }
+
+    async fn deposit(&self, _: &Intent) -> Result<String, NaisuError> {
+        self.0.call(Step::Deposit, NaisuError::Sui)?;
+        Ok("0xdeposit".to_string())
+    }
+}
+
+#[async_trait]
+impl BridgeOps for MockBridge {
+    async fn burn(&self, _: &Intent) -> Result<BurnReceipt, NaisuError> {
+        self.0.call(Step::Burn, NaisuError::Bridge)?;
+        Ok(BurnReceipt {
+            tx_hash: "0xburn".to_string(),
+            nonce: "42".to_string(),
+            message_hash: None,
+        })
+    }
+
+    async fn attestation(&self, _: &Intent, nonce: &str) -> Result<Attestation, NaisuError> {
+        assert_eq!(nonce, "42");
+        self.0.call(Step::Attestation, NaisuError::Bridge)?;
+        if self.0.attestation_times_out {
+            return Err(NaisuError::AttestationTimeout("nonce 42".to_string()));
+        }
+        Ok(Attestation {
+            message: "0xmessage".to_string(),
+            attestation: "0xattestation".to_string(),
+        })
+    }
+
+    async fn already_received(&self, _: &Intent, nonce: &str) -> Result<bool, NaisuError> {
+        assert_eq!(nonce, "42");
+        self.0.call(Step::ReceivedCheck, NaisuError::Bridge)?;
+        Ok(self.0.already_received)
+    }
+
+    async fn mint(&self, _: &Intent, _: &Attestation) -> Result<String, NaisuError> {
+        self.0.call(Step::Mint, NaisuError::Bridge)?;
+        Ok("0xmint".to_string())
+    }
+}
+
+/// Orchestrator whose three backends all follow `script`
+pub fn orchestrator(script: &Arc<Script>) -> IntentOrchestrator {
+    IntentOrchestrator::new(
+        Arc::new(MockEvm(script.clone())),
+        Arc::new(MockSui(script.clone())),
+        Arc::new(MockBridge(script.clone())),
+    )
+}
@@ -3,7 +3,12 @@
 //! Provides PTB construction for burning USDC on Sui via CCTP.
 //! The user signs and submits the transaction; we just build it.
 
+use naisu_core::{addr::to_sui_bytes32, EvmChain, SuiNetwork};
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::coin::CoinSelection;
+use crate::ptb::{ProgrammableTransactionBlock, PtbBuilder, PtbError};
 
 // ─── CCTP Package IDs (Sui Testnet) ──────────────────────────────────────────
 // Source: https://github.com/circlefin/sui-cctp (testnet branch Move.lock)
@@ -13,8 +18,7 @@ pub const TOKEN_MESSENGER_MINTER_PACKAGE: &str =
     "0x31cc14d80c175ae39777c0238f20594c6d4869cfab199f40b69f3319956b8beb";
 
 /// USDC coin type on Sui Testnet
-pub const USDC_COIN_TYPE: &str =
-    "0xa1ec7fc00a6f40db9693ad1415d0c193ad3906494428cf252621037bd7117e29::usdc::USDC";
+pub const USDC_COIN_TYPE: &str = SuiNetwork::Testnet.usdc_coin_type();
 
 /// MessageTransmitter package on Sui Testnet  
 pub const MESSAGE_TRANSMITTER_PACKAGE: &str =
@@ -33,10 +37,84 @@ pub const MESSAGE_TRANSMITTER_STATE: &str =
 pub const USDC_TREASURY: &str =
     "0x7170137d4a6431bf83351ac025baf462909bffe2877d87716374fb42b9629ebe";
 
+// ─── CCTP Package IDs (Sui Mainnet) ──────────────────────────────────────────
+// Source: https://developers.circle.com/cctp/v1/sui-packages#mainnet
+
+/// TokenMessengerMinter package on Sui Mainnet
+pub const MAINNET_TOKEN_MESSENGER_MINTER_PACKAGE: &str =
+    "0x2aa6c5d56376c371f88a6cc42e852824994993cb9bab8d3e6450cbe3cb32b94e";
+
+/// MessageTransmitter package on Sui Mainnet
+pub const MAINNET_MESSAGE_TRANSMITTER_PACKAGE: &str =
+    "0x08d87d37ba49e785dde270a83f8e979605b03dc552b5548f26fdf2f49bf7ed1b";
+
+/// USDC coin type on Sui Mainnet
+pub const MAINNET_USDC_COIN_TYPE: &str = SuiNetwork::Mainnet.usdc_coin_type();
+
+/// CCTP State object ID (TokenMessengerMinter State) on Sui Mainnet
+pub const MAINNET_CCTP_STATE_OBJECT: &str =
+    "0x45993eecc0382f37419864992c12faee2238f5cfe22b98ad3bf455baf65c8a2f";
+
+/// MessageTransmitter State object ID on Sui Mainnet
+pub const MAINNET_MESSAGE_TRANSMITTER_STATE: &str =
+    "0xf68268c3d9b1df3215f2439400c1c4ea08ac4ef4bb7d6f3ca6a2a239e17510af";
+
+/// USDC Treasury object ID on Sui Mainnet
+pub const MAINNET_USDC_TREASURY: &str =
+    "0x57d6725e7a8b49a7b2a612f6bd66ab5f39fc95332ca48be421c3229d514a6de7";
+
+/// CCTP Move packages, shared state objects and USDC type for one Sui network
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CctpPackages {
+    pub token_messenger_minter: String,
+    pub message_transmitter: String,
+    pub usdc_coin_type: String,
+    /// TokenMessengerMinter shared `State`
+    pub state_object: String,
+    /// MessageTransmitter shared `State`
+    pub message_transmitter_state: String,
+    /// USDC `Treasury` shared object
+    pub usdc_treasury: String,
+}
+
+impl CctpPackages {
+    /// Packages deployed on `network` (devnet has no CCTP; it uses testnet's)
+    pub fn for_network(network: SuiNetwork) -> Self {
+        let ids = match network {
+            SuiNetwork::Mainnet => [
+                MAINNET_TOKEN_MESSENGER_MINTER_PACKAGE,
+                MAINNET_MESSAGE_TRANSMITTER_PACKAGE,
+                MAINNET_USDC_COIN_TYPE,
+                MAINNET_CCTP_STATE_OBJECT,
+                MAINNET_MESSAGE_TRANSMITTER_STATE,
+                MAINNET_USDC_TREASURY,
+            ],
+            SuiNetwork::Testnet | SuiNetwork::Devnet => [
+                TOKEN_MESSENGER_MINTER_PACKAGE,
+                MESSAGE_TRANSMITTER_PACKAGE,
+                USDC_COIN_TYPE,
+                CCTP_STATE_OBJECT,
+                MESSAGE_TRANSMITTER_STATE,
+                USDC_TREASURY,
+            ],
+        };
+        let [token_messenger_minter, message_transmitter, usdc_coin_type, state_object, message_transmitter_state, usdc_treasury] =
+            ids.map(str::to_string);
+        Self {
+            token_messenger_minter,
+            message_transmitter,
+            usdc_coin_type,
+            state_object,
+            message_transmitter_state,
+            usdc_treasury,
+        }
+    }
+}
+
 // ─── CCTP Domain IDs ─────────────────────────────────────────────────────────
 
-pub const CCTP_DOMAIN_BASE: u32 = 5;
-pub const CCTP_DOMAIN_SUI: u32 = 10;
+pub const CCTP_DOMAIN_BASE: u32 = 6;
+pub const CCTP_DOMAIN_SUI: u32 = 8;
 
 // ─── Types ───────────────────────────────────────────────────────────────────
 
@@ -53,13 +131,31 @@ pub struct DepositForBurnRequest {
     pub dest_domain: u32,
 }
 
+/// Initial shared versions of the objects `deposit_for_burn` takes by
+/// reference, read from chain with [`crate::SuiClient::initial_shared_version`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CctpSharedVersions {
+    /// TokenMessengerMinter `State`
+    pub state: u64,
+    /// MessageTransmitter `State`
+    pub message_transmitter_state: u64,
+    /// USDC `Treasury`
+    pub usdc_treasury: u64,
+}
+
+/// System `DenyList` shared object, checked by regulated coins like USDC
+pub const DENY_LIST_OBJECT: &str = "0x403";
+
+/// `DenyList` has been shared since genesis
+const DENY_LIST_INITIAL_VERSION: u64 = 1;
+
 /// Response containing the PTB for the user to sign
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DepositForBurnResponse {
-    /// Base64-encoded transaction bytes
-    pub tx_bytes: String,
     /// Expected nonce (may be estimated)
     pub expected_nonce: Option<String>,
+    /// Coin preparation and `deposit_for_burn` call
+    pub ptb: ProgrammableTransactionBlock,
     /// Human-readable summary
     pub summary: String,
 }
@@ -75,21 +171,100 @@ pub struct BurnResult {
     pub source_domain: u32,
 }
 
+/// Typed arguments for the Sui `deposit_for_burn` Move call
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct SuiBurnTxParams {
+    /// Fully-qualified Move target (`package::module::function`)
+    pub target: String,
+    /// USDC amount to burn (raw, 6 decimals)
+    pub amount_raw: String,
+    /// Destination CCTP domain
+    pub dest_domain: u32,
+    /// EVM recipient padded to 32 bytes
+    pub mint_recipient: String,
+    /// USDC coin type being burned
+    pub coin_type: String,
+}
+
+/// Build `deposit_for_burn` arguments for a validated amount and EVM destination
+pub fn build_sui_burn_params(
+    packages: &CctpPackages,
+    amount_raw: u64,
+    dest_chain: EvmChain,
+    evm_destination: &str,
+) -> Result<SuiBurnTxParams, CctpSuiError> {
+    let dest_domain = dest_chain
+        .cctp_domain()
+        .ok_or(CctpSuiError::UnsupportedChain(dest_chain))?;
+
+    Ok(SuiBurnTxParams {
+        target: format!(
+            "{}::deposit_for_burn::deposit_for_burn",
+            packages.token_messenger_minter
+        ),
+        amount_raw: amount_raw.to_string(),
+        dest_domain,
+        mint_recipient: pad_evm_address(evm_destination)?,
+        coin_type: packages.usdc_coin_type.clone(),
+    })
+}
+
 // ─── PTB Builder ─────────────────────────────────────────────────────────────
 
 /// Build a Programmable Transaction Block for deposit_for_burn
 ///
 /// This constructs the Move call to `token_messenger_minter::deposit_for_burn`
-/// which burns USDC on Sui and initiates the CCTP transfer.
+/// which burns USDC on Sui and initiates the CCTP transfer. The wallet adds
+/// gas and serializes the transaction when the user signs it.
 pub fn build_deposit_for_burn_ptb(
+    packages: &CctpPackages,
+    shared: &CctpSharedVersions,
     request: &DepositForBurnRequest,
-    _usdc_coin_object_id: &str,
+    coins: &CoinSelection,
 ) -> Result<DepositForBurnResponse, CctpSuiError> {
-    // The actual PTB construction requires the Sui SDK's TransactionBlock builder
-    // For now, we return the parameters needed for the frontend to build it
+    if coins.amount != request.amount {
+        return Err(CctpSuiError::PtbBuildError(format!(
+            "coin selection covers {} but burn amount is {}",
+            coins.amount, request.amount
+        )));
+    }
 
     // Pad EVM address to 32 bytes (required by CCTP)
-    let _padded_dest = pad_evm_address(&request.evm_destination)?;
+    let padded_dest = pad_evm_address(&request.evm_destination)?;
+
+    // Merge the selected USDC coins and split off exactly `amount`
+    let mut builder = PtbBuilder::new();
+    let burn_coin = coins
+        .add_to_ptb(&mut builder)
+        .map_err(|e| CctpSuiError::PtbBuildError(e.to_string()))?;
+    let ptb_error = |e: PtbError| CctpSuiError::PtbBuildError(e.to_string());
+    let dest_domain = builder.add_pure(&request.dest_domain).map_err(ptb_error)?;
+    let mint_recipient = builder
+        .add_pure(&address_bytes(&padded_dest)?)
+        .map_err(ptb_error)?;
+    let state = builder.add_shared_object(&packages.state_object, shared.state, false);
+    let message_transmitter_state = builder.add_shared_object(
+        &packages.message_transmitter_state,
+        shared.message_transmitter_state,
+        true,
+    );
+    let deny_list = builder.add_shared_object(DENY_LIST_OBJECT, DENY_LIST_INITIAL_VERSION, false);
+    let treasury = builder.add_shared_object(&packages.usdc_treasury, shared.usdc_treasury, true);
+    builder.move_call(
+        &packages.token_messenger_minter,
+        "deposit_for_burn",
+        "deposit_for_burn",
+        vec![packages.usdc_coin_type.clone()],
+        vec![
+            burn_coin,
+            dest_domain,
+            mint_recipient,
+            state,
+            message_transmitter_state,
+            deny_list,
+            treasury,
+        ],
+    );
 
     let summary = format!(
         "Burn {} USDC on Sui → Mint on Base (domain {})",
@@ -97,17 +272,9 @@ pub fn build_deposit_for_burn_ptb(
         request.dest_domain
     );
 
-    // In a real implementation, we would:
-    // 1. Fetch the USDC coin object
-    // 2. Build PTB with:
-    //    - SplitCoins to get exact amount
-    //    - MoveCall to token_messenger_minter::deposit_for_burn::deposit_for_burn
-    // 3. Serialize to base64
-
-    // For MVP, return placeholder - frontend will build the actual PTB
     Ok(DepositForBurnResponse {
-        tx_bytes: "PLACEHOLDER_FRONTEND_BUILDS_PTB".to_string(),
         expected_nonce: None,
+        ptb: builder.build(),
         summary,
     })
 }
@@ -132,14 +299,23 @@ pub fn extract_nonce_from_events(events: &[serde_json::Value]) -> Option<String>
     None
 }
 
-/// Pad EVM address to 32 bytes (CCTP requirement)
-fn pad_evm_address(addr: &str) -> Result<String, CctpSuiError> {
-    let clean = addr.strip_prefix("0x").unwrap_or(addr);
-    if clean.len() != 40 {
-        return Err(CctpSuiError::InvalidAddress(addr.to_string()));
+/// Pad EVM address to 32 bytes (CCTP requirement), lowercased
+pub fn pad_evm_address(addr: &str) -> Result<String, CctpSuiError> {
+    to_sui_bytes32(addr).map_err(|e| CctpSuiError::InvalidAddress(format!("{} ({})", addr, e)))
+}
+
+/// Raw bytes of a padded `0x` + 64 hex address, as a Move `address` pure input
+fn address_bytes(padded: &str) -> Result<[u8; 32], CctpSuiError> {
+    let hex = padded.trim_start_matches("0x");
+    let mut bytes = [0u8; 32];
+    if hex.len() != 64 {
+        return Err(CctpSuiError::InvalidAddress(padded.to_string()));
+    }
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16)
+            .map_err(|_| CctpSuiError::InvalidAddress(padded.to_string()))?;
     }
-    // Pad with 24 zeros on the left (12 bytes = 24 hex chars)
-    Ok(format!("0x000000000000000000000000{}", clean))
+    Ok(bytes)
 }
 
 // ─── Errors ──────────────────────────────────────────────────────────────────
@@ -149,6 +325,9 @@ pub enum CctpSuiError {
     #[error("Invalid EVM address: {0}")]
     InvalidAddress(String),
 
+    #[error("Chain has no CCTP domain: {0:?}")]
+    UnsupportedChain(EvmChain),
+
     #[error("Failed to build PTB: {0}")]
     PtbBuildError(String),
 
@@ -162,6 +341,8 @@ pub enum CctpSuiError {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::client::CoinObject;
+    use crate::ptb::{PtbArgument, PtbCommand, PtbInput};
 
     #[test]
     fn test_pad_evm_address() {
@@ -173,4 +354,135 @@ mod tests {
         );
         assert_eq!(padded.len(), 66); // 0x + 64 hex chars
     }
+
+    #[test]
+    fn test_pad_evm_address_lowercases() {
+        let padded = pad_evm_address("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed").unwrap();
+        assert_eq!(
+            padded,
+            "0x0000000000000000000000005aaeb6053f3e94c9b9a09f33669435e7ef1beaed"
+        );
+        assert!(pad_evm_address("0xZZaeb6053F3E94C9b9A09f33669435E7Ef1BeAed").is_err());
+    }
+
+    #[test]
+    fn test_packages_differ_per_network() {
+        let mainnet = CctpPackages::for_network(SuiNetwork::Mainnet);
+        let testnet = CctpPackages::for_network(SuiNetwork::Testnet);
+
+        assert_ne!(
+            mainnet.token_messenger_minter,
+            testnet.token_messenger_minter
+        );
+        assert_ne!(mainnet.message_transmitter, testnet.message_transmitter);
+        assert_ne!(mainnet.usdc_coin_type, testnet.usdc_coin_type);
+        assert_ne!(mainnet.state_object, testnet.state_object);
+        assert_ne!(
+            mainnet.message_transmitter_state,
+            testnet.message_transmitter_state
+        );
+        assert_ne!(mainnet.usdc_treasury, testnet.usdc_treasury);
+        assert_eq!(
+            testnet.token_messenger_minter,
+            TOKEN_MESSENGER_MINTER_PACKAGE
+        );
+        assert_eq!(SuiNetwork::Mainnet.cctp_domain(), CCTP_DOMAIN_SUI);
+    }
+
+    #[test]
+    fn test_deposit_for_burn_ptb_passes_shared_objects() {
+        let packages = CctpPackages::for_network(SuiNetwork::Testnet);
+        let shared = CctpSharedVersions {
+            state: 11,
+            message_transmitter_state: 12,
+            usdc_treasury: 13,
+        };
+        let coins = CoinSelection {
+            primary: CoinObject {
+                coin_type: USDC_COIN_TYPE.to_string(),
+                coin_object_id: "0xc01n".to_string(),
+                version: "7".to_string(),
+                digest: "digest".to_string(),
+                balance: "2000000".to_string(),
+            },
+            merge: Vec::new(),
+            amount: 1_500_000,
+            change: 500_000,
+        };
+        let request = DepositForBurnRequest {
+            sender: "0x2".to_string(),
+            amount: 1_500_000,
+            evm_destination: "0x1234567890123456789012345678901234567890".to_string(),
+            dest_domain: CCTP_DOMAIN_BASE,
+        };
+
+        let ptb = build_deposit_for_burn_ptb(&packages, &shared, &request, &coins)
+            .unwrap()
+            .ptb;
+
+        let Some(PtbCommand::MoveCall(call)) = ptb.commands.last() else {
+            panic!("deposit_for_burn is the last command");
+        };
+        assert_eq!(call.arguments.len(), 7);
+        let input = |arg: &PtbArgument| match arg {
+            PtbArgument::Input { index } => ptb.inputs[*index as usize].clone(),
+            other => panic!("expected an input, got {:?}", other),
+        };
+
+        let PtbInput::Pure { value: recipient } = input(&call.arguments[2]) else {
+            panic!("mint recipient is a pure input");
+        };
+        assert_eq!(recipient.len(), 32);
+        assert_eq!(
+            recipient[12..],
+            [
+                0x12, 0x34, 0x56, 0x78, 0x90, 0x12, 0x34, 0x56, 0x78, 0x90, 0x12, 0x34, 0x56, 0x78,
+                0x90, 0x12, 0x34, 0x56, 0x78, 0x90
+            ]
+        );
+
+        let shared_inputs: Vec<_> = call.arguments[3..]
+            .iter()
+            .map(|arg| match input(arg) {
+                PtbInput::SharedObject {
+                    object_id,
+                    initial_shared_version,
+                    mutable,
+                } => (object_id, initial_shared_version, mutable),
+                other => panic!("expected a shared object, got {:?}", other),
+            })
+            .collect();
+        assert_eq!(
+            shared_inputs,
+            vec![
+                (CCTP_STATE_OBJECT.to_string(), 11, false),
+                (MESSAGE_TRANSMITTER_STATE.to_string(), 12, true),
+                (DENY_LIST_OBJECT.to_string(), 1, false),
+                (USDC_TREASURY.to_string(), 13, true),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sui_burn_params_shape() {
+        let params = build_sui_burn_params(
+            &CctpPackages::for_network(SuiNetwork::Testnet),
+            1_500_000,
+            EvmChain::Arbitrum,
+            "0x1234567890123456789012345678901234567890",
+        )
+        .unwrap();
+
+        assert_eq!(params.dest_domain, 3);
+        assert_eq!(
+            serde_json::to_value(&params).unwrap(),
+            serde_json::json!({
+                "target": format!("{}::deposit_for_burn::deposit_for_burn", TOKEN_MESSENGER_MINTER_PACKAGE),
+                "amount_raw": "1500000",
+                "dest_domain": 3,
+                "mint_recipient": "0x0000000000000000000000001234567890123456789012345678901234567890",
+                "coin_type": USDC_COIN_TYPE,
+            })
+        );
+    }
 }
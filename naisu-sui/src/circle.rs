@@ -0,0 +1,881 @@
+//! Circle attestation API client
+//!
+//! Polls Circle's Iris API for the attestation of a CCTP burn so the
+//! message can be received on the destination chain.
+//!
+//! API Docs: https://developers.circle.com/api-reference/cctp/all/get-messages-v-2
+
+use std::time::Duration;
+
+use naisu_core::{EvmChain, NaisuError, SuiNetwork};
+use reqwest::{header::RETRY_AFTER, StatusCode};
+use serde::{Deserialize, Serialize};
+use sha3::{Digest, Keccak256};
+
+use crate::config::HttpTimeouts;
+
+/// Circle Iris API (testnet)
+pub const CIRCLE_API_SANDBOX: &str = "https://iris-api-sandbox.circle.com";
+
+/// Circle Iris API (mainnet)
+pub const CIRCLE_API_MAINNET: &str = "https://iris-api.circle.com";
+
+/// Circle Iris API for a network (testnets use the sandbox)
+pub fn circle_api_url(network: SuiNetwork) -> &'static str {
+    if network.is_testnet() {
+        CIRCLE_API_SANDBOX
+    } else {
+        CIRCLE_API_MAINNET
+    }
+}
+
+/// CCTP MessageTransmitter contract on an EVM chain
+/// Source: https://developers.circle.com/cctp/v1/evm-smart-contracts
+pub fn message_transmitter_address(chain: EvmChain) -> &'static str {
+    match chain {
+        EvmChain::Ethereum => "0x0a992d191DEeC32aFe36203Ad87D7d289a738F81",
+        EvmChain::Base => "0xAD09780d193884d503182aD4588450C416D6F9D4",
+        EvmChain::Arbitrum => "0xC30362313FBBA5cf9163F0bb16a0e01f01A896ca",
+        EvmChain::Optimism => "0x4D41f22c5a0e5c74090899E5a8Fb597a8842b3e8",
+        EvmChain::BaseSepolia | EvmChain::Sepolia => "0x7865fAfC2db2093669d92c0F33AeEF291086BEFD",
+    }
+}
+
+/// CCTP TokenMessenger contract on an EVM chain (spender for the USDC approval)
+/// Source: https://developers.circle.com/cctp/v1/evm-smart-contracts
+pub fn token_messenger_address(chain: EvmChain) -> &'static str {
+    match chain {
+        EvmChain::Ethereum => "0xBd3fa81B58Ba92a82136038B25aDec7066af3155",
+        EvmChain::Base => "0x1682Ae6375C4E4A97e4B583BC394c861A46D8962",
+        EvmChain::Arbitrum => "0x19330d10D9Cc8751218eaf51E8885D058642E08A",
+        EvmChain::Optimism => "0x2B4069517957735bE00ceE0fadAE88a26365528f",
+        EvmChain::BaseSepolia | EvmChain::Sepolia => "0x9f3B8679c73C2Fef8b59B4f3444d4e156fb70AA5",
+    }
+}
+
+/// An unsigned EVM contract call
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EvmCall {
+    pub to: String,
+    /// ABI-encoded calldata, 0x-prefixed
+    pub data: String,
+    /// Native ETH sent with the call (wei, decimal)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+/// `ERC20.approve(spender, amount)` on `token`
+pub fn build_approve_call(token: &str, spender: &str, amount: u128) -> Result<EvmCall, CctpError> {
+    Ok(EvmCall {
+        to: token.to_string(),
+        data: encode_call(
+            "approve(address,uint256)",
+            &[address_word(spender)?, uint_word(amount)],
+        ),
+        value: None,
+    })
+}
+
+/// `WETH.deposit()` wrapping `amount` wei of native ETH on `chain`
+pub fn build_weth_deposit_call(chain: EvmChain, amount: u128) -> EvmCall {
+    EvmCall {
+        to: chain.weth_address().to_string(),
+        data: encode_call("deposit()", &[]),
+        value: Some(amount.to_string()),
+    }
+}
+
+/// `TokenMessenger.depositForBurn(amount, destinationDomain, mintRecipient, burnToken)`
+/// burning `chain`'s USDC for `mint_recipient` (32-byte hex, e.g. a Sui address)
+pub fn build_deposit_for_burn_call(
+    chain: EvmChain,
+    amount: u128,
+    dest_domain: u32,
+    mint_recipient: &str,
+) -> Result<EvmCall, CctpError> {
+    Ok(EvmCall {
+        to: token_messenger_address(chain).to_string(),
+        data: encode_call(
+            "depositForBurn(uint256,uint32,bytes32,address)",
+            &[
+                uint_word(amount),
+                uint_word(dest_domain as u128),
+                bytes32_word(mint_recipient)?,
+                address_word(chain.usdc_address())?,
+            ],
+        ),
+        value: None,
+    })
+}
+
+/// Attestation status reported by Circle
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AttestationStatus {
+    /// Burn seen, waiting for block confirmations
+    PendingConfirmations,
+    /// Attestation signed and ready to be received
+    Complete,
+}
+
+/// A CCTP message and its attestation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CircleMessage {
+    /// Hex-encoded message bytes
+    pub message: String,
+    /// Hex-encoded attestation ("PENDING" until complete)
+    pub attestation: String,
+    pub status: AttestationStatus,
+    pub event_nonce: Option<String>,
+}
+
+impl CircleMessage {
+    pub fn is_complete(&self) -> bool {
+        self.status == AttestationStatus::Complete && self.attestation.starts_with("0x")
+    }
+}
+
+/// Arguments for `MessageTransmitter.receiveMessage(bytes message, bytes attestation)`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReceiveMessageParams {
+    pub message_transmitter: String,
+    pub message: String,
+    pub attestation: String,
+}
+
+/// Build `receiveMessage` arguments for a completed attestation
+pub fn build_receive_message_params(
+    dest_chain: EvmChain,
+    message: &CircleMessage,
+) -> Result<ReceiveMessageParams, CctpError> {
+    if !message.is_complete() {
+        return Err(CctpError::NotReady);
+    }
+    Ok(ReceiveMessageParams {
+        message_transmitter: message_transmitter_address(dest_chain).to_string(),
+        message: message.message.clone(),
+        attestation: message.attestation.clone(),
+    })
+}
+
+#[derive(Debug, Deserialize)]
+struct MessagesResponse {
+    messages: Vec<CircleMessage>,
+}
+
+#[derive(Debug, Deserialize)]
+struct EthCallResponse {
+    result: Option<String>,
+    error: Option<serde_json::Value>,
+}
+
+/// Destination EVM chain used to check whether a message was already received
+#[derive(Debug, Clone)]
+struct EvmDestination {
+    chain: EvmChain,
+    rpc_url: String,
+}
+
+/// Circle attestation API client
+#[derive(Debug, Clone)]
+pub struct CctpClient {
+    client: reqwest::Client,
+    base_url: String,
+    evm: Option<EvmDestination>,
+}
+
+impl CctpClient {
+    /// Create a client against the given Iris API base URL
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self {
+            client: HttpTimeouts::default().build_client(),
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            evm: None,
+        }
+    }
+
+    /// Override the default request/connect timeouts
+    pub fn with_timeouts(mut self, timeouts: HttpTimeouts) -> Self {
+        self.client = timeouts.build_client();
+        self
+    }
+
+    /// Enable destination-side checks against an EVM chain's JSON-RPC endpoint
+    pub fn with_evm_destination(mut self, chain: EvmChain, rpc_url: impl Into<String>) -> Self {
+        self.evm = Some(EvmDestination {
+            chain,
+            rpc_url: rpc_url.into(),
+        });
+        self
+    }
+
+    /// Create a client against the Iris API for `network`
+    pub fn for_network(network: SuiNetwork) -> Self {
+        Self::new(circle_api_url(network))
+    }
+
+    /// Fetch the message for a burn nonce on `source_domain`.
+    ///
+    /// Returns `Ok(None)` while Circle has not indexed the burn yet (404).
+    /// Rate limits (429) and server errors (5xx) are reported as transient
+    /// errors, see [`CctpError::is_transient`].
+    pub async fn get_attestation(
+        &self,
+        source_domain: u32,
+        nonce: &str,
+    ) -> Result<Option<CircleMessage>, CctpError> {
+        let url = format!("{}/v2/messages/{}", self.base_url, source_domain);
+
+        let response = self
+            .client
+            .get(&url)
+            .query(&[("nonce", nonce)])
+            .send()
+            .await
+            .map_err(CctpError::from_reqwest)?;
+
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        let body: MessagesResponse = api_success(response)
+            .await?
+            .json()
+            .await
+            .map_err(|e| CctpError::Parse(e.to_string()))?;
+
+        Ok(body.messages.into_iter().next())
+    }
+
+    /// Circle's minimum fee, in basis points, for a standard (finalized)
+    /// transfer from `source_domain` to `dest_domain`
+    pub async fn transfer_fee_bps(
+        &self,
+        source_domain: u32,
+        dest_domain: u32,
+    ) -> Result<f64, CctpError> {
+        let url = format!(
+            "{}/v2/burn/USDC/fees/{}/{}",
+            self.base_url, source_domain, dest_domain
+        );
+        let response = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .map_err(CctpError::from_reqwest)?;
+        let fees: Vec<TransferFee> = api_success(response)
+            .await?
+            .json()
+            .await
+            .map_err(|e| CctpError::Parse(e.to_string()))?;
+
+        fees.into_iter()
+            .find(|fee| fee.finality_threshold == STANDARD_FINALITY_THRESHOLD)
+            .map(|fee| fee.minimum_fee)
+            .ok_or_else(|| CctpError::Parse("no fee for standard transfers".to_string()))
+    }
+
+    /// Poll until the attestation is complete.
+    ///
+    /// Not-yet-indexed, pending and transient (429/5xx) responses are retried
+    /// after `interval` (or the server's `Retry-After`), up to `max_attempts`.
+    pub async fn poll_attestation(
+        &self,
+        source_domain: u32,
+        nonce: &str,
+        interval: Duration,
+        max_attempts: u32,
+    ) -> Result<CircleMessage, CctpError> {
+        for attempt in 1..=max_attempts {
+            let wait = match self.get_attestation(source_domain, nonce).await {
+                Ok(Some(message)) if message.is_complete() => return Ok(message),
+                Ok(_) => interval,
+                Err(e) if e.is_transient() => {
+                    tracing::warn!(nonce, attempt, error = %e, "Transient Circle API error");
+                    e.retry_after().unwrap_or(interval)
+                }
+                Err(e) => return Err(e),
+            };
+
+            if attempt < max_attempts {
+                tokio::time::sleep(wait).await;
+            }
+        }
+
+        Err(CctpError::Timeout(max_attempts))
+    }
+
+    /// Whether the destination MessageTransmitter has already consumed
+    /// (`source_domain`, `nonce`), i.e. the USDC was already minted.
+    pub async fn is_message_already_received(
+        &self,
+        source_domain: u32,
+        nonce: u64,
+    ) -> Result<bool, CctpError> {
+        let evm = self.evm.as_ref().ok_or(CctpError::NoDestination)?;
+
+        let request = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "eth_call",
+            "params": [
+                {
+                    "to": message_transmitter_address(evm.chain),
+                    "data": used_nonces_calldata(source_domain, nonce),
+                },
+                "latest"
+            ]
+        });
+
+        let response: EthCallResponse = self
+            .client
+            .post(&evm.rpc_url)
+            .json(&request)
+            .send()
+            .await
+            .map_err(CctpError::from_reqwest)?
+            .json()
+            .await
+            .map_err(|e| CctpError::Parse(e.to_string()))?;
+
+        if let Some(error) = response.error {
+            return Err(CctpError::Rpc(error.to_string()));
+        }
+        let result = response
+            .result
+            .ok_or_else(|| CctpError::Parse("eth_call returned no result".to_string()))?;
+
+        // usedNonces returns uint256: 0 = unused, 1 = used
+        Ok(result.trim_start_matches("0x").chars().any(|c| c != '0'))
+    }
+
+    /// Whether the burn `tx_hash` is still included on the EVM chain behind
+    /// `rpc_url`. A reorged-out tx has no receipt any more.
+    pub async fn burn_tx_included(&self, rpc_url: &str, tx_hash: &str) -> Result<bool, CctpError> {
+        let request = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "eth_getTransactionReceipt",
+            "params": [tx_hash]
+        });
+
+        let response: serde_json::Value = self
+            .client
+            .post(rpc_url)
+            .json(&request)
+            .send()
+            .await
+            .map_err(CctpError::from_reqwest)?
+            .json()
+            .await
+            .map_err(|e| CctpError::Parse(e.to_string()))?;
+
+        if let Some(error) = response.get("error") {
+            return Err(CctpError::Rpc(error.to_string()));
+        }
+        Ok(response
+            .get("result")
+            .is_some_and(|receipt| receipt.get("blockHash").is_some_and(|b| !b.is_null())))
+    }
+
+    /// Poll the attestation and build `receiveMessage` params, or return
+    /// `None` if the message was already received (e.g. by Circle's relayer).
+    pub async fn prepare_receive_message(
+        &self,
+        source_domain: u32,
+        nonce: u64,
+        interval: Duration,
+        max_attempts: u32,
+    ) -> Result<Option<ReceiveMessageParams>, CctpError> {
+        let evm = self.evm.as_ref().ok_or(CctpError::NoDestination)?;
+
+        if self
+            .is_message_already_received(source_domain, nonce)
+            .await?
+        {
+            tracing::info!(
+                source_domain,
+                nonce,
+                "CCTP message already received, skipping mint"
+            );
+            return Ok(None);
+        }
+
+        let message = self
+            .poll_attestation(source_domain, &nonce.to_string(), interval, max_attempts)
+            .await?;
+
+        build_receive_message_params(evm.chain, &message).map(Some)
+    }
+}
+
+/// Calldata for `usedNonces(keccak256(abi.encodePacked(uint32 sourceDomain, uint64 nonce)))`
+fn used_nonces_calldata(source_domain: u32, nonce: u64) -> String {
+    let selector = Keccak256::digest(b"usedNonces(bytes32)");
+
+    let mut packed = Vec::with_capacity(12);
+    packed.extend_from_slice(&source_domain.to_be_bytes());
+    packed.extend_from_slice(&nonce.to_be_bytes());
+    let key = Keccak256::digest(&packed);
+
+    let hex: String = selector[..4]
+        .iter()
+        .chain(key.iter())
+        .map(|b| format!("{:02x}", b))
+        .collect();
+    format!("0x{}", hex)
+}
+
+fn encode_call(signature: &str, words: &[[u8; 32]]) -> String {
+    let selector = Keccak256::digest(signature.as_bytes());
+    let hex: String = selector[..4]
+        .iter()
+        .chain(words.iter().flatten())
+        .map(|b| format!("{:02x}", b))
+        .collect();
+    format!("0x{}", hex)
+}
+
+fn uint_word(value: u128) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    word[16..].copy_from_slice(&value.to_be_bytes());
+    word
+}
+
+/// Left-pad up to 32 bytes of hex into an ABI word
+fn hex_word(hex_str: &str, max_bytes: usize) -> Result<[u8; 32], CctpError> {
+    let hex = hex_str.strip_prefix("0x").unwrap_or(hex_str);
+    if hex.is_empty() || hex.len() > max_bytes * 2 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(CctpError::InvalidAddress(hex_str.to_string()));
+    }
+    let padded = format!("{:0>64}", hex);
+    let mut word = [0u8; 32];
+    for (i, byte) in word.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&padded[i * 2..i * 2 + 2], 16)
+            .map_err(|_| CctpError::InvalidAddress(hex_str.to_string()))?;
+    }
+    Ok(word)
+}
+
+fn address_word(address: &str) -> Result<[u8; 32], CctpError> {
+    hex_word(address, 20)
+}
+
+fn bytes32_word(value: &str) -> Result<[u8; 32], CctpError> {
+    hex_word(value, 32)
+}
+
+/// `finalityThreshold` Circle uses for standard (hard finality) transfers
+const STANDARD_FINALITY_THRESHOLD: u32 = 2000;
+
+/// One entry of `GET /v2/burn/USDC/fees/{source}/{dest}`
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TransferFee {
+    finality_threshold: u32,
+    /// Basis points of the burned amount
+    minimum_fee: f64,
+}
+
+/// `response` if it succeeded; rate limits (429) and server errors (5xx)
+/// become transient errors, anything else an API error
+async fn api_success(response: reqwest::Response) -> Result<reqwest::Response, CctpError> {
+    let status = response.status();
+    if status == StatusCode::TOO_MANY_REQUESTS {
+        let retry_after = response
+            .headers()
+            .get(RETRY_AFTER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.trim().parse().ok())
+            .map(Duration::from_secs);
+        return Err(CctpError::RateLimited { retry_after });
+    }
+    if status.is_server_error() {
+        return Err(CctpError::Unavailable(status.as_u16()));
+    }
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        return Err(CctpError::Api {
+            status: status.as_u16(),
+            message: body,
+        });
+    }
+    Ok(response)
+}
+
+/// Circle attestation API errors
+#[derive(Debug, thiserror::Error)]
+pub enum CctpError {
+    #[error("HTTP request failed: {0}")]
+    Request(String),
+
+    #[error("HTTP request timed out")]
+    RequestTimeout,
+
+    #[error("Rate limited by Circle API")]
+    RateLimited { retry_after: Option<Duration> },
+
+    #[error("Circle API unavailable ({0})")]
+    Unavailable(u16),
+
+    #[error("Circle API error {status}: {message}")]
+    Api { status: u16, message: String },
+
+    #[error("Failed to parse response: {0}")]
+    Parse(String),
+
+    #[error("Attestation not ready after {0} attempts")]
+    Timeout(u32),
+
+    #[error("Attestation is not complete")]
+    NotReady,
+
+    #[error("No destination chain configured")]
+    NoDestination,
+
+    #[error("EVM RPC error: {0}")]
+    Rpc(String),
+
+    #[error("Invalid address: {0}")]
+    InvalidAddress(String),
+}
+
+impl CctpError {
+    fn from_reqwest(err: reqwest::Error) -> Self {
+        if err.is_timeout() {
+            CctpError::RequestTimeout
+        } else {
+            CctpError::Request(err.to_string())
+        }
+    }
+
+    /// Whether the request may succeed if retried later
+    pub fn is_transient(&self) -> bool {
+        matches!(
+            self,
+            CctpError::Request(_)
+                | CctpError::RequestTimeout
+                | CctpError::RateLimited { .. }
+                | CctpError::Unavailable(_)
+        )
+    }
+
+    /// Server-requested delay before retrying, if any
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            CctpError::RateLimited { retry_after } => *retry_after,
+            _ => None,
+        }
+    }
+}
+
+impl From<CctpError> for NaisuError {
+    fn from(err: CctpError) -> Self {
+        match err {
+            CctpError::Timeout(attempts) => NaisuError::AttestationTimeout(format!(
+                "no attestation after {} attempts",
+                attempts
+            )),
+            other => NaisuError::Bridge(other.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn complete_message() -> serde_json::Value {
+        serde_json::json!({
+            "messages": [{
+                "message": "0xdeadbeef",
+                "attestation": "0xabcdef",
+                "status": "complete",
+                "eventNonce": "42"
+            }]
+        })
+    }
+
+    fn short_timeouts() -> HttpTimeouts {
+        HttpTimeouts {
+            request: Duration::from_millis(100),
+            connect: Duration::from_millis(100),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_slow_server_times_out() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v2/messages/8"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(complete_message())
+                    .set_delay(Duration::from_secs(2)),
+            )
+            .mount(&server)
+            .await;
+
+        let client = CctpClient::new(server.uri()).with_timeouts(short_timeouts());
+        let started = std::time::Instant::now();
+        let err = client.get_attestation(8, "42").await.unwrap_err();
+
+        assert!(matches!(err, CctpError::RequestTimeout));
+        assert!(err.is_transient());
+        assert!(started.elapsed() < Duration::from_secs(1));
+    }
+
+    #[tokio::test]
+    async fn test_poll_attestation_retries_after_timeout() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v2/messages/8"))
+            .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_secs(2)))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v2/messages/8"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(complete_message()))
+            .mount(&server)
+            .await;
+
+        let client = CctpClient::new(server.uri()).with_timeouts(short_timeouts());
+        let message = client
+            .poll_attestation(8, "42", Duration::from_millis(10), 3)
+            .await
+            .unwrap();
+
+        assert!(message.is_complete());
+    }
+
+    #[tokio::test]
+    async fn test_exhausted_poll_is_an_attestation_timeout() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v2/messages/0"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "messages": [{ "message": "0x", "attestation": "PENDING", "status": "pending_confirmations" }]
+            })))
+            .mount(&server)
+            .await;
+
+        let err = CctpClient::new(server.uri())
+            .poll_attestation(0, "42", Duration::from_millis(1), 2)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, CctpError::Timeout(2)));
+        assert!(matches!(
+            NaisuError::from(err),
+            NaisuError::AttestationTimeout(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_transfer_fee_uses_standard_finality() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v2/burn/USDC/fees/8/6"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                { "finalityThreshold": 1000, "minimumFee": 1 },
+                { "finalityThreshold": 2000, "minimumFee": 0.5 }
+            ])))
+            .mount(&server)
+            .await;
+
+        let client = CctpClient::new(server.uri());
+        assert_eq!(client.transfer_fee_bps(8, 6).await.unwrap(), 0.5);
+        assert!(matches!(
+            client.transfer_fee_bps(8, 0).await,
+            Err(CctpError::Api { status: 404, .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_burn_tx_included_reads_the_receipt() {
+        use wiremock::matchers::body_partial_json;
+
+        let server = MockServer::start().await;
+        for (tx_hash, receipt) in [
+            (
+                "0xkept",
+                serde_json::json!({ "blockHash": "0xb10c", "status": "0x1" }),
+            ),
+            ("0xreorged", serde_json::Value::Null),
+        ] {
+            Mock::given(method("POST"))
+                .and(body_partial_json(serde_json::json!({
+                    "method": "eth_getTransactionReceipt",
+                    "params": [tx_hash]
+                })))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "jsonrpc": "2.0", "id": 1, "result": receipt
+                })))
+                .mount(&server)
+                .await;
+        }
+
+        let client = CctpClient::new("http://unused");
+        assert!(client
+            .burn_tx_included(&server.uri(), "0xkept")
+            .await
+            .unwrap());
+        assert!(!client
+            .burn_tx_included(&server.uri(), "0xreorged")
+            .await
+            .unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_poll_attestation_retries_after_rate_limit() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v2/messages/8"))
+            .and(query_param("nonce", "42"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "0"))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v2/messages/8"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(complete_message()))
+            .mount(&server)
+            .await;
+
+        let client = CctpClient::new(server.uri());
+        let message = client
+            .poll_attestation(8, "42", Duration::from_millis(10), 3)
+            .await
+            .unwrap();
+
+        assert!(message.is_complete());
+        assert_eq!(message.attestation, "0xabcdef");
+    }
+
+    async fn mock_used_nonce(server: &MockServer, used: bool) {
+        let result = format!("0x{:064x}", used as u8);
+        Mock::given(method("POST"))
+            .and(path("/rpc"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": result
+            })))
+            .mount(server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_already_received_message_is_skipped() {
+        let server = MockServer::start().await;
+        mock_used_nonce(&server, true).await;
+
+        let client = CctpClient::new(server.uri())
+            .with_evm_destination(EvmChain::BaseSepolia, format!("{}/rpc", server.uri()));
+
+        assert!(client.is_message_already_received(8, 42).await.unwrap());
+        let params = client
+            .prepare_receive_message(8, 42, Duration::from_millis(10), 3)
+            .await
+            .unwrap();
+        assert!(params.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_unused_nonce_builds_receive_params() {
+        let server = MockServer::start().await;
+        mock_used_nonce(&server, false).await;
+        Mock::given(method("GET"))
+            .and(path("/v2/messages/8"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(complete_message()))
+            .mount(&server)
+            .await;
+
+        let client = CctpClient::new(server.uri())
+            .with_evm_destination(EvmChain::BaseSepolia, format!("{}/rpc", server.uri()));
+
+        assert!(!client.is_message_already_received(8, 42).await.unwrap());
+        let params = client
+            .prepare_receive_message(8, 42, Duration::from_millis(10), 3)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(params.message, "0xdeadbeef");
+        assert_eq!(params.attestation, "0xabcdef");
+    }
+
+    #[test]
+    fn test_used_nonces_calldata() {
+        // usedNonces(bytes32) selector, then keccak256(0x00000008 ++ 0x000000000000002a)
+        assert_eq!(
+            used_nonces_calldata(8, 42),
+            "0xfeb61724\
+             84198d5e87a36797f160498885d4a0a0e43070ec99a3facd3ace05370ed78f1d"
+        );
+        assert_ne!(
+            used_nonces_calldata(8, 42),
+            used_nonces_calldata(42, 8),
+            "domain and nonce must not be interchangeable"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_poll_attestation_fails_on_bad_request() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(400).set_body_string("invalid nonce"))
+            .mount(&server)
+            .await;
+
+        let client = CctpClient::new(server.uri());
+        let err = client
+            .poll_attestation(8, "not-a-nonce", Duration::from_millis(10), 3)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, CctpError::Api { status: 400, .. }));
+        assert_eq!(server.received_requests().await.unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_deposit_for_burn_calldata() {
+        let call =
+            build_deposit_for_burn_call(EvmChain::BaseSepolia, 1_000_000, 8, "0xabc").unwrap();
+
+        assert_eq!(call.to, token_messenger_address(EvmChain::BaseSepolia));
+        // selector + 4 words
+        assert_eq!(call.data.len(), 2 + 8 + 4 * 64);
+        assert!(call.data.starts_with("0x6fd3504e"));
+        assert!(call
+            .data
+            .ends_with("036cbd53842c5426634e7929541ec2318f3dcf7e"));
+
+        let approve = build_approve_call(
+            EvmChain::BaseSepolia.usdc_address(),
+            token_messenger_address(EvmChain::BaseSepolia),
+            1_000_000,
+        )
+        .unwrap();
+        assert!(approve.data.starts_with("0x095ea7b3"));
+        assert!(approve.data.ends_with("00000f4240"));
+
+        assert!(matches!(
+            build_approve_call(EvmChain::Base.usdc_address(), "0xspender", 1),
+            Err(CctpError::InvalidAddress(_))
+        ));
+    }
+
+    #[test]
+    fn test_weth_wrap_calldata() {
+        let wrap = build_weth_deposit_call(EvmChain::Base, 5);
+        assert_eq!(wrap.to, EvmChain::Base.weth_address());
+        assert_eq!(wrap.data, "0xd0e30db0");
+        assert_eq!(wrap.value.as_deref(), Some("5"));
+    }
+}
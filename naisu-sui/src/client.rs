@@ -1,9 +1,10 @@
 //! Sui RPC client using JSON-RPC
 
+use naisu_core::YieldStrategy;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
 
-use crate::SuiConfig;
+use crate::{CoinSelection, HttpTimeouts, SuiConfig};
 
 /// Sui RPC client
 #[derive(Clone)]
@@ -25,15 +26,42 @@ impl SuiClient {
     pub fn new(config: SuiConfig) -> Self {
         Self {
             config,
-            client: Client::new(),
+            client: HttpTimeouts::default().build_client(),
         }
     }
 
-    /// Make a JSON-RPC call
+    /// Override the default HTTP timeouts
+    pub fn with_timeouts(mut self, timeouts: HttpTimeouts) -> Self {
+        self.client = timeouts.build_client();
+        self
+    }
+
+    /// Make a JSON-RPC call, retrying transient failures per `SuiConfig::retry`
     pub async fn rpc_call<T: for<'de> Deserialize<'de>>(
         &self,
         method: &str,
         params: serde_json::Value,
+    ) -> Result<T, SuiClientError> {
+        let policy = &self.config.retry;
+        let mut attempt = 1;
+        loop {
+            match self.rpc_call_once(method, params.clone()).await {
+                Err(e) if e.is_retryable() && attempt < policy.max_attempts => {
+                    let delay = policy.delay_for(attempt);
+                    tracing::warn!(method, attempt, error = %e, ?delay, "Retrying Sui RPC call");
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Make a single JSON-RPC call
+    async fn rpc_call_once<T: for<'de> Deserialize<'de>>(
+        &self,
+        method: &str,
+        params: serde_json::Value,
     ) -> Result<T, SuiClientError> {
         let request = serde_json::json!({
             "jsonrpc": "2.0",
@@ -48,7 +76,7 @@ impl SuiClient {
             .json(&request)
             .send()
             .await
-            .map_err(|e| SuiClientError::Request(e.to_string()))?;
+            .map_err(SuiClientError::from_reqwest)?;
 
         if !response.status().is_success() {
             let status = response.status();
@@ -79,19 +107,52 @@ impl SuiClient {
         }
     }
 
-    /// Get coins owned by an address
+    /// Get coins owned by an address (all pages)
     pub async fn get_coins(
         &self,
         owner: &str,
         coin_type: Option<&str>,
     ) -> Result<Vec<CoinObject>, SuiClientError> {
-        let params = serde_json::json!([
-            owner, coin_type, null, // cursor
-            null  // limit
-        ]);
+        let mut coins = Vec::new();
+        let mut cursor: Option<String> = None;
 
-        let response: CoinsResponse = self.rpc_call("suix_getCoins", params).await?;
-        Ok(response.data)
+        loop {
+            let params = serde_json::json!([
+                owner, coin_type, cursor, null // limit
+            ]);
+
+            let response: CoinsResponse = self.rpc_call("suix_getCoins", params).await?;
+            coins.extend(response.data);
+
+            match response.next_cursor {
+                Some(next) if response.has_next_page => cursor = Some(next),
+                _ => return Ok(coins),
+            }
+        }
+    }
+
+    /// Pick `owner`'s coins of `coin_type` covering `amount`, see [`CoinSelection`]
+    pub async fn select_coins(
+        &self,
+        owner: &str,
+        coin_type: &str,
+        amount: u64,
+    ) -> Result<CoinSelection, SuiClientError> {
+        let coins = self.get_coins(owner, Some(coin_type)).await?;
+        CoinSelection::select(coins, amount)
+    }
+
+    /// Pick `owner`'s coins of `strategy`'s asset covering `amount`
+    pub async fn select_strategy_coins(
+        &self,
+        owner: &str,
+        strategy: YieldStrategy,
+        amount: u64,
+    ) -> Result<CoinSelection, SuiClientError> {
+        let coin_type = strategy.sui_coin_type(self.config.network).ok_or_else(|| {
+            SuiClientError::PtbBuild(format!("No Sui coin type for {}", strategy.name()))
+        })?;
+        self.select_coins(owner, coin_type, amount).await
     }
 
     /// Get USDC balance for an address
@@ -123,6 +184,68 @@ impl SuiClient {
             .ok_or(SuiClientError::ObjectNotFound(object_id.to_string()))
     }
 
+    /// Version at which a shared object was shared, needed to pass it to a PTB
+    pub async fn initial_shared_version(&self, object_id: &str) -> Result<u64, SuiClientError> {
+        let object = self.get_object(object_id).await?;
+        object
+            .initial_shared_version()
+            .ok_or_else(|| SuiClientError::Parse(format!("Object {} is not shared", object_id)))
+    }
+
+    /// Objects of `struct_type` owned by `owner` (all pages), with content
+    pub async fn get_owned_objects(
+        &self,
+        owner: &str,
+        struct_type: &str,
+    ) -> Result<Vec<SuiObject>, SuiClientError> {
+        let mut objects = Vec::new();
+        let mut cursor: Option<String> = None;
+
+        loop {
+            let params = serde_json::json!([
+                owner,
+                {
+                    "filter": { "StructType": struct_type },
+                    "options": {
+                        "showType": true,
+                        "showOwner": true,
+                        "showContent": true
+                    }
+                },
+                cursor,
+                null // limit
+            ]);
+
+            let response: OwnedObjectsResponse =
+                self.rpc_call("suix_getOwnedObjects", params).await?;
+            objects.extend(response.data.into_iter().filter_map(|o| o.data));
+
+            match response.next_cursor {
+                Some(next) if response.has_next_page => cursor = Some(next),
+                _ => return Ok(objects),
+            }
+        }
+    }
+
+    /// Get an executed transaction by digest, with its effects and object
+    /// changes
+    pub async fn get_transaction(
+        &self,
+        digest: &str,
+    ) -> Result<TransactionResponse, SuiClientError> {
+        let params = serde_json::json!([
+            digest,
+            {
+                "showInput": true,
+                "showEffects": true,
+                "showEvents": true,
+                "showObjectChanges": true
+            }
+        ]);
+
+        self.rpc_call("sui_getTransactionBlock", params).await
+    }
+
     /// Execute a transaction
     pub async fn execute_transaction(
         &self,
@@ -135,7 +258,8 @@ impl SuiClient {
             {
                 "showInput": true,
                 "showEffects": true,
-                "showEvents": true
+                "showEvents": true,
+                "showObjectChanges": true
             },
             "WaitForLocalExecution"
         ]);
@@ -171,9 +295,11 @@ pub struct CoinsResponse {
     pub data: Vec<CoinObject>,
     #[serde(rename = "nextCursor")]
     pub next_cursor: Option<String>,
+    #[serde(rename = "hasNextPage", default)]
+    pub has_next_page: bool,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CoinObject {
     pub coin_type: String,
@@ -188,6 +314,15 @@ pub struct ObjectResponse {
     pub data: Option<SuiObject>,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct OwnedObjectsResponse {
+    pub data: Vec<ObjectResponse>,
+    #[serde(rename = "nextCursor")]
+    pub next_cursor: Option<String>,
+    #[serde(rename = "hasNextPage", default)]
+    pub has_next_page: bool,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SuiObject {
@@ -203,8 +338,34 @@ pub struct SuiObject {
 #[serde(rename_all = "camelCase")]
 pub struct TransactionResponse {
     pub digest: String,
+    #[serde(default)]
+    pub transaction: Option<serde_json::Value>,
     pub effects: TransactionEffects,
     pub events: Option<Vec<serde_json::Value>>,
+    #[serde(default)]
+    pub object_changes: Option<Vec<serde_json::Value>>,
+}
+
+impl SuiObject {
+    /// `initial_shared_version` from a `{"Shared": {..}}` owner
+    pub fn initial_shared_version(&self) -> Option<u64> {
+        let version = self
+            .owner
+            .as_ref()?
+            .pointer("/Shared/initial_shared_version")?;
+        version.as_u64().or_else(|| version.as_str()?.parse().ok())
+    }
+}
+
+impl TransactionResponse {
+    /// Address that signed the transaction, when its input was requested
+    pub fn sender(&self) -> Option<&str> {
+        self.transaction.as_ref()?.pointer("/data/sender")?.as_str()
+    }
+
+    pub fn succeeded(&self) -> bool {
+        self.effects.status.status == "success"
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -239,6 +400,9 @@ pub enum SuiClientError {
     #[error("Request failed: {0}")]
     Request(String),
 
+    #[error("Request timed out")]
+    Timeout,
+
     #[error("RPC error ({code}): {message}")]
     Rpc { code: i32, message: String },
 
@@ -251,6 +415,233 @@ pub enum SuiClientError {
     #[error("Transaction failed: {0}")]
     TransactionFailed(String),
 
-    #[error("Insufficient balance")]
-    InsufficientBalance,
+    #[error("Failed to build transaction: {0}")]
+    PtbBuild(String),
+
+    #[error("Insufficient balance: short by {}", required.saturating_sub(*available))]
+    InsufficientBalance { required: u64, available: u64 },
+}
+
+impl SuiClientError {
+    fn from_reqwest(err: reqwest::Error) -> Self {
+        if err.is_timeout() {
+            SuiClientError::Timeout
+        } else {
+            SuiClientError::Request(err.to_string())
+        }
+    }
+
+    /// Amount missing to cover the request, for `InsufficientBalance`
+    pub fn shortfall(&self) -> Option<u64> {
+        match self {
+            SuiClientError::InsufficientBalance {
+                required,
+                available,
+            } => Some(required.saturating_sub(*available)),
+            _ => None,
+        }
+    }
+
+    /// Connection errors, timeouts, HTTP 429 and HTTP 5xx may succeed on retry.
+    /// JSON-RPC errors (negative codes), missing objects and insufficient
+    /// balance are deterministic.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            SuiClientError::Request(_) | SuiClientError::Timeout => true,
+            SuiClientError::Rpc { code, .. } => *code == 429 || (500..600).contains(code),
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::RetryPolicy;
+    use wiremock::matchers::method;
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn client_for(server: &MockServer) -> SuiClient {
+        SuiClient::new(
+            SuiConfig::testnet()
+                .with_rpc_url(server.uri())
+                .with_retry(RetryPolicy {
+                    max_attempts: 3,
+                    base_delay: Duration::from_millis(1),
+                    max_delay: Duration::from_millis(5),
+                }),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_rpc_call_retries_transient_errors() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(429))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": "42"
+            })))
+            .mount(&server)
+            .await;
+
+        let result: String = client_for(&server)
+            .rpc_call(
+                "sui_getLatestCheckpointSequenceNumber",
+                serde_json::json!([]),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result, "42");
+        assert_eq!(server.received_requests().await.unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_select_coins_merges_two_coins() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": {
+                    "data": [
+                        { "coinType": "0x2::usdc::USDC", "coinObjectId": "0xa", "version": "3", "digest": "da", "balance": "400000" },
+                        { "coinType": "0x2::usdc::USDC", "coinObjectId": "0xb", "version": "4", "digest": "db", "balance": "700000" },
+                        { "coinType": "0x2::usdc::USDC", "coinObjectId": "0xc", "version": "5", "digest": "dc", "balance": "100000" }
+                    ],
+                    "nextCursor": "0xc",
+                    "hasNextPage": false
+                }
+            })))
+            .mount(&server)
+            .await;
+
+        let client = client_for(&server);
+        let selection = client
+            .select_coins("0x1", "0x2::usdc::USDC", 1_000_000)
+            .await
+            .unwrap();
+
+        assert_eq!(selection.primary.coin_object_id, "0xb");
+        assert_eq!(selection.merge.len(), 1);
+        assert_eq!(selection.merge[0].coin_object_id, "0xa");
+        assert_eq!(selection.change, 100_000);
+
+        let err = client
+            .select_coins("0x1", "0x2::usdc::USDC", 2_000_000)
+            .await
+            .unwrap_err();
+        assert_eq!(err.shortfall(), Some(800_000));
+    }
+
+    #[tokio::test]
+    async fn test_initial_shared_version_from_owner() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": {
+                    "data": {
+                        "objectId": "0x5",
+                        "version": "900",
+                        "digest": "d5",
+                        "owner": { "Shared": { "initial_shared_version": 42 } }
+                    }
+                }
+            })))
+            .mount(&server)
+            .await;
+
+        let version = client_for(&server).initial_shared_version("0x5").await;
+        assert_eq!(version.unwrap(), 42);
+
+        let owned = SuiObject {
+            object_id: "0x6".to_string(),
+            version: "1".to_string(),
+            digest: "d6".to_string(),
+            r#type: None,
+            owner: Some(serde_json::json!({ "AddressOwner": "0x1" })),
+            content: None,
+        };
+        assert_eq!(owned.initial_shared_version(), None);
+    }
+
+    #[tokio::test]
+    async fn test_rpc_call_does_not_retry_rpc_errors() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "error": { "code": -32602, "message": "Invalid object id" }
+            })))
+            .mount(&server)
+            .await;
+
+        let err = client_for(&server)
+            .rpc_call::<serde_json::Value>("sui_getObject", serde_json::json!(["0xbad"]))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, SuiClientError::Rpc { code: -32602, .. }));
+        assert_eq!(server.received_requests().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_slow_rpc_is_a_retryable_timeout() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_secs(2)))
+            .mount(&server)
+            .await;
+
+        let err = client_for(&server)
+            .with_timeouts(HttpTimeouts {
+                request: Duration::from_millis(100),
+                connect: Duration::from_millis(100),
+            })
+            .get_object("0x5")
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, SuiClientError::Timeout));
+        assert!(err.is_retryable());
+    }
+
+    #[test]
+    fn test_insufficient_balance_is_not_retryable() {
+        let err = SuiClientError::InsufficientBalance {
+            required: 10,
+            available: 4,
+        };
+        assert!(!err.is_retryable());
+        assert_eq!(err.shortfall(), Some(6));
+        assert_eq!(err.to_string(), "Insufficient balance: short by 6");
+
+        // The balance moved between the check and the error
+        let err = SuiClientError::InsufficientBalance {
+            required: 4,
+            available: 10,
+        };
+        assert_eq!(err.shortfall(), Some(0));
+        assert_eq!(err.to_string(), "Insufficient balance: short by 0");
+    }
 }
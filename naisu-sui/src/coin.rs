@@ -0,0 +1,146 @@
+//! Coin selection for PTBs that spend a fungible balance
+//!
+//! Sui balances are split across coin objects; spending an amount means
+//! picking enough of them, merging into one and splitting off the exact amount.
+
+use serde::{Deserialize, Serialize};
+
+use crate::client::{CoinObject, SuiClientError};
+use crate::ptb::{PtbArgument, PtbBuilder};
+
+/// Coins chosen to cover an amount
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CoinSelection {
+    /// Coin that receives the merges and is split from
+    pub primary: CoinObject,
+    /// Additional coins merged into `primary`
+    pub merge: Vec<CoinObject>,
+    /// Requested amount
+    pub amount: u64,
+    /// Balance left in `primary` after splitting off `amount`
+    pub change: u64,
+}
+
+impl CoinSelection {
+    /// Pick the fewest coins (largest first) whose balances cover `amount`
+    pub fn select(coins: Vec<CoinObject>, amount: u64) -> Result<Self, SuiClientError> {
+        let mut coins = coins
+            .into_iter()
+            .map(|coin| coin_balance(&coin).map(|balance| (balance, coin)))
+            .collect::<Result<Vec<_>, _>>()?;
+        coins.sort_by_key(|(balance, _)| std::cmp::Reverse(*balance));
+
+        let mut total: u64 = 0;
+        let mut chosen = Vec::new();
+        for (balance, coin) in coins {
+            if total >= amount && !chosen.is_empty() {
+                break;
+            }
+            total = total.saturating_add(balance);
+            chosen.push(coin);
+        }
+
+        if total < amount || chosen.is_empty() {
+            return Err(SuiClientError::InsufficientBalance {
+                required: amount,
+                available: total,
+            });
+        }
+
+        let primary = chosen.remove(0);
+        Ok(Self {
+            primary,
+            merge: chosen,
+            amount,
+            change: total - amount,
+        })
+    }
+
+    /// Add the selected coins to `builder`, merge them and split off `amount`.
+    /// Returns the argument for the split coin.
+    pub fn add_to_ptb(&self, builder: &mut PtbBuilder) -> Result<PtbArgument, SuiClientError> {
+        let primary = add_coin(builder, &self.primary)?;
+        if !self.merge.is_empty() {
+            let sources = self
+                .merge
+                .iter()
+                .map(|coin| add_coin(builder, coin))
+                .collect::<Result<Vec<_>, _>>()?;
+            builder.merge_coins(primary.clone(), sources);
+        }
+
+        let amount = builder
+            .add_pure(&self.amount)
+            .map_err(|e| SuiClientError::PtbBuild(e.to_string()))?;
+        Ok(builder.split_coins(primary, vec![amount]))
+    }
+}
+
+fn coin_balance(coin: &CoinObject) -> Result<u64, SuiClientError> {
+    coin.balance.parse().map_err(|_| {
+        SuiClientError::Parse(format!(
+            "Invalid balance {} for coin {}",
+            coin.balance, coin.coin_object_id
+        ))
+    })
+}
+
+fn add_coin(builder: &mut PtbBuilder, coin: &CoinObject) -> Result<PtbArgument, SuiClientError> {
+    let version = coin.version.parse().map_err(|_| {
+        SuiClientError::PtbBuild(format!(
+            "Invalid version {} for coin {}",
+            coin.version, coin.coin_object_id
+        ))
+    })?;
+    Ok(builder.add_object(&coin.coin_object_id, version, &coin.digest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ptb::PtbCommand;
+
+    fn coin(id: &str, balance: u64) -> CoinObject {
+        CoinObject {
+            coin_type: "0x2::sui::SUI".to_string(),
+            coin_object_id: id.to_string(),
+            version: "1".to_string(),
+            digest: "digest".to_string(),
+            balance: balance.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_single_coin_covers_amount() {
+        let selection =
+            CoinSelection::select(vec![coin("0xa", 10), coin("0xb", 500)], 100).unwrap();
+        assert_eq!(selection.primary.coin_object_id, "0xb");
+        assert!(selection.merge.is_empty());
+        assert_eq!(selection.change, 400);
+    }
+
+    #[test]
+    fn test_insufficient_balance_reports_shortfall() {
+        let err = CoinSelection::select(vec![coin("0xa", 10), coin("0xb", 20)], 100).unwrap_err();
+        assert!(matches!(
+            err,
+            SuiClientError::InsufficientBalance {
+                required: 100,
+                available: 30
+            }
+        ));
+        assert_eq!(err.to_string(), "Insufficient balance: short by 70");
+    }
+
+    #[test]
+    fn test_merge_then_split_in_ptb() {
+        let selection = CoinSelection::select(vec![coin("0xa", 60), coin("0xb", 70)], 100).unwrap();
+
+        let mut builder = PtbBuilder::new();
+        selection.add_to_ptb(&mut builder).unwrap();
+        let ptb = builder.build();
+
+        assert!(matches!(ptb.commands[0], PtbCommand::MergeCoins(_)));
+        assert!(matches!(ptb.commands[1], PtbCommand::SplitCoins(_)));
+    }
+}
@@ -1,7 +1,87 @@
 //! Sui configuration
 
+use std::time::Duration;
+
 use naisu_core::SuiNetwork;
 
+use crate::cctp::CctpPackages;
+
+/// Retry policy for Sui RPC calls
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Total attempts, including the first (1 disables retries)
+    pub max_attempts: u32,
+    /// Delay before the first retry; doubles on each subsequent retry
+    pub base_delay: Duration,
+    /// Upper bound on a single delay (before jitter)
+    pub max_delay: Duration,
+}
+
+impl RetryPolicy {
+    /// No retries
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    /// Backoff before retry number `retry` (1-based), with up to 50% added jitter
+    pub fn delay_for(&self, retry: u32) -> Duration {
+        let exp = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(retry.saturating_sub(1)));
+        let delay = exp.min(self.max_delay);
+
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.subsec_nanos())
+            .unwrap_or_default();
+        let jitter = delay.mul_f64(f64::from(nanos % 1000) / 2000.0);
+
+        delay + jitter
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(5),
+        }
+    }
+}
+
+/// Timeouts for outbound HTTP API clients (Circle, Wormholescan)
+#[derive(Debug, Clone, Copy, serde::Serialize)]
+pub struct HttpTimeouts {
+    /// Whole-request deadline, including reading the body
+    pub request: Duration,
+    /// TCP/TLS connect deadline
+    pub connect: Duration,
+}
+
+impl HttpTimeouts {
+    /// Build a `reqwest::Client` enforcing these timeouts
+    pub fn build_client(&self) -> reqwest::Client {
+        reqwest::Client::builder()
+            .timeout(self.request)
+            .connect_timeout(self.connect)
+            .build()
+            .expect("Failed to build HTTP client")
+    }
+}
+
+impl Default for HttpTimeouts {
+    fn default() -> Self {
+        Self {
+            request: Duration::from_secs(10),
+            connect: Duration::from_secs(5),
+        }
+    }
+}
+
 /// Sui chain configuration
 #[derive(Debug, Clone)]
 pub struct SuiConfig {
@@ -14,6 +94,10 @@ pub struct SuiConfig {
     pub navi_package: Option<String>,
     /// USDC coin type on Sui
     pub usdc_coin_type: String,
+    /// Retry policy for transient RPC failures
+    pub retry: RetryPolicy,
+    /// CCTP packages for `network`
+    pub cctp: CctpPackages,
 }
 
 impl SuiConfig {
@@ -24,10 +108,9 @@ impl SuiConfig {
             private_key: None,
             scallop_package: None,
             navi_package: None,
-            // Testnet USDC (example - actual address may differ)
-            usdc_coin_type:
-                "0x5d4b302506645c37ff133b98c4b50a5ae14841659738d6d733d59d0d217a93bf::coin::COIN"
-                    .to_string(),
+            usdc_coin_type: SuiNetwork::Testnet.usdc_coin_type().to_string(),
+            retry: RetryPolicy::default(),
+            cctp: CctpPackages::for_network(SuiNetwork::Testnet),
         }
     }
 
@@ -38,9 +121,9 @@ impl SuiConfig {
             private_key: None,
             scallop_package: Some("0x...".to_string()), // Actual Scallop package
             navi_package: Some("0x...".to_string()),    // Actual Navi package
-            usdc_coin_type:
-                "0x5d4b302506645c37ff133b98c4b50a5ae14841659738d6d733d59d0d217a93bf::coin::COIN"
-                    .to_string(),
+            usdc_coin_type: SuiNetwork::Mainnet.usdc_coin_type().to_string(),
+            retry: RetryPolicy::default(),
+            cctp: CctpPackages::for_network(SuiNetwork::Mainnet),
         }
     }
 
@@ -58,4 +141,14 @@ impl SuiConfig {
         self.navi_package = Some(package);
         self
     }
+
+    pub fn with_rpc_url(mut self, rpc_url: String) -> Self {
+        self.rpc_url = rpc_url;
+        self
+    }
+
+    pub fn with_retry(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
 }
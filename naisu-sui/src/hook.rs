@@ -0,0 +1,169 @@
+//! Naisu V4 hook logs over EVM JSON-RPC
+//!
+//! Pulls `IntentCreated` logs with `eth_getLogs`, either every log in a block
+//! range or the one log for an intent id. Shared by the API (admin replay and
+//! the listener's polling mode) and the agent.
+
+use naisu_core::{EvmLog, IntentCreatedEvent};
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::HttpTimeouts;
+
+#[derive(Debug, thiserror::Error)]
+pub enum HookLogError {
+    #[error("HTTP request failed: {0}")]
+    Request(String),
+
+    #[error("RPC error {code}: {message}")]
+    Rpc { code: i64, message: String },
+
+    #[error("Failed to parse response: {0}")]
+    Parse(String),
+}
+
+#[derive(Debug, Deserialize)]
+struct RpcResponse<T> {
+    result: Option<T>,
+    #[serde(default)]
+    error: Option<RpcErrorBody>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RpcErrorBody {
+    code: i64,
+    message: String,
+}
+
+/// Fetches hook logs over JSON-RPC
+#[derive(Debug, Clone)]
+pub struct HookLogClient {
+    client: reqwest::Client,
+    rpc_url: String,
+    hook_address: String,
+}
+
+impl HookLogClient {
+    pub fn new(rpc_url: impl Into<String>, hook_address: impl Into<String>) -> Self {
+        Self {
+            client: HttpTimeouts::default().build_client(),
+            rpc_url: rpc_url.into(),
+            hook_address: hook_address.into(),
+        }
+    }
+
+    /// Override the default request/connect timeouts
+    pub fn with_timeouts(mut self, timeouts: HttpTimeouts) -> Self {
+        self.client = timeouts.build_client();
+        self
+    }
+
+    /// `IntentCreated` logs emitted by the hook in `from_block..=to_block`
+    pub async fn backfill(
+        &self,
+        from_block: u64,
+        to_block: u64,
+    ) -> Result<Vec<EvmLog>, HookLogError> {
+        self.get_logs(json!({
+            "address": self.hook_address,
+            "fromBlock": format!("0x{:x}", from_block),
+            "toBlock": format!("0x{:x}", to_block),
+            "topics": [IntentCreatedEvent::topic0()],
+        }))
+        .await
+    }
+
+    /// The `IntentCreated` log for `intent_id` (bytes32 hex), searching from
+    /// `from_block` to the chain head. `None` if the hook never emitted it.
+    pub async fn find_intent(
+        &self,
+        intent_id: &str,
+        from_block: u64,
+    ) -> Result<Option<EvmLog>, HookLogError> {
+        let id = intent_id.strip_prefix("0x").unwrap_or(intent_id);
+        let logs = self
+            .get_logs(json!({
+                "address": self.hook_address,
+                "fromBlock": format!("0x{:x}", from_block),
+                "toBlock": "latest",
+                "topics": [IntentCreatedEvent::topic0(), format!("0x{:0>64}", id.to_lowercase())],
+            }))
+            .await?;
+        Ok(logs.into_iter().next())
+    }
+
+    /// Current chain head
+    pub async fn block_number(&self) -> Result<u64, HookLogError> {
+        let head: String = self.rpc_call("eth_blockNumber", json!([])).await?;
+        u64::from_str_radix(head.trim_start_matches("0x"), 16)
+            .map_err(|e| HookLogError::Parse(format!("block number {}: {}", head, e)))
+    }
+
+    async fn get_logs(&self, filter: serde_json::Value) -> Result<Vec<EvmLog>, HookLogError> {
+        self.rpc_call("eth_getLogs", json!([filter])).await
+    }
+
+    async fn rpc_call<T: serde::de::DeserializeOwned>(
+        &self,
+        method: &str,
+        params: serde_json::Value,
+    ) -> Result<T, HookLogError> {
+        let body = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": method,
+            "params": params,
+        });
+
+        let response: RpcResponse<T> = self
+            .client
+            .post(&self.rpc_url)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|e| HookLogError::Request(e.to_string()))?
+            .json()
+            .await
+            .map_err(|e| HookLogError::Parse(e.to_string()))?;
+
+        if let Some(error) = response.error {
+            return Err(HookLogError::Rpc {
+                code: error.code,
+                message: error.message,
+            });
+        }
+
+        response
+            .result
+            .ok_or_else(|| HookLogError::Parse("missing result".to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::*;
+    use wiremock::matchers::method;
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_stalled_rpc_times_out() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_secs(5)))
+            .mount(&server)
+            .await;
+
+        let client = HookLogClient::new(server.uri(), "0xf0093fcf07aa10de35b78d1f33c60439d11bc0c0")
+            .with_timeouts(HttpTimeouts {
+                request: Duration::from_millis(100),
+                connect: Duration::from_millis(100),
+            });
+        let started = Instant::now();
+        let err = client.find_intent("0x09", 0).await.unwrap_err();
+
+        assert!(matches!(err, HookLogError::Request(_)));
+        assert!(started.elapsed() < Duration::from_secs(2));
+    }
+}
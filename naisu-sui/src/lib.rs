@@ -5,23 +5,36 @@
 //! - PTB (Programmable Transaction Block) construction
 //! - Scallop/Navi/Cetus protocol integration
 //! - Bridge fund detection
+//! - Circle CCTP attestation polling
+//! - Naisu V4 hook log fetching
+//! - Wormhole token bridge for non-USDC assets
 //! - Protocol adapters for yield optimization
 
 pub mod adapters;
 pub mod cctp;
+pub mod circle;
 pub mod client;
+pub mod coin;
 pub mod config;
+pub mod hook;
 pub mod protocols;
 pub mod ptb;
 pub mod ptb_cetus;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+pub mod wormhole;
 
 pub use adapters::*;
 pub use cctp::*;
+pub use circle::*;
 pub use client::*;
+pub use coin::*;
 pub use config::*;
+pub use hook::*;
 pub use protocols::*;
 pub use ptb::*;
 pub use ptb_cetus::*;
+pub use wormhole::*;
 
 /// Cetus protocol constants
 pub mod cetus {
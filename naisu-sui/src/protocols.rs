@@ -1,7 +1,9 @@
 //! Sui DeFi protocol integrations (Scallop, Navi)
 
-use crate::ptb::{PtbArgument, PtbBuilder};
-use naisu_core::YieldStrategy;
+use crate::client::{SuiObject, TransactionResponse};
+use crate::ptb::{PtbArgument, PtbBuilder, PtbError};
+use naisu_core::addr::normalize_sui_address;
+use naisu_core::{SuiNetwork, YieldStrategy};
 
 /// Scallop protocol integration
 pub struct ScallopProtocol {
@@ -10,6 +12,11 @@ pub struct ScallopProtocol {
 }
 
 impl ScallopProtocol {
+    /// Type of a user's position, relative to the package
+    pub const OBLIGATION_TYPE: &'static str = "obligation::Obligation";
+    /// Type of the owned key that authorizes use of an obligation
+    pub const OBLIGATION_KEY_TYPE: &'static str = "obligation::ObligationKey";
+
     pub fn new(package_id: String, market_id: String) -> Self {
         Self {
             package_id,
@@ -17,31 +24,67 @@ impl ScallopProtocol {
         }
     }
 
+    /// Build PTB commands for depositing into an obligation
+    pub fn build_deposit_collateral(
+        &self,
+        ptb: &mut PtbBuilder,
+        coin_type: &str,
+        obligation: PtbArgument,
+        market: PtbArgument,
+        coin: PtbArgument,
+    ) -> PtbArgument {
+        ptb.move_call(
+            &self.package_id,
+            "deposit_collateral",
+            "deposit_collateral",
+            vec![coin_type.to_string()],
+            vec![obligation, market, coin],
+        )
+    }
+
     /// Build PTB commands for depositing USDC into Scallop
     pub fn build_deposit_usdc(
         &self,
         ptb: &mut PtbBuilder,
+        coin_type: &str,
         usdc_coin: PtbArgument,
         market: PtbArgument,
     ) -> PtbArgument {
-        // Call scallop::lending::deposit<USDC>
+        // Call scallop::lending::deposit<T>
         ptb.move_call(
             &self.package_id,
             "lending",
             "deposit",
-            vec![
-                // USDC type argument
-                "0x5d4b302506645c37ff133b98c4b50a5ae14841659738d6d733d59d0d217a93bf::coin::COIN"
-                    .to_string(),
-            ],
+            vec![coin_type.to_string()],
             vec![market, usdc_coin],
         )
     }
 
+    /// Build PTB commands for withdrawing collateral from an obligation,
+    /// authorized by its `ObligationKey`
+    pub fn build_withdraw_collateral(
+        &self,
+        ptb: &mut PtbBuilder,
+        coin_type: &str,
+        obligation: PtbArgument,
+        obligation_key: PtbArgument,
+        market: PtbArgument,
+        amount: PtbArgument,
+    ) -> PtbArgument {
+        ptb.move_call(
+            &self.package_id,
+            "withdraw_collateral",
+            "withdraw_collateral",
+            vec![coin_type.to_string()],
+            vec![obligation, obligation_key, market, amount],
+        )
+    }
+
     /// Build PTB commands for withdrawing from Scallop
     pub fn build_withdraw_usdc(
         &self,
         ptb: &mut PtbBuilder,
+        coin_type: &str,
         amount: PtbArgument,
         market: PtbArgument,
     ) -> PtbArgument {
@@ -49,10 +92,7 @@ impl ScallopProtocol {
             &self.package_id,
             "lending",
             "withdraw",
-            vec![
-                "0x5d4b302506645c37ff133b98c4b50a5ae14841659738d6d733d59d0d217a93bf::coin::COIN"
-                    .to_string(),
-            ],
+            vec![coin_type.to_string()],
             vec![market, amount],
         )
     }
@@ -65,6 +105,9 @@ pub struct NaviProtocol {
 }
 
 impl NaviProtocol {
+    /// Type of a user's position, relative to the package
+    pub const ACCOUNT_CAP_TYPE: &'static str = "account::AccountCap";
+
     pub fn new(package_id: String, pool_id: String) -> Self {
         Self {
             package_id,
@@ -72,22 +115,50 @@ impl NaviProtocol {
         }
     }
 
-    /// Build PTB commands for depositing USDC into Navi
-    pub fn build_deposit_usdc(
+    /// Create a new account, returning its `AccountCap`
+    pub fn build_create_account(&self, ptb: &mut PtbBuilder) -> PtbArgument {
+        ptb.move_call(
+            &self.package_id,
+            "lending",
+            "create_account",
+            vec![],
+            vec![],
+        )
+    }
+
+    /// Build PTB commands for depositing into the account behind `account_cap`
+    pub fn build_deposit_with_account_cap(
         &self,
         ptb: &mut PtbBuilder,
-        usdc_coin: PtbArgument,
+        coin_type: &str,
+        coin: PtbArgument,
         pool: PtbArgument,
+        account_cap: PtbArgument,
     ) -> PtbArgument {
         ptb.move_call(
             &self.package_id,
             "pool",
-            "deposit",
-            vec![
-                "0x5d4b302506645c37ff133b98c4b50a5ae14841659738d6d733d59d0d217a93bf::coin::COIN"
-                    .to_string(),
-            ],
-            vec![pool, usdc_coin],
+            "deposit_with_account_cap",
+            vec![coin_type.to_string()],
+            vec![pool, coin, account_cap],
+        )
+    }
+
+    /// Build PTB commands for withdrawing from the account behind `account_cap`
+    pub fn build_withdraw_with_account_cap(
+        &self,
+        ptb: &mut PtbBuilder,
+        coin_type: &str,
+        amount: PtbArgument,
+        pool: PtbArgument,
+        account_cap: PtbArgument,
+    ) -> PtbArgument {
+        ptb.move_call(
+            &self.package_id,
+            "pool",
+            "withdraw_with_account_cap",
+            vec![coin_type.to_string()],
+            vec![pool, amount, account_cap],
         )
     }
 
@@ -95,6 +166,7 @@ impl NaviProtocol {
     pub fn build_withdraw_usdc(
         &self,
         ptb: &mut PtbBuilder,
+        coin_type: &str,
         amount: PtbArgument,
         pool: PtbArgument,
     ) -> PtbArgument {
@@ -102,26 +174,411 @@ impl NaviProtocol {
             &self.package_id,
             "pool",
             "withdraw",
-            vec![
-                "0x5d4b302506645c37ff133b98c4b50a5ae14841659738d6d733d59d0d217a93bf::coin::COIN"
-                    .to_string(),
-            ],
+            vec![coin_type.to_string()],
             vec![pool, amount],
         )
     }
 }
 
+/// A user's existing position on a lending protocol (Scallop obligation,
+/// Navi account cap)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PositionObject {
+    Shared {
+        object_id: String,
+        initial_shared_version: u64,
+    },
+    Owned {
+        object_id: String,
+        version: u64,
+        digest: String,
+    },
+}
+
+impl PositionObject {
+    pub fn object_id(&self) -> &str {
+        match self {
+            PositionObject::Shared { object_id, .. } | PositionObject::Owned { object_id, .. } => {
+                object_id
+            }
+        }
+    }
+
+    fn add_to(&self, ptb: &mut PtbBuilder) -> PtbArgument {
+        match self {
+            PositionObject::Shared {
+                object_id,
+                initial_shared_version,
+            } => ptb.add_shared_object(object_id, *initial_shared_version, true),
+            PositionObject::Owned {
+                object_id,
+                version,
+                digest,
+            } => ptb.add_object(object_id, *version, digest),
+        }
+    }
+}
+
+/// Where a deposit lands
+#[derive(Debug, Clone, Copy)]
+pub enum Position<'a> {
+    /// Add to the user's existing position
+    Existing(&'a PositionObject),
+    /// Open a new position and transfer its handle to `owner`
+    New { owner: &'a str },
+}
+
+/// Where a withdraw draws from
+#[derive(Debug, Clone, Copy)]
+pub enum WithdrawFrom<'a> {
+    /// The protocol's lending pool
+    Pool,
+    /// The user's Scallop obligation, unlocked by its `ObligationKey`
+    Obligation {
+        obligation: &'a PositionObject,
+        key: &'a PositionObject,
+    },
+    /// The user's Navi account, behind its `AccountCap`
+    AccountCap(&'a PositionObject),
+}
+
 /// Protocol factory for creating protocol instances based on strategy
 pub struct ProtocolFactory;
 
 impl ProtocolFactory {
-    /// Create a deposit PTB for the given strategy
+    /// Whether the package / object ids `strategy`'s protocol needs are configured
+    pub fn is_available(strategy: YieldStrategy, protocol_config: &ProtocolConfig) -> bool {
+        match strategy {
+            YieldStrategy::ScallopUsdc | YieldStrategy::ScallopSui => {
+                protocol_config.scallop_package.is_some()
+                    && protocol_config.scallop_market.is_some()
+            }
+            YieldStrategy::NaviUsdc | YieldStrategy::NaviSui => {
+                protocol_config.navi_package.is_some() && protocol_config.navi_pool.is_some()
+            }
+            YieldStrategy::Custom(_) => false,
+        }
+    }
+
+    /// Fully-qualified type of a user's position for `strategy`'s protocol
+    pub fn position_type(
+        strategy: YieldStrategy,
+        protocol_config: &ProtocolConfig,
+    ) -> Result<String, ProtocolError> {
+        let (package, suffix) = match strategy {
+            YieldStrategy::ScallopUsdc | YieldStrategy::ScallopSui => (
+                protocol_config
+                    .scallop_package
+                    .as_deref()
+                    .ok_or(ProtocolError::NotConfigured("Scallop"))?,
+                ScallopProtocol::OBLIGATION_TYPE,
+            ),
+            YieldStrategy::NaviUsdc | YieldStrategy::NaviSui => (
+                protocol_config
+                    .navi_package
+                    .as_deref()
+                    .ok_or(ProtocolError::NotConfigured("Navi"))?,
+                NaviProtocol::ACCOUNT_CAP_TYPE,
+            ),
+            YieldStrategy::Custom(_) => {
+                return Err(ProtocolError::NotImplemented("Custom strategies"))
+            }
+        };
+        let package = normalize_sui_address(package)
+            .map_err(|_| ProtocolError::NotConfigured("protocol package id"))?;
+        Ok(format!("{}::{}", package, suffix))
+    }
+
+    /// Check that `object` is a position of `strategy`'s protocol usable by
+    /// `owner`: the protocol's position type, and either shared or owned by
+    /// `owner`
+    pub fn position_object(
+        strategy: YieldStrategy,
+        object: &SuiObject,
+        owner: &str,
+        protocol_config: &ProtocolConfig,
+    ) -> Result<PositionObject, ProtocolError> {
+        let expected = Self::position_type(strategy, protocol_config)?;
+        if object.r#type.as_deref() != Some(expected.as_str()) {
+            return Err(ProtocolError::InvalidPosition(format!(
+                "{} is a {}, expected {}",
+                object.object_id,
+                object.r#type.as_deref().unwrap_or("object of unknown type"),
+                expected
+            )));
+        }
+
+        let owner_field = object.owner.as_ref();
+        if let Some(version) = owner_field
+            .and_then(|o| o.pointer("/Shared/initial_shared_version"))
+            .and_then(|v| v.as_u64())
+        {
+            return Ok(PositionObject::Shared {
+                object_id: object.object_id.clone(),
+                initial_shared_version: version,
+            });
+        }
+
+        let owned_by = owner_field
+            .and_then(|o| o.get("AddressOwner"))
+            .and_then(|a| a.as_str());
+        let owner_matches = match (
+            owned_by.map(normalize_sui_address),
+            normalize_sui_address(owner),
+        ) {
+            (Some(Ok(a)), Ok(b)) => a == b,
+            _ => false,
+        };
+        if !owner_matches {
+            return Err(ProtocolError::InvalidPosition(format!(
+                "{} is not owned by {}",
+                object.object_id, owner
+            )));
+        }
+        Ok(PositionObject::Owned {
+            object_id: object.object_id.clone(),
+            version: object.version.parse().map_err(|_| {
+                ProtocolError::InvalidPosition(format!("bad version {}", object.version))
+            })?,
+            digest: object.digest.clone(),
+        })
+    }
+
+    /// Fully-qualified `ObligationKey` type, for Scallop strategies; other
+    /// protocols' positions need no separate key
+    pub fn position_key_type(
+        strategy: YieldStrategy,
+        protocol_config: &ProtocolConfig,
+    ) -> Result<Option<String>, ProtocolError> {
+        match strategy {
+            YieldStrategy::ScallopUsdc | YieldStrategy::ScallopSui => {
+                let package = protocol_config
+                    .scallop_package
+                    .as_deref()
+                    .ok_or(ProtocolError::NotConfigured("Scallop"))?;
+                let package = normalize_sui_address(package)
+                    .map_err(|_| ProtocolError::NotConfigured("protocol package id"))?;
+                Ok(Some(format!(
+                    "{}::{}",
+                    package,
+                    ScallopProtocol::OBLIGATION_KEY_TYPE
+                )))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Find which of `keys` (the owner's `ObligationKey`s) unlocks the
+    /// shared obligation `position`
+    pub fn check_position_key(
+        position: &PositionObject,
+        keys: &[SuiObject],
+    ) -> Result<PositionObject, ProtocolError> {
+        let target = normalize_sui_address(position.object_id()).ok();
+        let unlocks = |key: &SuiObject| {
+            key.content
+                .as_ref()
+                .and_then(|c| c.pointer("/fields/ownership/fields/of"))
+                .and_then(|of| of.as_str())
+                .map(normalize_sui_address)
+                .and_then(Result::ok)
+                .is_some_and(|of| Some(of) == target)
+        };
+        let key = keys.iter().find(|key| unlocks(key)).ok_or_else(|| {
+            ProtocolError::InvalidPosition(format!(
+                "no ObligationKey for {} is held by the sender",
+                position.object_id()
+            ))
+        })?;
+        Ok(PositionObject::Owned {
+            object_id: key.object_id.clone(),
+            version: key.version.parse().map_err(|_| {
+                ProtocolError::InvalidPosition(format!("bad version {}", key.version))
+            })?,
+            digest: key.digest.clone(),
+        })
+    }
+
+    /// Id of the position a deposit PTB opened, from its executed
+    /// transaction's object changes
+    pub fn created_position_id(
+        strategy: YieldStrategy,
+        response: &TransactionResponse,
+        protocol_config: &ProtocolConfig,
+    ) -> Option<String> {
+        let expected = Self::position_type(strategy, protocol_config).ok()?;
+        response
+            .object_changes
+            .iter()
+            .flatten()
+            .filter(|change| change["type"] == "created")
+            .find(|change| change["objectType"] == expected.as_str())
+            .and_then(|change| change["objectId"].as_str())
+            .map(str::to_string)
+    }
+
+    /// Create a deposit PTB for the given strategy, typed with its asset's
+    /// coin type on `network`
     pub fn build_deposit_ptb(
         strategy: YieldStrategy,
         usdc_coin: PtbArgument,
+        position: Position,
+        protocol_config: &ProtocolConfig,
+        network: SuiNetwork,
+    ) -> Result<PtbBuilder, ProtocolError> {
+        let mut ptb = PtbBuilder::new();
+        Self::add_deposit(
+            &mut ptb,
+            strategy,
+            usdc_coin,
+            position,
+            protocol_config,
+            network,
+        )?;
+        Ok(ptb)
+    }
+
+    /// Create one PTB that splits `usdc_coin` into `allocations` (amounts in
+    /// the smallest unit) and deposits each part into a new position owned by
+    /// `owner`
+    pub fn build_split_deposit_ptb(
+        allocations: &[(YieldStrategy, u64)],
+        usdc_coin: PtbArgument,
+        owner: &str,
+        protocol_config: &ProtocolConfig,
+        network: SuiNetwork,
+    ) -> Result<PtbBuilder, ProtocolError> {
+        let mut ptb = PtbBuilder::new();
+        let amounts = allocations
+            .iter()
+            .map(|(_, amount)| ptb.add_pure(amount))
+            .collect::<Result<_, _>>()?;
+        let parts = ptb.split_coins(usdc_coin, amounts);
+
+        for (result_index, (strategy, _)) in allocations.iter().enumerate() {
+            let part = parts
+                .nested(result_index as u16)
+                .expect("split_coins returns a command result");
+            Self::add_deposit(
+                &mut ptb,
+                *strategy,
+                part,
+                Position::New { owner },
+                protocol_config,
+                network,
+            )?;
+        }
+        Ok(ptb)
+    }
+
+    /// Create a PTB withdrawing `amount` USDC (smallest unit) from `strategy`'s
+    /// lending pool or the user's position, and transferring the coin to
+    /// `recipient`
+    pub fn build_withdraw_ptb(
+        strategy: YieldStrategy,
+        amount: u64,
+        from: WithdrawFrom,
+        recipient: &str,
         protocol_config: &ProtocolConfig,
+        network: SuiNetwork,
     ) -> Result<PtbBuilder, ProtocolError> {
+        let coin_type = strategy
+            .sui_coin_type(network)
+            .ok_or(ProtocolError::NotImplemented("Custom strategies"))?;
+
         let mut ptb = PtbBuilder::new();
+        let amount = ptb.add_pure(&amount)?;
+        let coin = match strategy {
+            YieldStrategy::ScallopUsdc => {
+                let scallop = ScallopProtocol::new(
+                    protocol_config
+                        .scallop_package
+                        .clone()
+                        .ok_or(ProtocolError::NotConfigured("Scallop"))?,
+                    protocol_config
+                        .scallop_market
+                        .clone()
+                        .ok_or(ProtocolError::NotConfigured("Scallop market"))?,
+                );
+                let market = ptb.add_shared_object(&scallop.market_id, 1, true);
+                match from {
+                    WithdrawFrom::Pool => {
+                        scallop.build_withdraw_usdc(&mut ptb, coin_type, amount, market)
+                    }
+                    WithdrawFrom::Obligation { obligation, key } => {
+                        let obligation = obligation.add_to(&mut ptb);
+                        let key = key.add_to(&mut ptb);
+                        scallop.build_withdraw_collateral(
+                            &mut ptb, coin_type, obligation, key, market, amount,
+                        )
+                    }
+                    WithdrawFrom::AccountCap(_) => {
+                        return Err(ProtocolError::InvalidPosition(
+                            "Scallop positions are obligations, not account caps".to_string(),
+                        ))
+                    }
+                }
+            }
+            YieldStrategy::NaviUsdc => {
+                let navi = NaviProtocol::new(
+                    protocol_config
+                        .navi_package
+                        .clone()
+                        .ok_or(ProtocolError::NotConfigured("Navi"))?,
+                    protocol_config
+                        .navi_pool
+                        .clone()
+                        .ok_or(ProtocolError::NotConfigured("Navi pool"))?,
+                );
+                let pool = ptb.add_shared_object(&navi.pool_id, 1, true);
+                match from {
+                    WithdrawFrom::Pool => {
+                        navi.build_withdraw_usdc(&mut ptb, coin_type, amount, pool)
+                    }
+                    WithdrawFrom::AccountCap(account_cap) => {
+                        let account_cap = account_cap.add_to(&mut ptb);
+                        navi.build_withdraw_with_account_cap(
+                            &mut ptb,
+                            coin_type,
+                            amount,
+                            pool,
+                            account_cap,
+                        )
+                    }
+                    WithdrawFrom::Obligation { .. } => {
+                        return Err(ProtocolError::InvalidPosition(
+                            "Navi positions are account caps, not obligations".to_string(),
+                        ))
+                    }
+                }
+            }
+            YieldStrategy::ScallopSui | YieldStrategy::NaviSui => {
+                // Would need a SUI -> USDC swap to end as Sui USDC
+                return Err(ProtocolError::NotImplemented(
+                    "SUI withdraw strategies require swap",
+                ));
+            }
+            YieldStrategy::Custom(_) => {
+                return Err(ProtocolError::NotImplemented("Custom strategies"));
+            }
+        };
+        let recipient = ptb.add_pure(&recipient)?;
+        ptb.transfer_objects(vec![coin], recipient);
+        Ok(ptb)
+    }
+
+    /// Append the deposit of `usdc_coin` into `strategy` at `position` to `ptb`
+    fn add_deposit(
+        ptb: &mut PtbBuilder,
+        strategy: YieldStrategy,
+        usdc_coin: PtbArgument,
+        position: Position,
+        protocol_config: &ProtocolConfig,
+        network: SuiNetwork,
+    ) -> Result<(), ProtocolError> {
+        let coin_type = strategy
+            .sui_coin_type(network)
+            .ok_or(ProtocolError::NotImplemented("Custom strategies"))?;
 
         match strategy {
             YieldStrategy::ScallopUsdc => {
@@ -140,7 +597,21 @@ impl ProtocolFactory {
                     1, // initial version
                     true,
                 );
-                scallop.build_deposit_usdc(&mut ptb, usdc_coin, market);
+                match position {
+                    Position::Existing(obligation) => {
+                        let obligation = obligation.add_to(ptb);
+                        scallop.build_deposit_collateral(
+                            ptb, coin_type, obligation, market, usdc_coin,
+                        );
+                    }
+                    Position::New { owner } => {
+                        // No obligation given: mint the interest-bearing sCoin
+                        // and hand it to the owner.
+                        let s_coin = scallop.build_deposit_usdc(ptb, coin_type, usdc_coin, market);
+                        let owner = ptb.add_pure(&owner)?;
+                        ptb.transfer_objects(vec![s_coin], owner);
+                    }
+                }
             }
             YieldStrategy::NaviUsdc => {
                 let navi = NaviProtocol::new(
@@ -155,7 +626,30 @@ impl ProtocolFactory {
                 );
                 let pool =
                     ptb.add_shared_object(&protocol_config.navi_pool.clone().unwrap(), 1, true);
-                navi.build_deposit_usdc(&mut ptb, usdc_coin, pool);
+                match position {
+                    Position::Existing(account_cap) => {
+                        let account_cap = account_cap.add_to(ptb);
+                        navi.build_deposit_with_account_cap(
+                            ptb,
+                            coin_type,
+                            usdc_coin,
+                            pool,
+                            account_cap,
+                        );
+                    }
+                    Position::New { owner } => {
+                        let account_cap = navi.build_create_account(ptb);
+                        navi.build_deposit_with_account_cap(
+                            ptb,
+                            coin_type,
+                            usdc_coin,
+                            pool,
+                            account_cap.clone(),
+                        );
+                        let owner = ptb.add_pure(&owner)?;
+                        ptb.transfer_objects(vec![account_cap], owner);
+                    }
+                }
             }
             YieldStrategy::ScallopSui | YieldStrategy::NaviSui => {
                 // For SUI strategies, need to swap USDC -> SUI first
@@ -169,12 +663,12 @@ impl ProtocolFactory {
             }
         }
 
-        Ok(ptb)
+        Ok(())
     }
 }
 
 /// Protocol configuration
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, serde::Serialize)]
 pub struct ProtocolConfig {
     pub scallop_package: Option<String>,
     pub scallop_market: Option<String>,
@@ -192,6 +686,381 @@ pub enum ProtocolError {
     #[error("Not implemented: {0}")]
     NotImplemented(&'static str),
 
+    #[error("Invalid position object: {0}")]
+    InvalidPosition(String),
+
     #[error("Execution failed: {0}")]
     ExecutionFailed(String),
+
+    #[error("Failed to build PTB: {0}")]
+    Ptb(#[from] PtbError),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ptb::{MoveCallCommand, ProgrammableTransactionBlock, PtbCommand, PtbInput};
+    use crate::testing::configured_protocols;
+
+    const OWNER: &str = "0x00000000000000000000000000000000000000000000000000000000000000a1";
+
+    fn calls(ptb: &ProgrammableTransactionBlock) -> Vec<&MoveCallCommand> {
+        ptb.commands
+            .iter()
+            .filter_map(|command| match command {
+                PtbCommand::MoveCall(call) => Some(call),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn sui_object(type_: &str, owner: serde_json::Value) -> SuiObject {
+        SuiObject {
+            object_id: "0xb0b".to_string(),
+            version: "7".to_string(),
+            digest: "digest".to_string(),
+            r#type: Some(type_.to_string()),
+            owner: Some(owner),
+            content: None,
+        }
+    }
+
+    #[test]
+    fn test_split_deposit_ptb_deposits_each_part() {
+        let total: u64 = 10_000_000;
+        let allocations = [
+            (YieldStrategy::ScallopUsdc, total * 60 / 100),
+            (YieldStrategy::NaviUsdc, total * 40 / 100),
+        ];
+
+        let ptb = ProtocolFactory::build_split_deposit_ptb(
+            &allocations,
+            PtbArgument::Input { index: 0 },
+            OWNER,
+            &configured_protocols(),
+            SuiNetwork::Testnet,
+        )
+        .unwrap()
+        .build();
+
+        let PtbCommand::SplitCoins(split) = &ptb.commands[0] else {
+            panic!("first command must split the minted coin");
+        };
+        let split_amounts: Vec<u64> = split
+            .amounts
+            .iter()
+            .map(|amount| {
+                let PtbArgument::Input { index } = amount else {
+                    panic!("split amounts are pure inputs");
+                };
+                let PtbInput::Pure { value } = &ptb.inputs[*index as usize] else {
+                    panic!("split amounts are pure inputs");
+                };
+                bcs::from_bytes(value).unwrap()
+            })
+            .collect();
+        assert_eq!(split_amounts, [6_000_000, 4_000_000]);
+        assert_eq!(split_amounts.iter().sum::<u64>(), total);
+
+        let deposits: Vec<_> = calls(&ptb)
+            .into_iter()
+            .filter(|call| call.function.starts_with("deposit"))
+            .collect();
+        assert_eq!(deposits.len(), 2);
+        assert_eq!(deposits[0].package, "0x5ca1");
+        assert_eq!(deposits[1].package, "0x4a71");
+        let coins = [&deposits[0].arguments[1], &deposits[1].arguments[1]];
+        for (result_index, coin) in coins.into_iter().enumerate() {
+            assert!(matches!(
+                coin,
+                PtbArgument::NestedResult { index: 0, result_index: r } if *r as usize == result_index
+            ));
+        }
+    }
+
+    #[test]
+    fn test_deposit_into_existing_obligation() {
+        let obligation = PositionObject::Shared {
+            object_id: "0x0b11".to_string(),
+            initial_shared_version: 42,
+        };
+
+        let ptb = ProtocolFactory::build_deposit_ptb(
+            YieldStrategy::ScallopUsdc,
+            PtbArgument::Input { index: 0 },
+            Position::Existing(&obligation),
+            &configured_protocols(),
+            SuiNetwork::Testnet,
+        )
+        .unwrap()
+        .build();
+
+        let calls = calls(&ptb);
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].function, "deposit_collateral");
+        let PtbArgument::Input { index } = calls[0].arguments[0] else {
+            panic!("obligation must be an input");
+        };
+        assert!(matches!(
+            &ptb.inputs[index as usize],
+            PtbInput::SharedObject { object_id, initial_shared_version: 42, mutable: true }
+                if object_id == "0x0b11"
+        ));
+    }
+
+    #[test]
+    fn test_deposit_without_position_opens_one() {
+        let ptb = ProtocolFactory::build_deposit_ptb(
+            YieldStrategy::NaviUsdc,
+            PtbArgument::Input { index: 0 },
+            Position::New { owner: OWNER },
+            &configured_protocols(),
+            SuiNetwork::Testnet,
+        )
+        .unwrap()
+        .build();
+
+        let functions: Vec<_> = calls(&ptb).iter().map(|c| c.function.as_str()).collect();
+        assert_eq!(functions, ["create_account", "deposit_with_account_cap"]);
+        let PtbCommand::TransferObjects(transfer) = ptb.commands.last().unwrap() else {
+            panic!("the new account cap must go to the owner");
+        };
+        assert!(matches!(
+            transfer.objects[0],
+            PtbArgument::Result { index: 0 }
+        ));
+    }
+
+    #[test]
+    fn test_scallop_deposit_without_obligation_mints_s_coin() {
+        let ptb = ProtocolFactory::build_deposit_ptb(
+            YieldStrategy::ScallopUsdc,
+            PtbArgument::Input { index: 0 },
+            Position::New { owner: OWNER },
+            &configured_protocols(),
+            SuiNetwork::Testnet,
+        )
+        .unwrap()
+        .build();
+
+        let calls = calls(&ptb);
+        assert_eq!(calls.len(), 1);
+        assert_eq!(
+            (calls[0].module.as_str(), calls[0].function.as_str()),
+            ("lending", "deposit")
+        );
+        let PtbCommand::TransferObjects(transfer) = ptb.commands.last().unwrap() else {
+            panic!("the sCoin must go to the owner");
+        };
+        assert!(matches!(
+            transfer.objects[0],
+            PtbArgument::Result { index: 0 }
+        ));
+    }
+
+    #[test]
+    fn test_created_position_id_reads_object_changes() {
+        let navi_cap = format!("0x{:0>64}::account::AccountCap", "4a71");
+        let response: TransactionResponse = serde_json::from_value(serde_json::json!({
+            "digest": "tx",
+            "effects": {
+                "status": { "status": "success" },
+                "gasUsed": { "computationCost": "1", "storageCost": "1" }
+            },
+            "objectChanges": [
+                { "type": "mutated", "objectType": navi_cap, "objectId": "0xold" },
+                { "type": "created", "objectType": "0x2::coin::Coin<0x2::sui::SUI>", "objectId": "0xc01" },
+                { "type": "created", "objectType": navi_cap, "objectId": "0xca9" }
+            ]
+        }))
+        .unwrap();
+
+        assert_eq!(
+            ProtocolFactory::created_position_id(
+                YieldStrategy::NaviUsdc,
+                &response,
+                &configured_protocols()
+            ),
+            Some("0xca9".to_string())
+        );
+        assert_eq!(
+            ProtocolFactory::created_position_id(
+                YieldStrategy::ScallopUsdc,
+                &response,
+                &configured_protocols()
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_shared_obligation_needs_senders_key() {
+        let obligation = PositionObject::Shared {
+            object_id: "0x0b11".to_string(),
+            initial_shared_version: 42,
+        };
+        let key_for = |of: &str| SuiObject {
+            content: Some(serde_json::json!({
+                "fields": { "ownership": { "fields": { "of": of } } }
+            })),
+            ..sui_object(
+                &ProtocolFactory::position_key_type(
+                    YieldStrategy::ScallopUsdc,
+                    &configured_protocols(),
+                )
+                .unwrap()
+                .unwrap(),
+                serde_json::json!({ "AddressOwner": OWNER }),
+            )
+        };
+
+        assert!(ProtocolFactory::check_position_key(&obligation, &[key_for("0xdead")]).is_err());
+        assert!(ProtocolFactory::check_position_key(&obligation, &[]).is_err());
+        let key = ProtocolFactory::check_position_key(
+            &obligation,
+            &[key_for("0xdead"), key_for(&format!("0x{:0>64}", "b11"))],
+        )
+        .unwrap();
+        assert!(matches!(key, PositionObject::Owned { .. }));
+        assert_eq!(
+            ProtocolFactory::position_key_type(YieldStrategy::NaviUsdc, &configured_protocols())
+                .unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_withdraw_ptb_returns_coin_to_recipient() {
+        let ptb = ProtocolFactory::build_withdraw_ptb(
+            YieldStrategy::ScallopUsdc,
+            1_000_000,
+            WithdrawFrom::Pool,
+            OWNER,
+            &configured_protocols(),
+            SuiNetwork::Testnet,
+        )
+        .unwrap()
+        .build();
+
+        let calls = calls(&ptb);
+        assert_eq!(calls.len(), 1);
+        assert_eq!(
+            (calls[0].module.as_str(), calls[0].function.as_str()),
+            ("lending", "withdraw")
+        );
+        let PtbCommand::TransferObjects(transfer) = ptb.commands.last().unwrap() else {
+            panic!("the withdrawn coin must go to the recipient");
+        };
+        assert!(matches!(
+            transfer.objects[0],
+            PtbArgument::Result { index: 0 }
+        ));
+    }
+
+    #[test]
+    fn test_withdraw_ptb_draws_from_users_position() {
+        let obligation = PositionObject::Shared {
+            object_id: "0x0b11".to_string(),
+            initial_shared_version: 42,
+        };
+        let owned = |object_id: &str| PositionObject::Owned {
+            object_id: object_id.to_string(),
+            version: 7,
+            digest: "digest".to_string(),
+        };
+        let withdraw = |strategy, from| {
+            ProtocolFactory::build_withdraw_ptb(
+                strategy,
+                1_000_000,
+                from,
+                OWNER,
+                &configured_protocols(),
+                SuiNetwork::Testnet,
+            )
+        };
+
+        let key = owned("0x6e1");
+        let ptb = withdraw(
+            YieldStrategy::ScallopUsdc,
+            WithdrawFrom::Obligation {
+                obligation: &obligation,
+                key: &key,
+            },
+        )
+        .unwrap()
+        .build();
+        let call = &calls(&ptb)[0];
+        assert_eq!(
+            (call.module.as_str(), call.function.as_str()),
+            ("withdraw_collateral", "withdraw_collateral")
+        );
+        assert_eq!(call.arguments.len(), 4);
+
+        let account_cap = owned("0x4a1");
+        let ptb = withdraw(
+            YieldStrategy::NaviUsdc,
+            WithdrawFrom::AccountCap(&account_cap),
+        )
+        .unwrap()
+        .build();
+        let call = &calls(&ptb)[0];
+        assert_eq!(
+            (call.module.as_str(), call.function.as_str()),
+            ("pool", "withdraw_with_account_cap")
+        );
+
+        // Each protocol only takes its own kind of position
+        assert!(withdraw(
+            YieldStrategy::ScallopUsdc,
+            WithdrawFrom::AccountCap(&account_cap)
+        )
+        .is_err());
+        assert!(withdraw(
+            YieldStrategy::NaviUsdc,
+            WithdrawFrom::Obligation {
+                obligation: &obligation,
+                key: &key,
+            }
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_position_object_must_belong_to_protocol() {
+        let navi_cap = format!("0x{:0>64}::account::AccountCap", "4a71");
+        let owned = serde_json::json!({ "AddressOwner": OWNER });
+
+        let position = ProtocolFactory::position_object(
+            YieldStrategy::NaviUsdc,
+            &sui_object(&navi_cap, owned.clone()),
+            OWNER,
+            &configured_protocols(),
+        )
+        .unwrap();
+        assert_eq!(
+            position,
+            PositionObject::Owned {
+                object_id: "0xb0b".to_string(),
+                version: 7,
+                digest: "digest".to_string(),
+            }
+        );
+
+        // A Navi account cap is not a Scallop obligation
+        assert!(ProtocolFactory::position_object(
+            YieldStrategy::ScallopUsdc,
+            &sui_object(&navi_cap, owned),
+            OWNER,
+            &configured_protocols(),
+        )
+        .is_err());
+        // Someone else's cap
+        assert!(ProtocolFactory::position_object(
+            YieldStrategy::NaviUsdc,
+            &sui_object(&navi_cap, serde_json::json!({ "AddressOwner": "0xbad" })),
+            OWNER,
+            &configured_protocols(),
+        )
+        .is_err());
+    }
 }
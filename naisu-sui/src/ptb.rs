@@ -58,6 +58,20 @@ pub enum PtbArgument {
     NestedResult { index: u16, result_index: u16 },
 }
 
+impl PtbArgument {
+    /// The `result_index`th value of a command returning several; `None`
+    /// unless `self` is a command result
+    pub fn nested(&self, result_index: u16) -> Option<PtbArgument> {
+        match self {
+            PtbArgument::Result { index } => Some(PtbArgument::NestedResult {
+                index: *index,
+                result_index,
+            }),
+            _ => None,
+        }
+    }
+}
+
 /// PTB builder
 #[derive(Debug, Default)]
 pub struct PtbBuilder {
@@ -96,10 +110,10 @@ impl PtbBuilder {
         PtbArgument::Input { index }
     }
 
-    /// Add a pure value input
-    pub fn add_pure<T: Serialize>(&mut self, value: &T) -> PtbArgument {
-        let bytes = bcs_serialize(value);
-        self.add_input(PtbInput::Pure { value: bytes })
+    /// Add a pure value input, BCS-encoded
+    pub fn add_pure<T: Serialize>(&mut self, value: &T) -> Result<PtbArgument, PtbError> {
+        let bytes = bcs::to_bytes(value)?;
+        Ok(self.add_input(PtbInput::Pure { value: bytes }))
     }
 
     /// Add an object input
@@ -187,9 +201,31 @@ pub struct ProgrammableTransactionBlock {
     pub commands: Vec<PtbCommand>,
 }
 
-/// Simple BCS serialization (placeholder - use actual bcs crate in production)
-fn bcs_serialize<T: Serialize>(_value: &T) -> Vec<u8> {
-    // TODO: Use proper BCS serialization
-    // For MVP, this is a placeholder
-    vec![]
+/// PTB construction errors
+#[derive(Debug, thiserror::Error)]
+pub enum PtbError {
+    #[error("Failed to encode pure input: {0}")]
+    Encode(#[from] bcs::Error),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unencodable_pure_input_is_an_error() {
+        let mut builder = PtbBuilder::new();
+        assert!(matches!(
+            builder.add_pure(&1.5f64),
+            Err(PtbError::Encode(_))
+        ));
+        assert!(builder.build().inputs.is_empty());
+
+        let mut builder = PtbBuilder::new();
+        builder.add_pure(&7u64).unwrap();
+        assert!(matches!(
+            &builder.build().inputs[..],
+            [PtbInput::Pure { value }] if value == &7u64.to_le_bytes()
+        ));
+    }
 }
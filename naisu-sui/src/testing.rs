@@ -0,0 +1,17 @@
+//! Test fixtures shared across crates
+//!
+//! Enabled in other crates' tests through the `testing` feature.
+
+use crate::protocols::ProtocolConfig;
+
+/// Scallop and Navi configured with placeholder ids, so every USDC strategy
+/// can build its PTBs
+pub fn configured_protocols() -> ProtocolConfig {
+    ProtocolConfig {
+        scallop_package: Some("0x5ca1".to_string()),
+        scallop_market: Some("0xa4e7".to_string()),
+        navi_package: Some("0x4a71".to_string()),
+        navi_pool: Some("0x9001".to_string()),
+        deepbook_package: None,
+    }
+}
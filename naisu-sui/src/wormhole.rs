@@ -0,0 +1,483 @@
+//! Wormhole token bridge integration (EVM → Sui)
+//!
+//! Route for non-USDC assets, which CCTP cannot carry. The user calls
+//! `transferTokens` on the EVM token bridge, we fetch the signed VAA from
+//! Wormholescan and build the Sui `complete_transfer` PTB that redeems it.
+
+use std::time::Duration;
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use naisu_core::{EvmChain, SuiNetwork};
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+
+use crate::config::HttpTimeouts;
+use crate::ptb::{PtbBuilder, PtbError};
+
+// ─── Wormhole Chain IDs ──────────────────────────────────────────────────────
+// Source: https://wormhole.com/docs/build/reference/chain-ids/
+
+pub const WORMHOLE_CHAIN_SUI: u16 = 21;
+
+/// Wormhole chain ID for an EVM chain
+pub fn wormhole_chain_id(chain: EvmChain) -> u16 {
+    match chain {
+        EvmChain::Ethereum => 2,
+        EvmChain::Arbitrum => 23,
+        EvmChain::Optimism => 24,
+        EvmChain::Base => 30,
+        EvmChain::Sepolia => 10002,
+        EvmChain::BaseSepolia => 10004,
+    }
+}
+
+/// Token bridge contract on an EVM chain
+/// Source: https://wormhole.com/docs/build/reference/contract-addresses/
+pub fn evm_token_bridge(chain: EvmChain) -> &'static str {
+    match chain {
+        EvmChain::Ethereum => "0x3ee18B2214AFF97000D974cf647E7C347E8fa585",
+        EvmChain::Arbitrum => "0x0b2402144Bb366A632D14B83F244D2e0e21bD39c",
+        EvmChain::Optimism => "0x1D68124e65faFC907325e3EDbF8c4d84499DAa8b",
+        EvmChain::Base => "0x8d2de8d2f73F1F4cAB472AC9A881C9b123C79627",
+        EvmChain::Sepolia => "0xDB5492265f6038831E89f495670FF909aDe94bd9",
+        EvmChain::BaseSepolia => "0x86F55A04690fd7815A3D802bD587e83eA888B239",
+    }
+}
+
+// ─── Sui Package IDs (Testnet) ───────────────────────────────────────────────
+
+/// Wormhole core package on Sui Testnet
+pub const WORMHOLE_PACKAGE: &str =
+    "0xf47329f4344f3bf0f8e436e2f7b485466cff300f12a166563995d3888c296a94";
+
+/// Wormhole core State object on Sui Testnet
+pub const WORMHOLE_STATE: &str =
+    "0x31358d198147da50db32eda2562951d53973a0c0ad5ed738e9b17d88b213d790";
+
+/// Token bridge package on Sui Testnet
+pub const TOKEN_BRIDGE_PACKAGE: &str =
+    "0x562760fc51d90d4ae1835bac3e91e0e6987d3497b06f066941d3e51f6e8d76d0";
+
+/// Token bridge State object on Sui Testnet
+pub const TOKEN_BRIDGE_STATE: &str =
+    "0x6fb10cdb7aa299e9a4308752dadecb049ff55a892de92992a1edbd7912b3d6da";
+
+/// Sui system clock
+const SUI_CLOCK: &str = "0x6";
+
+/// Wormholescan API (testnet)
+pub const WORMHOLESCAN_API_TESTNET: &str = "https://api.testnet.wormholescan.io";
+
+/// Wormholescan API (mainnet)
+pub const WORMHOLESCAN_API_MAINNET: &str = "https://api.wormholescan.io";
+
+/// Wormholescan API for a network
+pub fn wormholescan_api_url(network: SuiNetwork) -> &'static str {
+    if network.is_testnet() {
+        WORMHOLESCAN_API_TESTNET
+    } else {
+        WORMHOLESCAN_API_MAINNET
+    }
+}
+
+// ─── Bridge Selection ────────────────────────────────────────────────────────
+
+/// Bridge used to move an asset from EVM to Sui
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BridgeProvider {
+    /// Circle CCTP (native USDC burn/mint)
+    Cctp,
+    /// Wormhole token bridge (lock/mint wrapped assets)
+    Wormhole,
+}
+
+impl BridgeProvider {
+    /// USDC goes over CCTP, everything else over the Wormhole token bridge
+    pub fn for_token(chain: EvmChain, token: &str) -> Self {
+        if token.eq_ignore_ascii_case(chain.usdc_address()) {
+            BridgeProvider::Cctp
+        } else {
+            BridgeProvider::Wormhole
+        }
+    }
+}
+
+// ─── Types ───────────────────────────────────────────────────────────────────
+
+/// Parameters for `TokenBridge.transferTokens` on the source EVM chain
+///
+/// `transferTokens(address token, uint256 amount, uint16 recipientChain,
+/// bytes32 recipient, uint256 arbiterFee, uint32 nonce)`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferTokensParams {
+    /// Token bridge contract to call
+    pub token_bridge: String,
+    pub token: String,
+    /// Amount (raw, with decimals)
+    pub amount: String,
+    pub recipient_chain: u16,
+    /// Sui recipient as bytes32
+    pub recipient: String,
+    pub arbiter_fee: String,
+    pub nonce: u32,
+}
+
+/// Build the `transferTokens` call for an EVM→Sui transfer.
+/// The user signs and submits it; we just supply the arguments.
+pub fn build_transfer_tokens(
+    chain: EvmChain,
+    token: &str,
+    amount: &str,
+    sui_recipient: &str,
+    nonce: u32,
+) -> Result<TransferTokensParams, WormholeError> {
+    Ok(TransferTokensParams {
+        token_bridge: evm_token_bridge(chain).to_string(),
+        token: token.to_string(),
+        amount: amount.to_string(),
+        recipient_chain: WORMHOLE_CHAIN_SUI,
+        recipient: pad_sui_address(sui_recipient)?,
+        arbiter_fee: "0".to_string(),
+        nonce,
+    })
+}
+
+/// Parameters for redeeming a transfer VAA on Sui
+#[derive(Debug, Clone)]
+pub struct CompleteTransferRequest {
+    /// Recipient Sui address (must match the VAA payload)
+    pub recipient: String,
+    /// Sui coin type being redeemed (wrapped or native)
+    pub coin_type: String,
+    /// Signed VAA bytes
+    pub vaa: Vec<u8>,
+}
+
+/// Build the Sui PTB that verifies a transfer VAA and redeems the tokens.
+///
+/// 1. `wormhole::vaa::parse_and_verify` — check guardian signatures
+/// 2. `token_bridge::vaa::verify_only_once` — replay protection
+/// 3. `token_bridge::complete_transfer::authorize_transfer<T>`
+/// 4. `token_bridge::complete_transfer::redeem_relayer_payout<T>` — the coin
+/// 5. Transfer the coin to the recipient
+pub fn build_complete_transfer_ptb(
+    request: &CompleteTransferRequest,
+) -> Result<PtbBuilder, WormholeError> {
+    if request.vaa.is_empty() {
+        return Err(WormholeError::InvalidVaa("empty VAA".to_string()));
+    }
+    let recipient = pad_sui_address(&request.recipient)?;
+
+    let mut ptb = PtbBuilder::new();
+
+    let wormhole_state = ptb.add_shared_object(WORMHOLE_STATE, 1, false);
+    let token_bridge_state = ptb.add_shared_object(TOKEN_BRIDGE_STATE, 1, true);
+    let clock = ptb.add_shared_object(SUI_CLOCK, 1, false);
+    let vaa_bytes = ptb.add_pure(&request.vaa)?;
+
+    let verified_vaa = ptb.move_call(
+        WORMHOLE_PACKAGE,
+        "vaa",
+        "parse_and_verify",
+        vec![],
+        vec![wormhole_state, vaa_bytes, clock],
+    );
+    let message = ptb.move_call(
+        TOKEN_BRIDGE_PACKAGE,
+        "vaa",
+        "verify_only_once",
+        vec![],
+        vec![token_bridge_state.clone(), verified_vaa],
+    );
+    let receipt = ptb.move_call(
+        TOKEN_BRIDGE_PACKAGE,
+        "complete_transfer",
+        "authorize_transfer",
+        vec![request.coin_type.clone()],
+        vec![token_bridge_state, message],
+    );
+    let coin = ptb.move_call(
+        TOKEN_BRIDGE_PACKAGE,
+        "complete_transfer",
+        "redeem_relayer_payout",
+        vec![request.coin_type.clone()],
+        vec![receipt],
+    );
+
+    let recipient = ptb.add_pure(&recipient)?;
+    ptb.transfer_objects(vec![coin], recipient);
+
+    Ok(ptb)
+}
+
+/// Left-pad a Sui address to 32 bytes
+fn pad_sui_address(addr: &str) -> Result<String, WormholeError> {
+    let clean = addr.strip_prefix("0x").unwrap_or(addr);
+    if clean.is_empty() || clean.len() > 64 || !clean.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(WormholeError::InvalidAddress(addr.to_string()));
+    }
+    Ok(format!("0x{:0>64}", clean.to_lowercase()))
+}
+
+// ─── VAA Fetching ────────────────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize)]
+struct VaaResponse {
+    data: VaaData,
+}
+
+#[derive(Debug, Deserialize)]
+struct VaaData {
+    /// Base64-encoded signed VAA
+    vaa: String,
+}
+
+/// Wormholescan client for fetching signed VAAs
+#[derive(Debug, Clone)]
+pub struct WormholeClient {
+    client: reqwest::Client,
+    base_url: String,
+}
+
+impl WormholeClient {
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self {
+            client: HttpTimeouts::default().build_client(),
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+        }
+    }
+
+    /// Override the default request/connect timeouts
+    pub fn with_timeouts(mut self, timeouts: HttpTimeouts) -> Self {
+        self.client = timeouts.build_client();
+        self
+    }
+
+    /// Fetch the signed VAA for a token bridge transfer from `chain`.
+    ///
+    /// Returns `Ok(None)` until the guardians have signed it (404).
+    pub async fn get_vaa(
+        &self,
+        chain: EvmChain,
+        sequence: u64,
+    ) -> Result<Option<Vec<u8>>, WormholeError> {
+        let emitter = evm_token_bridge(chain)
+            .trim_start_matches("0x")
+            .to_lowercase();
+        let url = format!(
+            "{}/api/v1/vaas/{}/{:0>64}/{}",
+            self.base_url,
+            wormhole_chain_id(chain),
+            emitter,
+            sequence
+        );
+
+        let response = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .map_err(WormholeError::from_reqwest)?;
+
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            return Err(WormholeError::Api {
+                status: status.as_u16(),
+                message: body,
+            });
+        }
+
+        let body: VaaResponse = response
+            .json()
+            .await
+            .map_err(|e| WormholeError::Parse(e.to_string()))?;
+
+        STANDARD
+            .decode(body.data.vaa)
+            .map(Some)
+            .map_err(|e| WormholeError::InvalidVaa(e.to_string()))
+    }
+
+    /// Poll until the VAA is signed, up to `max_attempts`.
+    /// Transient request failures (including timeouts) count as not-yet-signed.
+    pub async fn poll_vaa(
+        &self,
+        chain: EvmChain,
+        sequence: u64,
+        interval: Duration,
+        max_attempts: u32,
+    ) -> Result<Vec<u8>, WormholeError> {
+        for attempt in 1..=max_attempts {
+            match self.get_vaa(chain, sequence).await {
+                Ok(Some(vaa)) => return Ok(vaa),
+                Ok(None) => {}
+                Err(e) if e.is_transient() => {
+                    tracing::warn!(sequence, attempt, error = %e, "Transient Wormholescan error");
+                }
+                Err(e) => return Err(e),
+            }
+            if attempt < max_attempts {
+                tokio::time::sleep(interval).await;
+            }
+        }
+
+        Err(WormholeError::Timeout(max_attempts))
+    }
+}
+
+// ─── Errors ──────────────────────────────────────────────────────────────────
+
+#[derive(Debug, thiserror::Error)]
+pub enum WormholeError {
+    #[error("Invalid Sui address: {0}")]
+    InvalidAddress(String),
+
+    #[error("Invalid VAA: {0}")]
+    InvalidVaa(String),
+
+    #[error("HTTP request failed: {0}")]
+    Request(String),
+
+    #[error("HTTP request timed out")]
+    RequestTimeout,
+
+    #[error("Wormholescan API error {status}: {message}")]
+    Api { status: u16, message: String },
+
+    #[error("Failed to parse response: {0}")]
+    Parse(String),
+
+    #[error("VAA not signed after {0} attempts")]
+    Timeout(u32),
+
+    #[error("Failed to build PTB: {0}")]
+    Ptb(#[from] PtbError),
+}
+
+impl WormholeError {
+    fn from_reqwest(err: reqwest::Error) -> Self {
+        if err.is_timeout() {
+            WormholeError::RequestTimeout
+        } else {
+            WormholeError::Request(err.to_string())
+        }
+    }
+
+    /// Whether the request may succeed if retried later
+    pub fn is_transient(&self) -> bool {
+        matches!(
+            self,
+            WormholeError::Request(_) | WormholeError::RequestTimeout
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ptb::{PtbArgument, PtbCommand};
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const WETH: &str = "0x4200000000000000000000000000000000000006";
+
+    #[test]
+    fn test_bridge_provider_by_token() {
+        let usdc = EvmChain::BaseSepolia.usdc_address().to_lowercase();
+        assert_eq!(
+            BridgeProvider::for_token(EvmChain::BaseSepolia, &usdc),
+            BridgeProvider::Cctp
+        );
+        assert_eq!(
+            BridgeProvider::for_token(EvmChain::BaseSepolia, WETH),
+            BridgeProvider::Wormhole
+        );
+    }
+
+    #[test]
+    fn test_complete_transfer_ptb() {
+        let request = CompleteTransferRequest {
+            recipient: "0x2".to_string(),
+            coin_type: "0xabc::coin::COIN".to_string(),
+            vaa: vec![1, 0, 0, 0],
+        };
+
+        let ptb = build_complete_transfer_ptb(&request).unwrap().build();
+
+        let calls: Vec<_> = ptb
+            .commands
+            .iter()
+            .filter_map(|c| match c {
+                PtbCommand::MoveCall(call) => Some((call.module.as_str(), call.function.as_str())),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            calls,
+            [
+                ("vaa", "parse_and_verify"),
+                ("vaa", "verify_only_once"),
+                ("complete_transfer", "authorize_transfer"),
+                ("complete_transfer", "redeem_relayer_payout"),
+            ]
+        );
+
+        let PtbCommand::TransferObjects(transfer) = ptb.commands.last().unwrap() else {
+            panic!("expected final TransferObjects");
+        };
+        assert!(matches!(
+            transfer.objects[..],
+            [PtbArgument::Result { index: 3 }]
+        ));
+    }
+
+    #[test]
+    fn test_complete_transfer_rejects_empty_vaa() {
+        let request = CompleteTransferRequest {
+            recipient: "0x2".to_string(),
+            coin_type: "0x2::sui::SUI".to_string(),
+            vaa: vec![],
+        };
+        assert!(matches!(
+            build_complete_transfer_ptb(&request),
+            Err(WormholeError::InvalidVaa(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_poll_vaa_waits_for_signature() {
+        let server = MockServer::start().await;
+        let vaa_path = format!(
+            "/api/v1/vaas/10004/{:0>64}/17",
+            "86f55a04690fd7815a3d802bd587e83ea888b239"
+        );
+
+        Mock::given(method("GET"))
+            .and(path(vaa_path.as_str()))
+            .respond_with(ResponseTemplate::new(404))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path(vaa_path.as_str()))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": { "vaa": STANDARD.encode([1u8, 2, 3]) }
+            })))
+            .mount(&server)
+            .await;
+
+        let client = WormholeClient::new(server.uri());
+        let vaa = client
+            .poll_vaa(EvmChain::BaseSepolia, 17, Duration::from_millis(10), 3)
+            .await
+            .unwrap();
+
+        assert_eq!(vaa, vec![1, 2, 3]);
+    }
+}